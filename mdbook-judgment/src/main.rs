@@ -1,14 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use std::path::{Path, PathBuf};
 use std::process;
 
-use mdbook_preprocessor::book::{Book, BookItem};
+use dada_model::coverage::Coverage;
+use dada_model::test_util;
+use mdbook_preprocessor::book::{Book, BookItem, Chapter};
 use mdbook_preprocessor::{Preprocessor, PreprocessorContext};
 use regex::Regex;
 
 const GITHUB_BASE: &str = "https://github.com/dada-lang/dada-model/blob/main";
 
+/// The generated chapter listing every judgment; see [`render_appendix`].
+const APPENDIX_TITLE: &str = "Judgment reference";
+const APPENDIX_PATH: &str = "judgments.md";
+
 fn main() {
     let args: Vec<String> = std::env::args().collect();
 
@@ -17,6 +23,14 @@ fn main() {
         process::exit(0);
     }
 
+    if args.len() > 1 && args[1] == "coverage" {
+        if let Err(e) = print_coverage(&args[2..]) {
+            eprintln!("error: {e:#}");
+            process::exit(1);
+        }
+        process::exit(0);
+    }
+
     let (ctx, book) = mdbook_preprocessor::parse_input(io::stdin()).expect("failed to parse input");
     let preprocessor = JudgmentPreprocessor;
     let processed = preprocessor
@@ -36,12 +50,42 @@ impl Preprocessor for JudgmentPreprocessor {
         let root = ctx.root.clone();
         let src_dir = root.join("src");
         let index = scan_source_files(&src_dir, &root)?;
+        let strict = ctx
+            .config
+            .get::<bool>("preprocessor.judgment.strict")?
+            .unwrap_or(false);
 
+        let mut report = RefReport::default();
         book.for_each_mut(|item| {
             if let BookItem::Chapter(chapter) = item {
-                chapter.content = replace_refs(&chapter.content, &index);
+                report.chapter = chapter.name.clone();
+                let content = check_dada_blocks(&chapter.content, &mut report);
+                chapter.content = replace_refs(&content, &index, &mut report);
             }
         });
+        check_refs(&index, &report, strict)?;
+        if !report.failed_examples.is_empty() {
+            for failure in &report.failed_examples {
+                eprintln!("error: {failure}");
+            }
+            anyhow::bail!(
+                "{} `dada` block(s) did not match their annotation",
+                report.failed_examples.len()
+            );
+        }
+
+        let appendix = ctx
+            .config
+            .get::<bool>("preprocessor.judgment.appendix")?
+            .unwrap_or(true);
+        if appendix {
+            book.items.push(BookItem::Chapter(Chapter::new(
+                APPENDIX_TITLE,
+                render_appendix(&index),
+                APPENDIX_PATH,
+                vec![],
+            )));
+        }
 
         Ok(book)
     }
@@ -81,6 +125,31 @@ struct Judgment {
     rules: Vec<Rule>,
 }
 
+/// What the chapters referenced, filled in by [`replace_refs`].
+#[derive(Debug, Default)]
+struct RefReport {
+    /// The chapter being processed, for messages.
+    chapter: String,
+    judgments: HashSet<String>,
+    /// `(judgment, rule)` pairs.
+    rules: HashSet<(String, String)>,
+    anchors: HashSet<String>,
+    /// References that did not resolve, as messages.
+    dangling: Vec<String>,
+    /// `dada` blocks whose annotation did not match, as messages.
+    failed_examples: Vec<String>,
+}
+
+impl RefReport {
+    /// Record a reference that did not resolve, returning the text that
+    /// replaces it in the chapter. [`check_refs`] reports it.
+    fn dangling(&mut self, what: String) -> String {
+        self.dangling
+            .push(format!("{what} not found (in chapter `{}`)", self.chapter));
+        format!("**[{what} not found]**")
+    }
+}
+
 #[derive(Debug)]
 struct Rule {
     name: String,
@@ -487,15 +556,211 @@ fn clean_rule_text(rule_text: &str) -> String {
         .to_string()
 }
 
+// --- Inference rules ---
+
+/// A rule of a `judgment_fn!`, in mathematical notation: the premises that
+/// go above the bar and the conclusion that goes below it.
+#[derive(Debug, PartialEq, Eq)]
+struct InferenceRule {
+    premises: Vec<String>,
+    conclusion: String,
+}
+
+/// Parse the text of a rule (as stored in [`Rule::raw_text`]): parenthesized
+/// premises, the `-----` separator with the rule name, and the conclusion.
+fn parse_inference_rule(rule_text: &str) -> Option<InferenceRule> {
+    let separator_re = Regex::new(r#"-{3,}\s*\("[^"]+"\)"#).unwrap();
+    let separator = separator_re.find(rule_text)?;
+
+    let premises = split_groups(&rule_text[..separator.start()])
+        .into_iter()
+        .map(notation)
+        .collect();
+    let conclusions = split_groups(&rule_text[separator.end()..]);
+    let [conclusion] = conclusions[..] else {
+        return None;
+    };
+
+    Some(InferenceRule {
+        premises,
+        conclusion: notation(conclusion),
+    })
+}
+
+/// The contents of each top-level `(...)` group in `text`. Anything between
+/// groups, such as the `!` that commits to a rule, is skipped.
+fn split_groups(text: &str) -> Vec<&str> {
+    let mut groups = Vec::new();
+    let mut pos = 0;
+    while let Some(open) = text[pos..].find('(') {
+        let open = pos + open;
+        let Some(close) = find_matching_paren(text, open) else {
+            break;
+        };
+        groups.push(&text[open + 1..close]);
+        pos = close + 1;
+    }
+    groups
+}
+
+/// Write a premise or conclusion in mathematical notation:
+///
+/// * `if cond` and `if let pat = expr` become the condition itself;
+/// * `let x = expr` becomes `x ≔ expr`;
+/// * `for_all(x in xs) with(acc) (premise)` becomes `∀ x ∈ xs (acc). premise`;
+/// * `judgment(args) => result` becomes `judgment(args) ⇒ result`,
+///   leaving out `⇒ ()`.
+fn notation(premise: &str) -> String {
+    let premise = premise.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if let Some(condition) = premise.strip_prefix("if let ") {
+        return symbols(condition);
+    }
+    if let Some(condition) = premise.strip_prefix("if ") {
+        return symbols(condition);
+    }
+    if let Some(binding) = premise.strip_prefix("let ") {
+        return match binding.split_once(" = ") {
+            Some((name, value)) => format!("{} ≔ {}", symbols(name), symbols(value)),
+            None => symbols(binding),
+        };
+    }
+    if premise.starts_with("for_all(") {
+        if let Some(quantified) = for_all_notation(&premise) {
+            return quantified;
+        }
+    }
+
+    let text = symbols(&premise);
+    match text.strip_suffix(" ⇒ ()") {
+        Some(text) => text.to_string(),
+        None => text,
+    }
+}
+
+fn for_all_notation(premise: &str) -> Option<String> {
+    let quantifier = premise.strip_prefix("for_all")?;
+    let header_close = find_matching_paren(quantifier, 0)?;
+    let (var, collection) = quantifier[1..header_close].split_once(" in ")?;
+
+    let mut rest = quantifier[header_close + 1..].trim_start();
+    let mut accumulator = String::new();
+    if let Some(with) = rest
+        .strip_prefix("with")
+        .filter(|with| with.starts_with('('))
+    {
+        let with_close = find_matching_paren(with, 0)?;
+        accumulator = format!(" ({})", with[1..with_close].trim());
+        rest = with[with_close + 1..].trim_start();
+    }
+
+    let bodies = split_groups(rest);
+    let [body] = bodies[..] else {
+        return None;
+    };
+    Some(format!(
+        "∀ {} ∈ {}{accumulator}. {}",
+        var.trim(),
+        symbols(collection.trim()),
+        notation(body)
+    ))
+}
+
+/// Replace Rust operators with mathematical symbols, outside of string
+/// literals. A `!` after an identifier is a macro call and is kept; `&`
+/// borrows and `?` after a call are dropped.
+fn symbols(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        let next = chars.get(i + 1).copied();
+        let prev = if i > 0 { Some(chars[i - 1]) } else { None };
+
+        if in_string {
+            out.push(ch);
+            if ch == '"' && prev != Some('\\') {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        let (replacement, width) = match (ch, next) {
+            ('"', _) => {
+                in_string = true;
+                ("\"", 1)
+            }
+            ('=', Some('>')) => ("⇒", 2),
+            ('=', Some('=')) => ("=", 2),
+            ('!', Some('=')) => ("≠", 2),
+            ('>', Some('=')) => ("≥", 2),
+            ('<', Some('=')) => ("≤", 2),
+            ('&', Some('&')) => ("∧", 2),
+            ('|', Some('|')) => ("∨", 2),
+            ('!', _) if prev.is_some_and(|c| c.is_alphanumeric() || c == '_') => ("!", 1),
+            ('!', _) => ("¬", 1),
+            ('&', Some(c)) if !c.is_whitespace() => ("", 1),
+            ('?', _) if prev == Some(')') => ("", 1),
+            _ => {
+                out.push(ch);
+                i += 1;
+                continue;
+            }
+        };
+        out.push_str(replacement);
+        i += width;
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render premises over a bar, the conclusion below it, and the rule name
+/// beside it. Each element is on its own line, with no blank lines.
+fn render_inference_rule(rule: &InferenceRule, rule_name: &str) -> String {
+    let mut out = String::from(
+        "<div class=\"inference-rule\">\n\
+         <div class=\"inference-body\">\n\
+         <div class=\"premises\">\n",
+    );
+    for premise in &rule.premises {
+        out.push_str(&format!(
+            "<span class=\"premise\">{}</span>\n",
+            escape_html(premise)
+        ));
+    }
+    out.push_str(&format!(
+        "</div>\n\
+         <div class=\"conclusion\">{}</div>\n\
+         </div>\n\
+         <span class=\"rule-name\">({})</span>\n\
+         </div>\n",
+        escape_html(&rule.conclusion),
+        escape_html(rule_name)
+    ));
+    out
+}
+
 // --- Rendering ---
 
 /// Render a `<figure>` element with a code block and `[src]` link.
 /// This is the common rendering for judgments, judgment rules, and anchors.
+/// When `typeset` HTML is given, it is shown instead, and the code is
+/// tucked into a collapsible block below it.
 fn render_figure(
     css_class: &str,
     id: &str,
     label: &str,
     src_link: &str,
+    typeset: Option<&str>,
     code: &str,
     doc: Option<&str>,
 ) -> String {
@@ -504,13 +769,30 @@ fn render_figure(
          <figcaption>\n\
          <a href=\"#{id}\">{label}</a>\n\
          <a class=\"judgment-src\" href=\"{src_link}\" title=\"View source\" target=\"_blank\">[src]</a>\n\
-         </figcaption>\n\
-         \n\
-         ```rust,ignore\n\
-         {code}\n\
-         ```\n",
+         </figcaption>\n",
     );
 
+    // The typeset HTML has no blank lines, so that markdown leaves it alone;
+    // the code block needs blank lines around it to be parsed as markdown.
+    match typeset {
+        Some(typeset) => {
+            out.push_str(typeset);
+            out.push_str(&format!(
+                "<details class=\"judgment-raw\">\n\
+                 <summary>Rust source</summary>\n\
+                 \n\
+                 ```rust,ignore\n\
+                 {code}\n\
+                 ```\n\
+                 \n\
+                 </details>\n",
+            ));
+        }
+        None => {
+            out.push_str(&format!("\n```rust,ignore\n{code}\n```\n"));
+        }
+    }
+
     if let Some(doc) = doc {
         out.push('\n');
         out.push_str(doc);
@@ -525,25 +807,22 @@ fn github_link(file_path: &str, line: usize) -> String {
     format!("{GITHUB_BASE}/{file_path}#L{line}")
 }
 
-fn render_rule(judgment: &Judgment, rule_name: &str) -> String {
-    match judgment.rules.iter().find(|r| r.name == rule_name) {
-        Some(rule) => {
-            let link = github_link(&judgment.file_path, rule.line_number);
-            let id = format!("judgment-{}--{}", judgment.name, rule_name);
-            let label = format!("{}::{}", judgment.name, rule_name);
-            render_figure("judgment-rule", &id, &label, &link, &rule.raw_text, None)
-        }
-        None => {
-            eprintln!(
-                "warning: rule `{}` not found in judgment `{}`",
-                rule_name, judgment.name
-            );
-            format!(
-                "**[rule `{}` not found in `{}`]**",
-                rule_name, judgment.name
-            )
-        }
-    }
+fn render_rule(judgment: &Judgment, rule_name: &str) -> Option<String> {
+    let rule = judgment.rules.iter().find(|r| r.name == rule_name)?;
+    let link = github_link(&judgment.file_path, rule.line_number);
+    let id = format!("judgment-{}--{}", judgment.name, rule_name);
+    let label = format!("{}::{}", judgment.name, rule_name);
+    let typeset = parse_inference_rule(&rule.raw_text)
+        .map(|inference_rule| render_inference_rule(&inference_rule, rule_name));
+    Some(render_figure(
+        "judgment-rule",
+        &id,
+        &label,
+        &link,
+        typeset.as_deref(),
+        &rule.raw_text,
+        None,
+    ))
 }
 
 fn render_judgment(judgment: &Judgment) -> String {
@@ -554,18 +833,351 @@ fn render_judgment(judgment: &Judgment) -> String {
     } else {
         Some(judgment.doc_comment.as_str())
     };
-    render_figure("judgment", &id, &judgment.name, &link, &judgment.signature, doc)
+    let typeset = format!(
+        "<div class=\"judgment-form\">{}</div>\n",
+        escape_html(&notation(&judgment.signature))
+    );
+    render_figure(
+        "judgment",
+        &id,
+        &judgment.name,
+        &link,
+        Some(&typeset),
+        &judgment.signature,
+        doc,
+    )
 }
 
 fn render_anchor(anchor: &Anchor) -> String {
     let link = github_link(&anchor.file_path, anchor.line_number);
     let id = format!("anchor-{}", anchor.name);
-    render_figure("anchor", &id, &anchor.name, &link, &anchor.content, None)
+    render_figure(
+        "anchor",
+        &id,
+        &anchor.name,
+        &link,
+        None,
+        &anchor.content,
+        None,
+    )
+}
+
+// --- Appendix ---
+
+/// The judgments that the premises of `rule` invoke, in order of first use.
+/// A judgment is invoked when its name is followed by `(`.
+fn invoked_judgments<'a>(rule: &Rule, index: &'a SourceIndex) -> Vec<&'a str> {
+    let separator_re = Regex::new(r#"-{3,}\s*\("[^"]+"\)"#).unwrap();
+    let premises = match separator_re.find(&rule.raw_text) {
+        Some(separator) => &rule.raw_text[..separator.start()],
+        None => &rule.raw_text,
+    };
+
+    let call_re = Regex::new(r"\b(\w+)\s*\(").unwrap();
+    let mut invoked: Vec<&str> = vec![];
+    for caps in call_re.captures_iter(premises) {
+        if let Some((name, _)) = index.judgments.get_key_value(&caps[1]) {
+            if !invoked.contains(&name.as_str()) {
+                invoked.push(name);
+            }
+        }
+    }
+    invoked
+}
+
+/// For each judgment, the `(judgment, rule)` pairs whose premises invoke it.
+fn judgment_callers(index: &SourceIndex) -> HashMap<&str, Vec<(&str, &str)>> {
+    let mut callers: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+    for judgment in index.judgments.values() {
+        for rule in &judgment.rules {
+            for callee in invoked_judgments(rule, index) {
+                callers
+                    .entry(callee)
+                    .or_default()
+                    .push((&judgment.name, &rule.name));
+            }
+        }
+    }
+    for rules in callers.values_mut() {
+        rules.sort();
+    }
+    callers
+}
+
+fn judgment_link(name: &str) -> String {
+    format!("[`{name}`](#judgment-{name})")
+}
+
+fn rule_link(judgment: &str, rule: &str) -> String {
+    format!("[`{judgment}::{rule}`](#judgment-{judgment}--{rule})")
+}
+
+/// The generated appendix chapter: every judgment, grouped by source file,
+/// with its signature, doc comment, rules and source links. Each rule lists
+/// the judgments its premises invoke, and each judgment the rules that invoke
+/// it. Figures keep the ids they have elsewhere (`judgment-NAME` and
+/// `judgment-NAME--RULE`), so chapters can deep-link with
+/// `judgments.md#judgment-NAME`.
+fn render_appendix(index: &SourceIndex) -> String {
+    let mut judgments: Vec<&Judgment> = index.judgments.values().collect();
+    judgments.sort_by(|a, b| (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number)));
+    let callers = judgment_callers(index);
+
+    let mut out = format!(
+        "# {APPENDIX_TITLE}\n\n\
+         Every `judgment_fn!` in the model, grouped by source file. \
+         This chapter is generated from the source by `mdbook-judgment`.\n"
+    );
+    let mut file_path = "";
+    for judgment in judgments {
+        if judgment.file_path != file_path {
+            file_path = &judgment.file_path;
+            out.push_str(&format!("\n## `{file_path}`\n"));
+        }
+
+        out.push_str(&format!("\n### `{}`\n\n", judgment.name));
+        out.push_str(&render_judgment(judgment));
+        let invoked_by = match callers.get(judgment.name.as_str()) {
+            Some(rules) => rules
+                .iter()
+                .map(|(caller, rule)| rule_link(caller, rule))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => "no other judgment".to_string(),
+        };
+        out.push_str(&format!("\nInvoked by {invoked_by}.\n"));
+
+        for rule in &judgment.rules {
+            out.push('\n');
+            if let Some(rendered) = render_rule(judgment, &rule.name) {
+                out.push_str(&rendered);
+            }
+            let invoked = invoked_judgments(rule, index);
+            if !invoked.is_empty() {
+                let links: Vec<String> = invoked.iter().map(|name| judgment_link(name)).collect();
+                out.push_str(&format!("\nInvokes {}.\n", links.join(", ")));
+            }
+        }
+    }
+    out
+}
+
+// --- Dada examples ---
+
+/// What a ```` ```dada ```` block claims about its program, from the
+/// attributes after `dada` in its info string:
+///
+/// * `type-error`: the type checker rejects the program (otherwise it must
+///   accept it);
+/// * `output="..."`: running `Main.main` prints these lines (separated
+///   by `\n`);
+/// * `result="..."`: running `Main.main` returns this value;
+/// * `trace`: show the interpreter trace below the block.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct DadaBlock {
+    type_error: bool,
+    output: Option<String>,
+    result: Option<String>,
+    trace: bool,
+}
+
+impl DadaBlock {
+    fn parse(attributes: &str) -> anyhow::Result<Self> {
+        let attribute_re = Regex::new(r#"^([\w-]+)(?:="((?:[^"\\]|\\.)*)")?\s*"#).unwrap();
+        let mut block = DadaBlock::default();
+        let mut rest = attributes.trim();
+        while !rest.is_empty() {
+            let Some(caps) = attribute_re.captures(rest) else {
+                anyhow::bail!("cannot parse `{rest}`");
+            };
+            let value = caps
+                .get(2)
+                .map(|value| value.as_str().replace("\\n", "\n").replace("\\\"", "\""));
+            match (&caps[1], value) {
+                ("type-error", None) => block.type_error = true,
+                ("trace", None) => block.trace = true,
+                ("output", Some(value)) => block.output = Some(value),
+                ("result", Some(value)) => block.result = Some(value),
+                _ => anyhow::bail!("unknown attribute `{}`", caps[0].trim()),
+            }
+            rest = &rest[caps.get(0).unwrap().end()..];
+        }
+        if block.type_error && (block.output.is_some() || block.result.is_some() || block.trace) {
+            anyhow::bail!("a `type-error` block cannot be run");
+        }
+        Ok(block)
+    }
+
+    fn runs(&self) -> bool {
+        self.output.is_some() || self.result.is_some() || self.trace
+    }
+
+    /// Check `source` against the annotation, returning the verdict to show
+    /// below the block.
+    fn check(&self, source: &str) -> anyhow::Result<String> {
+        let program = test_util::try_parse_program(source)?;
+        let errors = test_util::located_type_errors(source, &program);
+        match (errors.is_empty(), self.type_error) {
+            (true, false) => {}
+            (false, true) => return Ok(render_verdict("✗ rejected by the type checker")),
+            (true, true) => anyhow::bail!("expected a type error, but the program type checks"),
+            (false, false) => {
+                let errors: Vec<String> = errors
+                    .iter()
+                    .map(|error| {
+                        format!(
+                            "on line {} of the block:\n{}",
+                            error.lines.start(),
+                            error.leaves
+                        )
+                    })
+                    .collect();
+                anyhow::bail!("the program does not type check:\n{}", errors.join("\n"))
+            }
+        }
+        if !self.runs() {
+            return Ok(render_verdict("✓ type checks"));
+        }
+
+        let run = test_util::run_interpreter(&program);
+        let Some(result) = run.result.strip_prefix("Ok: ") else {
+            anyhow::bail!("the program faulted: {}", run.result);
+        };
+        let printed: Vec<&str> = run
+            .output_lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("----->"))
+            .map(str::trim)
+            .collect();
+        if let Some(output) = &self.output {
+            let expected: Vec<&str> = output.lines().collect();
+            if printed != expected {
+                anyhow::bail!("expected output {expected:?}, but the program printed {printed:?}");
+            }
+        }
+        if let Some(expected) = &self.result {
+            if result != expected {
+                anyhow::bail!("expected result `{expected}`, but the program returned `{result}`");
+            }
+        }
+
+        let mut verdict = render_verdict(&format!(
+            "✓ type checks and returns <code>{}</code>",
+            escape_html(result)
+        ));
+        if self.trace {
+            // As in `render_figure`, the code block needs blank lines around it.
+            verdict.push_str(&format!(
+                "<details class=\"dada-trace\">\n\
+                 <summary>Interpreter trace</summary>\n\
+                 \n\
+                 ```text\n\
+                 {}\n\
+                 ```\n\
+                 \n\
+                 </details>\n",
+                run.output_lines.join("\n")
+            ));
+        }
+        Ok(verdict)
+    }
+}
+
+fn render_verdict(html: &str) -> String {
+    format!("<div class=\"dada-verdict\">{html}</div>\n")
+}
+
+/// Check each ```` ```dada ```` block of a chapter against its annotation
+/// (see [`DadaBlock`]), recording mismatches in `report`, and show the
+/// verdict below the block.
+fn check_dada_blocks(content: &str, report: &mut RefReport) -> String {
+    let fence_re = Regex::new(r"(?ms)^```dada\b([^\n]*)\n(.*?)^```[ \t]*$").unwrap();
+    fence_re
+        .replace_all(content, |caps: &regex::Captures| {
+            let source = &caps[2];
+            let start = caps.get(0).unwrap().start();
+            let line = content[..start].matches('\n').count() + 1;
+            let verdict = DadaBlock::parse(&caps[1]).and_then(|block| block.check(source));
+            let verdict = match verdict {
+                Ok(verdict) => verdict,
+                Err(e) => {
+                    report.failed_examples.push(format!(
+                        "`dada` block at line {line} of chapter `{}`: {e}",
+                        report.chapter
+                    ));
+                    let message = e.to_string();
+                    let summary = message.lines().next().unwrap_or_default();
+                    format!("**[`dada` block failed: {}]**\n", escape_html(summary))
+                }
+            };
+            format!("```dada\n{source}```\n\n{verdict}")
+        })
+        .to_string()
+}
+
+// --- Rule coverage ---
+
+/// `mdbook-judgment coverage FILE...`: join the rule coverage that `cargo test`
+/// wrote to the files (with `DADA_COVERAGE=FILE`) with the rules in `src/`,
+/// and list the rules that were never applied.
+fn print_coverage(paths: &[String]) -> anyhow::Result<()> {
+    if paths.is_empty() {
+        anyhow::bail!("usage: mdbook-judgment coverage FILE...");
+    }
+    let mut text = String::new();
+    for path in paths {
+        text.push_str(&std::fs::read_to_string(path)?);
+    }
+    let coverage = Coverage::parse(&text)?;
+
+    let root = std::env::current_dir()?;
+    let index = scan_source_files(&root.join("src"), &root)?;
+    print!("{}", coverage_report(&index, &coverage));
+    Ok(())
+}
+
+/// How many of the rules in `index` were applied, followed by each rule that
+/// was not, in source order, with how often it failed and its source link.
+fn coverage_report(index: &SourceIndex, coverage: &Coverage) -> String {
+    let mut judgments: Vec<&Judgment> = index.judgments.values().collect();
+    judgments.sort_by(|a, b| (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number)));
+
+    let mut total = 0;
+    let mut never_applied = vec![];
+    for judgment in judgments {
+        for rule in &judgment.rules {
+            total += 1;
+            let rule_id = (judgment.name.clone(), rule.name.clone());
+            if coverage.applied.contains_key(&rule_id) {
+                continue;
+            }
+            let failed = match coverage.failed.get(&rule_id) {
+                Some(count) => format!("failed {count} time(s)"),
+                None => "never tried".to_string(),
+            };
+            never_applied.push(format!(
+                "  {}::{} ({failed}) {}\n",
+                judgment.name,
+                rule.name,
+                github_link(&judgment.file_path, rule.line_number)
+            ));
+        }
+    }
+
+    let mut out = format!(
+        "{} of {total} rules were applied\n",
+        total - never_applied.len()
+    );
+    if !never_applied.is_empty() {
+        out.push_str("\nNever applied:\n");
+        out.extend(never_applied);
+    }
+    out
 }
 
 // --- Markdown replacement ---
 
-fn replace_refs(content: &str, index: &SourceIndex) -> String {
+fn replace_refs(content: &str, index: &SourceIndex, report: &mut RefReport) -> String {
     // First pass: {judgment-rule}`fn_name, rule_name`
     let rule_re = Regex::new(r#"\{judgment-rule\}`(\w+),\s*([^`]+)`"#).unwrap();
     let content = rule_re.replace_all(content, |caps: &regex::Captures| {
@@ -573,11 +1185,16 @@ fn replace_refs(content: &str, index: &SourceIndex) -> String {
         let rule_name = caps[2].trim();
 
         match index.judgments.get(fn_name) {
-            Some(judgment) => render_rule(judgment, rule_name),
-            None => {
-                eprintln!("warning: judgment function `{fn_name}` not found");
-                format!("**[judgment `{fn_name}` not found]**")
-            }
+            Some(judgment) => match render_rule(judgment, rule_name) {
+                Some(rendered) => {
+                    report
+                        .rules
+                        .insert((fn_name.to_string(), rule_name.to_string()));
+                    rendered
+                }
+                None => report.dangling(format!("rule `{rule_name}` in `{fn_name}`")),
+            },
+            None => report.dangling(format!("judgment `{fn_name}`")),
         }
     });
 
@@ -588,11 +1205,11 @@ fn replace_refs(content: &str, index: &SourceIndex) -> String {
             let fn_name = &caps[1];
 
             match index.judgments.get(fn_name) {
-                Some(judgment) => render_judgment(judgment),
-                None => {
-                    eprintln!("warning: judgment function `{fn_name}` not found");
-                    format!("**[judgment `{fn_name}` not found]**")
+                Some(judgment) => {
+                    report.judgments.insert(fn_name.to_string());
+                    render_judgment(judgment)
                 }
+                None => report.dangling(format!("judgment `{fn_name}`")),
             }
         })
         .to_string();
@@ -604,16 +1221,90 @@ fn replace_refs(content: &str, index: &SourceIndex) -> String {
             let anchor_name = &caps[1];
 
             match index.anchors.get(anchor_name) {
-                Some(anchor) => render_anchor(anchor),
-                None => {
-                    eprintln!("warning: anchor `{anchor_name}` not found");
-                    format!("**[anchor `{anchor_name}` not found]**")
+                Some(anchor) => {
+                    report.anchors.insert(anchor_name.to_string());
+                    render_anchor(anchor)
                 }
+                None => report.dangling(format!("anchor `{anchor_name}`")),
             }
         })
         .to_string()
 }
 
+/// `// ANCHOR:` regions and judgment rules that no chapter references,
+/// sorted, with where they are defined.
+fn unreferenced(index: &SourceIndex, report: &RefReport) -> (Vec<String>, Vec<String>) {
+    let mut anchors: Vec<String> = index
+        .anchors
+        .values()
+        .filter(|anchor| !report.anchors.contains(&anchor.name))
+        .map(|anchor| {
+            format!(
+                "anchor `{}` ({}:{})",
+                anchor.name, anchor.file_path, anchor.line_number
+            )
+        })
+        .collect();
+    anchors.sort();
+
+    let mut rules: Vec<String> = index
+        .judgments
+        .values()
+        .flat_map(|judgment| {
+            judgment
+                .rules
+                .iter()
+                .filter(|rule| {
+                    !report
+                        .rules
+                        .contains(&(judgment.name.clone(), rule.name.clone()))
+                })
+                .map(|rule| {
+                    format!(
+                        "rule `{}` in `{}` ({}:{})",
+                        rule.name, judgment.name, judgment.file_path, rule.line_number
+                    )
+                })
+        })
+        .collect();
+    rules.sort();
+
+    (anchors, rules)
+}
+
+/// Report the references that did not resolve, as warnings. In strict mode
+/// (`strict = true` under `[preprocessor.judgment]` in `book.toml`), they are
+/// errors instead; also list the anchors and rules that no chapter references,
+/// and fail if any reference did not resolve or any anchor is unused.
+fn check_refs(index: &SourceIndex, report: &RefReport, strict: bool) -> anyhow::Result<()> {
+    if !strict {
+        for dangling in &report.dangling {
+            eprintln!("warning: {dangling}");
+        }
+        return Ok(());
+    }
+
+    let (anchors, rules) = unreferenced(index, report);
+    for rule in &rules {
+        eprintln!("note: {rule} is not referenced by any chapter");
+    }
+    for anchor in &anchors {
+        eprintln!("error: {anchor} is not referenced by any chapter");
+    }
+    for dangling in &report.dangling {
+        eprintln!("error: {dangling}");
+    }
+
+    if !report.dangling.is_empty() || !anchors.is_empty() {
+        anyhow::bail!(
+            "strict mode: {} reference(s) did not resolve and {} anchor(s) are unused",
+            report.dangling.len(),
+            anchors.len()
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -704,7 +1395,7 @@ some postamble
     fn test_judgment_shows_signature_only() {
         let index = make_index();
         let input = "The judgment: {judgment}`move_place`";
-        let output = replace_refs(input, &index);
+        let output = replace_refs(input, &index, &mut RefReport::default());
         assert!(output.contains("move_place("), "output: {output}");
         assert!(output.contains("judgment-src"), "output: {output}");
         // Should NOT contain individual rule text
@@ -715,7 +1406,7 @@ some postamble
     fn test_judgment_rule_shows_rule_with_link() {
         let index = make_index();
         let input = r#"The copy rule: {judgment-rule}`move_place, copy`"#;
-        let output = replace_refs(input, &index);
+        let output = replace_refs(input, &index, &mut RefReport::default());
         assert!(output.contains("prove_is_copy"), "output: {output}");
         assert!(output.contains("github.com"), "output: {output}");
         assert!(
@@ -724,13 +1415,96 @@ some postamble
         );
     }
 
+    #[test]
+    fn test_parse_inference_rule() {
+        let judgments = parse_judgment_fns(SAMPLE, "src/type_system/expressions.rs");
+        let rule = parse_inference_rule(&judgments[0].rules[1].raw_text).unwrap();
+        assert_eq!(
+            rule,
+            InferenceRule {
+                premises: vec![
+                    "¬live_after.is_live(place)".to_string(),
+                    "env ≔ env.with_place_in_flight(place)".to_string(),
+                ],
+                conclusion: "move_place(env, live_after, place, _ty) ⇒ env".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_notation() {
+        assert_eq!(
+            notation("prove_is_copy(&env, ty) => ()"),
+            "prove_is_copy(env, ty)"
+        );
+        assert_eq!(notation("if a != b && c >= d"), "a ≠ b ∧ c ≥ d");
+        assert_eq!(
+            notation("if let Some(x) = set![y].first()"),
+            "Some(x) = set![y].first()"
+        );
+        assert_eq!(
+            notation("let fields = env.place_fields(place)?"),
+            "fields ≔ env.place_fields(place)"
+        );
+        assert_eq!(
+            notation(
+                "for_all(place in places) with(env)
+                    (access_permitted(env, live_after, access, place) => env)"
+            ),
+            "∀ place ∈ places (env). access_permitted(env, live_after, access, place) ⇒ env"
+        );
+        assert_eq!(
+            notation("for_all(field in fields) (check_field(env, field) => ())"),
+            "∀ field ∈ fields. check_field(env, field)"
+        );
+    }
+
+    #[test]
+    fn test_judgment_rule_is_typeset() {
+        let index = make_index();
+        let output = replace_refs(
+            "{judgment-rule}`move_place, copy`",
+            &index,
+            &mut RefReport::default(),
+        );
+        assert!(
+            output.contains("class=\"inference-rule\""),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("<span class=\"premise\">prove_is_copy(env, ty)</span>"),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("<span class=\"rule-name\">(copy)</span>"),
+            "output: {output}"
+        );
+        // The raw source is still there, in a collapsible block.
+        assert!(output.contains("<details"), "output: {output}");
+        assert!(
+            output.contains("(prove_is_copy(&env, ty) => ())"),
+            "output: {output}"
+        );
+    }
+
+    #[test]
+    fn test_typeset_text_is_escaped() {
+        let rule = InferenceRule {
+            premises: vec!["x: Vec<Ty>".to_string()],
+            conclusion: "a ⇒ b".to_string(),
+        };
+        let output = render_inference_rule(&rule, "r");
+        assert!(output.contains("x: Vec&lt;Ty&gt;"), "output: {output}");
+        assert!(!output.contains("\n\n"), "output: {output}");
+    }
+
     #[test]
     fn test_both_in_same_content() {
         let index = make_index();
         let input = r#"Sig: {judgment}`move_place`
 
 Rule: {judgment-rule}`move_place, copy`"#;
-        let output = replace_refs(input, &index);
+        let output = replace_refs(input, &index, &mut RefReport::default());
         assert!(output.contains("move_place("), "output: {output}");
         assert!(output.contains("prove_is_copy"), "output: {output}");
         assert!(!output.contains("{judgment"), "output: {output}");
@@ -752,11 +1526,234 @@ Rule: {judgment-rule}`move_place, copy`"#;
     fn test_anchor_replacement() {
         let index = make_index();
         let input = "The env: {anchor}`Env`";
-        let output = replace_refs(input, &index);
+        let output = replace_refs(input, &index, &mut RefReport::default());
         assert!(output.contains("pub struct Env"), "output: {output}");
         assert!(output.contains("github.com"), "output: {output}");
         assert!(output.contains("anchor-Env"), "output: {output}");
         assert!(output.contains("[src]"), "output: {output}");
         assert!(!output.contains("{anchor}"), "output: {output}");
     }
+
+    #[test]
+    fn test_dangling_refs_are_reported() {
+        let index = make_index();
+        let mut report = RefReport {
+            chapter: "Intro".to_string(),
+            ..RefReport::default()
+        };
+        let input = "{judgment-rule}`move_place, lend` {judgment}`nope` {anchor}`Nope`";
+        let output = replace_refs(input, &index, &mut report);
+        assert!(output.contains("not found"), "output: {output}");
+        assert_eq!(
+            report.dangling,
+            vec![
+                "rule `lend` in `move_place` not found (in chapter `Intro`)",
+                "judgment `nope` not found (in chapter `Intro`)",
+                "anchor `Nope` not found (in chapter `Intro`)",
+            ]
+        );
+        assert!(check_refs(&index, &report, false).is_ok());
+        assert!(check_refs(&index, &report, true).is_err());
+    }
+
+    #[test]
+    fn test_unreferenced_anchors_and_rules() {
+        let index = make_index();
+        let mut report = RefReport::default();
+        replace_refs("{judgment-rule}`move_place, copy`", &index, &mut report);
+        let (anchors, rules) = unreferenced(&index, &report);
+        assert_eq!(anchors, vec!["anchor `Env` (src/type_system/env.rs:3)"]);
+        assert_eq!(rules.len(), 1);
+        assert!(
+            rules[0].starts_with("rule `give` in `move_place`"),
+            "rules: {rules:?}"
+        );
+        assert!(check_refs(&index, &report, true).is_err());
+
+        replace_refs("{anchor}`Env`", &index, &mut report);
+        let (anchors, _) = unreferenced(&index, &report);
+        assert!(anchors.is_empty(), "anchors: {anchors:?}");
+        // Unreferenced rules are only reported.
+        assert!(check_refs(&index, &report, true).is_ok());
+    }
+
+    const CALLER_SAMPLE: &str = r#"
+judgment_fn! {
+    fn move_places(
+        env: Env,
+        places: Vec<Place>,
+    ) => Env {
+        debug(places, env)
+
+        (
+            (move_place(&env, live_after, place, ty) => env)
+            ----------------------------------- ("one")
+            (move_places(env, places) => env)
+        )
+    }
+}
+"#;
+
+    #[test]
+    fn test_appendix_call_graph() {
+        let mut index = make_index();
+        for j in parse_judgment_fns(CALLER_SAMPLE, "src/type_system/places.rs") {
+            index.judgments.insert(j.name.clone(), j);
+        }
+
+        let one = &index.judgments["move_places"].rules[0];
+        assert_eq!(invoked_judgments(one, &index), vec!["move_place"]);
+        let callers = judgment_callers(&index);
+        assert_eq!(callers["move_place"], vec![("move_places", "one")]);
+        assert!(!callers.contains_key("move_places"));
+
+        let output = render_appendix(&index);
+        assert!(
+            output.starts_with("# Judgment reference"),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("## `src/type_system/places.rs`"),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("id=\"judgment-move_place--give\""),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("Invoked by [`move_places::one`](#judgment-move_places--one)."),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("Invokes [`move_place`](#judgment-move_place)."),
+            "output: {output}"
+        );
+        // Files are listed in order.
+        let expressions = output.find("expressions.rs").unwrap();
+        assert!(expressions < output.find("places.rs").unwrap());
+    }
+
+    const DADA_SUM: &str = "class Main {
+    fn main(given self) -> Int {
+        let x = 10;
+        print(x.give);
+        x.give + 20;
+    }
+}
+";
+
+    const DADA_GIVE_TWICE: &str = "class Data { }
+
+class Main {
+    fn main(given self) -> Data {
+        let d = new Data();
+        d.give;
+        d.give;
+    }
+}
+";
+
+    #[test]
+    fn test_parse_dada_block() {
+        assert_eq!(DadaBlock::parse("").unwrap(), DadaBlock::default());
+        assert_eq!(
+            DadaBlock::parse(r#" output="1\n2" result="Point { x: 1 }" trace"#).unwrap(),
+            DadaBlock {
+                type_error: false,
+                output: Some("1\n2".to_string()),
+                result: Some("Point { x: 1 }".to_string()),
+                trace: true,
+            }
+        );
+        assert!(DadaBlock::parse("type-eror").is_err());
+        assert!(DadaBlock::parse(r#"type-error result="1""#).is_err());
+    }
+
+    #[test]
+    fn test_dada_blocks_are_checked() {
+        let input = format!(
+            "```dada output=\"10\" result=\"30\" trace\n{DADA_SUM}```\n\n\
+             ```dada type-error\n{DADA_GIVE_TWICE}```\n"
+        );
+        let mut report = RefReport::default();
+        let output = check_dada_blocks(&input, &mut report);
+        assert!(
+            report.failed_examples.is_empty(),
+            "{:?}",
+            report.failed_examples
+        );
+        assert!(output.contains("```dada\nclass Main"), "output: {output}");
+        assert!(
+            output.contains("returns <code>30</code>"),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("Trace: enter Main.main"),
+            "output: {output}"
+        );
+        assert!(
+            output.contains("rejected by the type checker"),
+            "output: {output}"
+        );
+    }
+
+    #[test]
+    fn test_dada_block_mismatch_is_reported() {
+        let input = format!(
+            "```dada\n{DADA_GIVE_TWICE}```\n\n\
+             ```dada type-error\n{DADA_SUM}```\n\n\
+             ```dada result=\"31\"\n{DADA_SUM}```\n"
+        );
+        let mut report = RefReport {
+            chapter: "Giving".to_string(),
+            ..RefReport::default()
+        };
+        let output = check_dada_blocks(&input, &mut report);
+        assert_eq!(
+            report.failed_examples.len(),
+            3,
+            "{:?}",
+            report.failed_examples
+        );
+        assert!(
+            report.failed_examples[0].starts_with(
+                "`dada` block at line 1 of chapter `Giving`: the program does not type check"
+            ),
+            "{:?}",
+            report.failed_examples
+        );
+        // The first `d.give` fails, because `d` is given again afterwards.
+        assert!(
+            report.failed_examples[0].contains("on line 6 of the block:"),
+            "{:?}",
+            report.failed_examples
+        );
+        assert!(
+            report.failed_examples[2].ends_with("returned `30`"),
+            "{:?}",
+            report.failed_examples
+        );
+        assert!(output.contains("block failed"), "output: {output}");
+    }
+
+    #[test]
+    fn test_coverage_report() {
+        let index = make_index();
+        let coverage = Coverage::parse(
+            "applied\tmove_place\tgive\t3\n\
+             failed\tmove_place\tcopy\t2\n\
+             applied\tprove_is_copy\tclass\t1\n",
+        )
+        .unwrap();
+        assert_eq!(
+            coverage_report(&index, &coverage),
+            format!(
+                "1 of 2 rules were applied\n\
+                 \n\
+                 Never applied:\n  \
+                 move_place::copy (failed 2 time(s)) \
+                 {GITHUB_BASE}/src/type_system/expressions.rs#L15\n"
+            )
+        );
+    }
 }
@@ -0,0 +1,104 @@
+//! Rule coverage (`DADA_COVERAGE`).
+//!
+//! [`Coverage`] counts, for each `(judgment, rule)` pair, how many times the rule
+//! was applied in a successful derivation and how many times it failed in a failed
+//! one. When the `DADA_COVERAGE` environment variable is set, the test helpers in
+//! [`crate::test_util`] append the coverage of each program they type check to the
+//! file it names, so that a whole `cargo test` run adds up to a single report
+//! (see [`Coverage::parse`]). `mdbook-judgment coverage` joins that report with the
+//! rules found in the source and lists the rules that were never applied.
+
+use formality_core::judgment::{FailedJudgment, ProofTree, RuleFailureCause};
+use formality_core::{Map, ProvenSet};
+
+use crate::profile::judgment_name;
+
+#[cfg(test)]
+mod tests;
+
+/// A judgment name and the name of one of its rules.
+pub type RuleId = (String, String);
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// Number of times each rule appears in a successful derivation.
+    pub applied: Map<RuleId, u64>,
+
+    /// Number of times each rule appears as a failed rule of a failed derivation.
+    pub failed: Map<RuleId, u64>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the rules used by each derivation in `result`, or the rules that
+    /// failed if there is none.
+    pub fn record(&mut self, result: &ProvenSet<()>) {
+        match result.clone().into_map() {
+            Ok(proofs) => {
+                for proof_tree in proofs.values() {
+                    self.record_proof_tree(proof_tree);
+                }
+            }
+            Err(failed) => self.record_failed_judgment(&failed),
+        }
+    }
+
+    pub fn record_proof_tree(&mut self, proof_tree: &ProofTree) {
+        if let Some(rule) = &proof_tree.rule_name {
+            let judgment = judgment_name(&proof_tree.judgment).to_string();
+            *self.applied.entry((judgment, rule.clone())).or_default() += 1;
+        }
+        for child in &proof_tree.children {
+            self.record_proof_tree(child);
+        }
+    }
+
+    pub fn record_failed_judgment(&mut self, failed: &FailedJudgment) {
+        let judgment = judgment_name(&failed.judgment);
+        for rule in &failed.failed_rules {
+            if let Some((rule_name, _index)) = &rule.rule_name_index {
+                let rule_id = (judgment.to_string(), rule_name.clone());
+                *self.failed.entry(rule_id).or_default() += 1;
+            }
+            if let RuleFailureCause::FailedJudgment(cause) = &rule.cause {
+                self.record_failed_judgment(cause);
+            }
+        }
+    }
+
+    /// One `applied|failed <tab> judgment <tab> rule <tab> count` line per pair.
+    /// Rule names can contain spaces, hence the tabs.
+    pub fn to_lines(&self) -> String {
+        let applied = self.applied.iter().map(|pair| ("applied", pair));
+        let failed = self.failed.iter().map(|pair| ("failed", pair));
+        applied
+            .chain(failed)
+            .map(|(kind, ((judgment, rule), count))| {
+                format!("{kind}\t{judgment}\t{rule}\t{count}\n")
+            })
+            .collect()
+    }
+
+    /// Parse the lines written by [`Coverage::to_lines`], adding up the counts of
+    /// pairs that appear more than once (e.g., once per test).
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut coverage = Coverage::new();
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [kind, judgment, rule, count] = fields[..] else {
+                anyhow::bail!("malformed coverage line `{line}`");
+            };
+            let counts = match kind {
+                "applied" => &mut coverage.applied,
+                "failed" => &mut coverage.failed,
+                _ => anyhow::bail!("malformed coverage line `{line}`"),
+            };
+            let rule_id = (judgment.to_string(), rule.to_string());
+            *counts.entry(rule_id).or_default() += count.parse::<u64>()?;
+        }
+        Ok(coverage)
+    }
+}
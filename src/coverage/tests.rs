@@ -0,0 +1,74 @@
+use crate::test_util::parse_program;
+use crate::type_system;
+
+use super::Coverage;
+
+fn coverage_of(program: &str) -> Coverage {
+    let program = parse_program(&[program]);
+    let result = type_system::memo::with_memo(|| type_system::check_program(&program)).0;
+    let mut coverage = Coverage::new();
+    coverage.record(&result);
+    coverage
+}
+
+fn rule(judgment: &str, rule: &str) -> (String, String) {
+    (judgment.to_string(), rule.to_string())
+}
+
+#[test]
+fn records_applied_rules() {
+    let coverage = coverage_of(
+        "class Data { }
+        class Main {
+            fn main(given self) -> Data {
+                let d = new Data();
+                d.give;
+            }
+        }",
+    );
+    assert!(coverage.failed.is_empty(), "{coverage:?}");
+    assert!(
+        coverage.applied.contains_key(&rule("move_place", "give")),
+        "{coverage:?}"
+    );
+    assert!(
+        !coverage.applied.contains_key(&rule("move_place", "copy")),
+        "{coverage:?}"
+    );
+}
+
+#[test]
+fn records_failed_rules() {
+    let coverage = coverage_of(
+        "class Data { }
+        class Main {
+            fn main(given self) -> Data {
+                let d = new Data();
+                d.give;
+                d.give;
+            }
+        }",
+    );
+    assert!(coverage.applied.is_empty(), "{coverage:?}");
+    assert!(
+        coverage.failed.contains_key(&rule("move_place", "give")),
+        "{coverage:?}"
+    );
+}
+
+#[test]
+fn lines_add_up() {
+    let mut coverage = Coverage::new();
+    coverage.applied.insert(rule("sub_perms", "perm-or"), 2);
+    coverage.failed.insert(rule("move_place", "not live"), 1);
+    let lines = coverage.to_lines();
+    assert_eq!(
+        lines,
+        "applied\tsub_perms\tperm-or\t2\nfailed\tmove_place\tnot live\t1\n"
+    );
+
+    let twice = Coverage::parse(&format!("{lines}{lines}")).unwrap();
+    assert_eq!(twice.applied[&rule("sub_perms", "perm-or")], 4);
+    assert_eq!(twice.failed[&rule("move_place", "not live")], 2);
+    assert!(Coverage::parse("applied\tsub_perms\t2\n").is_err());
+}
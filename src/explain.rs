@@ -0,0 +1,398 @@
+//! Interactive exploration of type-checking derivations (`dada-model explain`).
+//!
+//! Both successful derivations ([`ProofTree`]) and failed ones ([`FailedJudgment`])
+//! are converted into a common [`ExplainNode`] tree, which can be explored as a
+//! collapsible tree: expanding nodes, jumping to failing leaves, filtering by
+//! judgment name, and showing the `Env` that a judgment was invoked with.
+//!
+//! In a terminal, [`tui`] does this full-screen. When input or output is not a
+//! terminal (e.g., a script piping commands in), the [`Explorer`] does it with
+//! typed commands, re-printing the outline after each.
+
+use std::io::{BufRead, Write};
+
+use formality_core::judgment::{FailedJudgment, FailedRule, ProofTree, RuleFailureCause};
+use formality_core::Set;
+
+pub mod tui;
+
+/// One node of a derivation, either proven or failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExplainNode {
+    /// Name of the judgment (e.g., `prove_is_copy`), or a short description for leaves.
+    pub name: String,
+
+    /// The full text of the judgment invocation, including its inputs.
+    pub text: String,
+
+    /// The rule that was applied (for proven nodes) or attempted (for failed nodes).
+    pub rule: Option<String>,
+
+    /// Source location of the rule, when known.
+    pub location: Option<String>,
+
+    /// True if this node is part of a failed derivation.
+    pub failed: bool,
+
+    pub children: Vec<ExplainNode>,
+}
+
+/// Path from the root to a node, as a list of child indices.
+type NodePath = Vec<usize>;
+
+impl ExplainNode {
+    /// Convert a successful derivation.
+    pub fn from_proof_tree(tree: &ProofTree) -> Self {
+        ExplainNode {
+            name: judgment_name(&tree.judgment),
+            text: tree.judgment.clone(),
+            rule: tree.rule_name.clone(),
+            location: None,
+            failed: false,
+            children: tree.children.iter().map(Self::from_proof_tree).collect(),
+        }
+    }
+
+    /// Convert a failed derivation. Each attempted rule becomes a child
+    /// of the judgment node; the cause of the failure becomes a child of the rule.
+    pub fn from_failed_judgment(failed: &FailedJudgment) -> Self {
+        ExplainNode {
+            name: judgment_name(&failed.judgment),
+            text: failed.judgment.clone(),
+            rule: None,
+            location: None,
+            failed: true,
+            children: failed
+                .failed_rules
+                .iter()
+                .map(Self::from_failed_rule)
+                .collect(),
+        }
+    }
+
+    fn from_failed_rule(rule: &FailedRule) -> Self {
+        let rule_name = rule
+            .rule_name_index
+            .as_ref()
+            .map(|(name, _index)| name.clone());
+        let location = Some(format!("{}:{}:{}", rule.file, rule.line, rule.column));
+        let cause = match &rule.cause {
+            RuleFailureCause::FailedJudgment(judgment) => Self::from_failed_judgment(judgment),
+            RuleFailureCause::IfFalse { expr } => {
+                Self::failed_leaf(format!("condition `{expr}` was false"))
+            }
+            RuleFailureCause::IfLetDidNotMatch { pattern, value } => {
+                Self::failed_leaf(format!("pattern `{pattern}` did not match value `{value}`"))
+            }
+            RuleFailureCause::Inapplicable { reason } => Self::failed_leaf(reason.clone()),
+            other => Self::failed_leaf(format!("{other:?}")),
+        };
+        ExplainNode {
+            name: rule_name.clone().unwrap_or_else(|| "<rule>".to_string()),
+            text: format!("rule {}", rule_name.as_deref().unwrap_or("<unnamed>")),
+            rule: rule_name,
+            location,
+            failed: true,
+            children: vec![cause],
+        }
+    }
+
+    fn failed_leaf(text: String) -> Self {
+        ExplainNode {
+            name: text.clone(),
+            text,
+            rule: None,
+            location: None,
+            failed: true,
+            children: vec![],
+        }
+    }
+
+    /// The node reached by following `path` from `self`.
+    fn at(&self, path: &[usize]) -> &ExplainNode {
+        path.iter().fold(self, |node, &i| &node.children[i])
+    }
+
+    /// Paths to every failing leaf, in depth-first order.
+    pub fn failing_leaves(&self) -> Vec<NodePath> {
+        let mut paths = vec![];
+        self.collect_paths(&mut vec![], &mut paths, &|node| {
+            node.failed && node.children.is_empty()
+        });
+        paths
+    }
+
+    /// Paths to every node whose judgment is named `name`, in depth-first order.
+    pub fn nodes_named(&self, name: &str) -> Vec<NodePath> {
+        let mut paths = vec![];
+        self.collect_paths(&mut vec![], &mut paths, &|node| node.name == name);
+        paths
+    }
+
+    fn collect_paths(
+        &self,
+        prefix: &mut NodePath,
+        paths: &mut Vec<NodePath>,
+        filter: &impl Fn(&ExplainNode) -> bool,
+    ) {
+        if filter(self) {
+            paths.push(prefix.clone());
+        }
+        for (i, child) in self.children.iter().enumerate() {
+            prefix.push(i);
+            child.collect_paths(prefix, paths, filter);
+            prefix.pop();
+        }
+    }
+
+    /// Paths to the nodes that are visible when the nodes in `expanded` are,
+    /// in depth-first order: the root, and the children of every visible
+    /// node that is expanded.
+    fn visible_paths(&self, expanded: &Set<NodePath>) -> Vec<NodePath> {
+        let mut paths = vec![];
+        self.collect_visible_paths(expanded, &mut vec![], &mut paths);
+        paths
+    }
+
+    fn collect_visible_paths(
+        &self,
+        expanded: &Set<NodePath>,
+        prefix: &mut NodePath,
+        paths: &mut Vec<NodePath>,
+    ) {
+        paths.push(prefix.clone());
+        if expanded.contains(prefix) {
+            for (i, child) in self.children.iter().enumerate() {
+                prefix.push(i);
+                child.collect_visible_paths(expanded, prefix, paths);
+                prefix.pop();
+            }
+        }
+    }
+
+    /// The `Env` this judgment was invoked with, if its inputs include one.
+    pub fn env(&self) -> Option<&str> {
+        extract_env(&self.text)
+    }
+}
+
+/// Extract the name of the judgment from its debug text:
+/// `prove_is_copy { a: ..., env: ... }` becomes `prove_is_copy`.
+fn judgment_name(text: &str) -> String {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    if end == 0 {
+        text.to_string()
+    } else {
+        text[..end].to_string()
+    }
+}
+
+/// Find the `env: Env { .. }` input in the text of a judgment and
+/// return the `Env { .. }` part, up to its matching closing brace.
+fn extract_env(text: &str) -> Option<&str> {
+    let start = text.find("env: Env {")? + "env: ".len();
+    let mut depth = 0;
+    for (i, ch) in text[start..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[start..start + i + 1]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Line-oriented explorer over an [`ExplainNode`] tree, for when there is no
+/// terminal for [`tui`].
+pub struct Explorer {
+    root: ExplainNode,
+
+    /// Paths of nodes that are currently expanded.
+    expanded: Set<NodePath>,
+
+    /// When set, only nodes with this judgment name are listed.
+    filter: Option<String>,
+
+    /// Index into `root.failing_leaves()` of the next leaf to jump to.
+    next_failure: usize,
+
+    /// Paths of the nodes in the most recent listing, indexed by the number shown to the user.
+    listed: Vec<NodePath>,
+}
+
+const HELP: &str = "\
+commands:
+  ls                 list the visible tree
+  open N / close N   expand or collapse node N
+  fail               jump to the next failing leaf
+  filter NAME        only list nodes for judgment NAME (e.g., prove_is_copy)
+  filter             clear the filter
+  env N              show the Env of node N
+  show N             show the full judgment of node N
+  help               show this message
+  quit               exit
+";
+
+impl Explorer {
+    pub fn new(root: ExplainNode) -> Self {
+        let mut expanded = Set::new();
+        expanded.insert(vec![]);
+        Explorer {
+            root,
+            expanded,
+            filter: None,
+            next_failure: 0,
+            listed: vec![],
+        }
+    }
+
+    /// Read commands from `input` until it is exhausted or the user quits,
+    /// writing the resulting views to `output`.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> anyhow::Result<()> {
+        if self.root.failed {
+            self.jump_to_failure(&mut output)?;
+        } else {
+            self.list(&mut output)?;
+        }
+
+        for line in input.lines() {
+            let line = line?;
+            let mut words = line.split_whitespace();
+            let command = words.next().unwrap_or("ls");
+            let argument = words.next();
+            match (command, argument) {
+                ("quit" | "q", _) => break,
+                ("help" | "h" | "?", _) => write!(output, "{HELP}")?,
+                ("ls" | "l", _) => self.list(&mut output)?,
+                ("open" | "o", Some(n)) => {
+                    if let Some(path) = self.listed_path(n, &mut output)? {
+                        self.expanded.insert(path);
+                        self.list(&mut output)?;
+                    }
+                }
+                ("close" | "c", Some(n)) => {
+                    if let Some(path) = self.listed_path(n, &mut output)? {
+                        self.expanded.remove(&path);
+                        self.list(&mut output)?;
+                    }
+                }
+                ("fail" | "f", _) => self.jump_to_failure(&mut output)?,
+                ("filter", name) => {
+                    self.filter = name.map(|n| n.to_string());
+                    self.list(&mut output)?;
+                }
+                ("env" | "e", Some(n)) => {
+                    if let Some(path) = self.listed_path(n, &mut output)? {
+                        match self.root.at(&path).env() {
+                            Some(env) => writeln!(output, "{env}")?,
+                            None => writeln!(output, "node {n} has no `Env` input")?,
+                        }
+                    }
+                }
+                ("show" | "s", Some(n)) => {
+                    if let Some(path) = self.listed_path(n, &mut output)? {
+                        let node = self.root.at(&path);
+                        writeln!(output, "{}", node.text)?;
+                        if let Some(location) = &node.location {
+                            writeln!(output, "at {location}")?;
+                        }
+                    }
+                }
+                _ => writeln!(output, "unrecognized command `{line}`; try `help`")?,
+            }
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// The path of the node numbered `n` in the current listing. If there is no
+    /// such node, reports that to the user and returns `None`, so that a typo does
+    /// not end the session.
+    fn listed_path(&self, n: &str, output: &mut impl Write) -> anyhow::Result<Option<NodePath>> {
+        let path = n.parse::<usize>().ok().and_then(|index| self.listed.get(index));
+        match path {
+            Some(path) => Ok(Some(path.clone())),
+            None => {
+                writeln!(output, "no node numbered `{n}` in the current listing; try `ls`")?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Expand every node along the path to the next failing leaf and list the tree.
+    fn jump_to_failure(&mut self, output: &mut impl Write) -> anyhow::Result<()> {
+        let leaves = self.root.failing_leaves();
+        if leaves.is_empty() {
+            writeln!(output, "no failing leaves")?;
+            return Ok(());
+        }
+        let index = self.next_failure % leaves.len();
+        let path = &leaves[index];
+        self.next_failure = index + 1;
+        for i in 0..path.len() {
+            self.expanded.insert(path[..i].to_vec());
+        }
+        self.filter = None;
+        self.list(output)?;
+        writeln!(
+            output,
+            "failing leaf {} of {}: {}",
+            index + 1,
+            leaves.len(),
+            self.root.at(path).text
+        )?;
+        Ok(())
+    }
+
+    /// Write the current view of the tree and remember the numbering.
+    fn list(&mut self, output: &mut impl Write) -> anyhow::Result<()> {
+        self.listed = match &self.filter {
+            Some(name) => self.root.nodes_named(name),
+            None => self.root.visible_paths(&self.expanded),
+        };
+        write!(output, "{}", self.render())?;
+        Ok(())
+    }
+
+    /// Render the nodes in `self.listed`, one per line.
+    fn render(&self) -> String {
+        let mut out = String::new();
+        for (index, path) in self.listed.iter().enumerate() {
+            let node = self.root.at(path);
+            let indent = match self.filter {
+                Some(_) => String::new(),
+                None => "  ".repeat(path.len()),
+            };
+            let marker = if node.children.is_empty() {
+                ' '
+            } else if self.expanded.contains(path) {
+                '-'
+            } else {
+                '+'
+            };
+            let status = if node.failed { "\u{2717}" } else { "\u{2713}" };
+            out.push_str(&format!(
+                "{indent}{marker} [{index}] {status} {}",
+                node.name
+            ));
+            if let Some(rule) = &node.rule {
+                if *rule != node.name {
+                    out.push_str(&format!(" (\"{rule}\")"));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests;
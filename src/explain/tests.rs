@@ -0,0 +1,169 @@
+use super::{ExplainNode, Explorer};
+
+pub(super) fn node(text: &str, failed: bool, children: Vec<ExplainNode>) -> ExplainNode {
+    ExplainNode {
+        name: super::judgment_name(text),
+        text: text.to_string(),
+        rule: None,
+        location: None,
+        failed,
+        children,
+    }
+}
+
+pub(super) fn sample_tree() -> ExplainNode {
+    node(
+        "check_program { program: ElaboratedProgram { .. } }",
+        true,
+        vec![node(
+            "check_class { decl: class Main { } }",
+            true,
+            vec![
+                node("check_field { decl: x : Int ; }", false, vec![]),
+                node(
+                    "prove_is_copy { a: Data, env: Env { local_variables: {x: Data} }, live_after: {} }",
+                    true,
+                    vec![],
+                ),
+            ],
+        )],
+    )
+}
+
+fn explore(tree: ExplainNode, commands: &str) -> String {
+    let mut output = vec![];
+    Explorer::new(tree)
+        .run(commands.as_bytes(), &mut output)
+        .unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn starts_at_failing_leaf() {
+    let output = explore(sample_tree(), "quit\n");
+    expect_test::expect![[r#"
+        - [0] ✗ check_program
+          - [1] ✗ check_class
+              [2] ✓ check_field
+              [3] ✗ prove_is_copy
+        failing leaf 1 of 1: prove_is_copy { a: Data, env: Env { local_variables: {x: Data} }, live_after: {} }
+    "#]]
+    .assert_eq(&output);
+}
+
+#[test]
+fn filter_and_show_env() {
+    let output = explore(sample_tree(), "filter prove_is_copy\nenv 0\nquit\n");
+    expect_test::expect![[r#"
+        - [0] ✗ check_program
+          - [1] ✗ check_class
+              [2] ✓ check_field
+              [3] ✗ prove_is_copy
+        failing leaf 1 of 1: prove_is_copy { a: Data, env: Env { local_variables: {x: Data} }, live_after: {} }
+          [0] ✗ prove_is_copy
+        Env { local_variables: {x: Data} }
+    "#]]
+    .assert_eq(&output);
+}
+
+#[test]
+fn collapse_node() {
+    let output = explore(sample_tree(), "close 1\nquit\n");
+    expect_test::expect![[r#"
+        - [0] ✗ check_program
+          - [1] ✗ check_class
+              [2] ✓ check_field
+              [3] ✗ prove_is_copy
+        failing leaf 1 of 1: prove_is_copy { a: Data, env: Env { local_variables: {x: Data} }, live_after: {} }
+        - [0] ✗ check_program
+          + [1] ✗ check_class
+    "#]]
+    .assert_eq(&output);
+}
+
+#[test]
+fn bad_node_number_does_not_end_session() {
+    let output = explore(sample_tree(), "open 99\nopen x\nshow 3\nquit\n");
+    expect_test::expect![[r#"
+        - [0] ✗ check_program
+          - [1] ✗ check_class
+              [2] ✓ check_field
+              [3] ✗ prove_is_copy
+        failing leaf 1 of 1: prove_is_copy { a: Data, env: Env { local_variables: {x: Data} }, live_after: {} }
+        no node numbered `99` in the current listing; try `ls`
+        no node numbered `x` in the current listing; try `ls`
+        prove_is_copy { a: Data, env: Env { local_variables: {x: Data} }, live_after: {} }
+    "#]]
+    .assert_eq(&output);
+}
+
+#[test]
+fn failing_leaf_numbers_wrap_around() {
+    let tree = node(
+        "check_class { decl: class Main { } }",
+        true,
+        vec![
+            node("prove_is_copy { a: Data }", true, vec![]),
+            node("prove_is_move { a: Data }", true, vec![]),
+        ],
+    );
+    let output = explore(tree, "fail\nfail\nquit\n");
+    let positions: Vec<&str> = output
+        .lines()
+        .filter(|line| line.starts_with("failing leaf"))
+        .collect();
+    expect_test::expect![[r#"
+        [
+            "failing leaf 1 of 2: prove_is_copy { a: Data }",
+            "failing leaf 2 of 2: prove_is_move { a: Data }",
+            "failing leaf 1 of 2: prove_is_copy { a: Data }",
+        ]
+    "#]]
+    .assert_debug_eq(&positions);
+}
+
+/// Explore the derivation of a program that really fails to type check.
+#[test]
+fn real_failed_judgment() {
+    let program = crate::test_util::parse_program(&[stringify!(
+        class Data { }
+
+        class Foo {
+            i: Data;
+        }
+
+        class Main {
+            fn main(given self) -> Int {
+                let foo = new Foo(new Data());
+                foo.i.give;
+                foo.i.give;
+            }
+        }
+    )]);
+    let failed = match crate::type_system::check_program(&program).into_map() {
+        Ok(_) => panic!("expected the program to fail to type check"),
+        Err(failed) => failed,
+    };
+    let root = ExplainNode::from_failed_judgment(&failed);
+    assert!(root.failed);
+    assert_eq!(root.name, "check_program");
+
+    let leaves = root.failing_leaves();
+    assert!(!leaves.is_empty());
+    assert!(
+        leaves
+            .iter()
+            .any(|path| root.at(path).text.contains("live_after.is_live(place)")),
+        "no failing leaf mentions the liveness condition"
+    );
+
+    // The session starts at the first failing leaf, and every node in that
+    // listing can be shown without ending the session.
+    let output = explore(root, "show 0\nshow 1\nquit\n");
+    let first = output
+        .lines()
+        .find(|line| line.starts_with("failing leaf "))
+        .expect("no failing leaf reported");
+    assert!(first.starts_with(&format!("failing leaf 1 of {}: ", leaves.len())));
+    assert!(output.contains("check_program {"));
+}
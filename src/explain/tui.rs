@@ -0,0 +1,380 @@
+//! The full-screen explorer, used when `dada-model explain` runs in a terminal.
+//!
+//! The derivation is shown as a collapsible tree with a cursor; below it, a
+//! detail pane shows the full judgment of the selected node, or its `Env`.
+//! [`Tui`] holds the state and turns [`Key`]s into changes of it, and
+//! [`Tui::render`] lays it out as lines of text, so both can be tested without
+//! a terminal. [`run`] connects them to the terminal with `crossterm`.
+
+use std::io::Write;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::{cursor, execute, queue, terminal};
+use formality_core::Set;
+
+use super::{ExplainNode, NodePath};
+
+#[cfg(test)]
+mod tests;
+
+/// A key press, as far as the explorer cares.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Enter,
+    Backspace,
+    Esc,
+    Char(char),
+}
+
+/// What the detail pane shows about the selected node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Detail {
+    Judgment,
+    Env,
+}
+
+const HELP: &str = "\
+↑/↓ move  →/← open/close  enter toggle  f next failure  / filter  e env  q quit";
+
+/// State of the full-screen explorer over an [`ExplainNode`] tree.
+pub struct Tui {
+    root: ExplainNode,
+
+    /// Paths of nodes that are currently expanded.
+    expanded: Set<NodePath>,
+
+    /// When set, only nodes with this judgment name are listed.
+    filter: Option<String>,
+
+    /// The filter being typed after `/`, if any.
+    editing_filter: Option<String>,
+
+    /// Index into `root.failing_leaves()` of the next leaf to jump to.
+    next_failure: usize,
+
+    /// Index of the selected node among the listed ones.
+    cursor: usize,
+
+    /// Index of the first listed node on screen.
+    scroll: usize,
+
+    detail: Detail,
+
+    /// Shown on the status line until the next key.
+    message: Option<String>,
+}
+
+impl Tui {
+    /// A new explorer. A failed derivation starts at its first failing leaf.
+    pub fn new(root: ExplainNode) -> Self {
+        let mut expanded = Set::new();
+        expanded.insert(vec![]);
+        let mut tui = Tui {
+            root,
+            expanded,
+            filter: None,
+            editing_filter: None,
+            next_failure: 0,
+            cursor: 0,
+            scroll: 0,
+            detail: Detail::Judgment,
+            message: None,
+        };
+        if tui.root.failed {
+            tui.jump_to_failure();
+        }
+        tui
+    }
+
+    /// Apply `key`. Returns false once the user quits.
+    pub fn handle_key(&mut self, key: Key) -> bool {
+        self.message = None;
+        if let Some(filter) = &mut self.editing_filter {
+            match key {
+                Key::Char(c) => filter.push(c),
+                Key::Backspace => {
+                    filter.pop();
+                }
+                Key::Enter => {
+                    let filter = self.editing_filter.take().unwrap_or_default();
+                    self.filter = Some(filter).filter(|name| !name.is_empty());
+                    self.cursor = 0;
+                    if self.filter.is_some() && self.listed().is_empty() {
+                        self.message = Some("no nodes match the filter".to_string());
+                    }
+                }
+                Key::Esc => self.editing_filter = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        let listed = self.listed();
+        match key {
+            Key::Char('q') | Key::Esc => return false,
+            Key::Up | Key::Char('k') => self.cursor = self.cursor.saturating_sub(1),
+            Key::Down | Key::Char('j') => self.cursor += 1,
+            Key::PageUp => self.cursor = self.cursor.saturating_sub(10),
+            Key::PageDown => self.cursor += 10,
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = listed.len(),
+            Key::Right | Key::Char('l') => {
+                if let Some(path) = listed.get(self.cursor) {
+                    self.expanded.insert(path.clone());
+                }
+            }
+            Key::Left | Key::Char('h') => {
+                if let Some(path) = listed.get(self.cursor) {
+                    if !self.expanded.remove(path) && self.filter.is_none() {
+                        // Already closed: go to the parent instead.
+                        if let Some((_, parent)) = path.split_last() {
+                            self.select(parent);
+                        }
+                    }
+                }
+            }
+            Key::Enter | Key::Char(' ') => {
+                if let Some(path) = listed.get(self.cursor) {
+                    if !self.expanded.remove(path) {
+                        self.expanded.insert(path.clone());
+                    }
+                }
+            }
+            Key::Char('f') => self.jump_to_failure(),
+            Key::Char('/') => self.editing_filter = Some(String::new()),
+            Key::Char('e') => {
+                self.detail = match self.detail {
+                    Detail::Judgment => Detail::Env,
+                    Detail::Env => Detail::Judgment,
+                }
+            }
+            Key::Char('?') => self.message = Some(HELP.to_string()),
+            _ => {}
+        }
+        self.clamp_cursor();
+        true
+    }
+
+    /// The screen, `width` columns by `height` rows, as one string per row.
+    /// The row of the selected node is the one whose index is returned too.
+    pub fn render(&mut self, width: usize, height: usize) -> (Vec<String>, Option<usize>) {
+        let listed = self.listed();
+        let height = height.max(3);
+
+        // A third of the screen for the detail pane, one row for the status line.
+        let detail_rows = height / 3;
+        let tree_rows = height - detail_rows - 1;
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        } else if self.cursor >= self.scroll + tree_rows {
+            self.scroll = self.cursor + 1 - tree_rows;
+        }
+
+        let mut rows = vec![];
+        for path in listed.iter().skip(self.scroll).take(tree_rows) {
+            rows.push(truncate(&self.node_line(path), width));
+        }
+        let selected = listed.get(self.cursor).map(|_| self.cursor - self.scroll);
+        rows.resize(tree_rows, String::new());
+
+        let detail = match listed.get(self.cursor).map(|path| self.root.at(path)) {
+            None => String::new(),
+            Some(node) => match self.detail {
+                Detail::Judgment => match &node.location {
+                    Some(location) => format!("{}\nat {location}", node.text),
+                    None => node.text.clone(),
+                },
+                Detail::Env => node
+                    .env()
+                    .map_or_else(|| "(no `Env` input)".to_string(), str::to_string),
+            },
+        };
+        let mut detail_lines = wrap(&detail, width);
+        detail_lines.resize(detail_rows, String::new());
+        rows.extend(detail_lines);
+
+        let status = match (&self.editing_filter, &self.message) {
+            (Some(filter), _) => format!("filter: {filter}"),
+            (None, Some(message)) => message.clone(),
+            (None, None) => {
+                let filter = match &self.filter {
+                    Some(name) => format!(" (filter: {name})"),
+                    None => String::new(),
+                };
+                let position = if listed.is_empty() {
+                    0
+                } else {
+                    self.cursor + 1
+                };
+                format!("{position}/{}{filter} -- press ? for help", listed.len())
+            }
+        };
+        rows.push(truncate(&status, width));
+
+        (rows, selected)
+    }
+
+    /// The paths of the nodes listed, in order: those matching the filter,
+    /// or else those that are visible given which nodes are expanded.
+    fn listed(&self) -> Vec<NodePath> {
+        match &self.filter {
+            Some(name) => self.root.nodes_named(name),
+            None => self.root.visible_paths(&self.expanded),
+        }
+    }
+
+    fn node_line(&self, path: &NodePath) -> String {
+        let node = self.root.at(path);
+        let indent = match self.filter {
+            Some(_) => String::new(),
+            None => "  ".repeat(path.len()),
+        };
+        let marker = if node.children.is_empty() {
+            ' '
+        } else if self.expanded.contains(path) {
+            '▾'
+        } else {
+            '▸'
+        };
+        let status = if node.failed { "\u{2717}" } else { "\u{2713}" };
+        let mut line = format!("{indent}{marker} {status} {}", node.name);
+        if let Some(rule) = &node.rule {
+            if *rule != node.name {
+                line.push_str(&format!(" (\"{rule}\")"));
+            }
+        }
+        line
+    }
+
+    /// Expand every node along the path to the next failing leaf and select it.
+    fn jump_to_failure(&mut self) {
+        let leaves = self.root.failing_leaves();
+        if leaves.is_empty() {
+            self.message = Some("no failing leaves".to_string());
+            return;
+        }
+        let index = self.next_failure % leaves.len();
+        let path = &leaves[index];
+        self.next_failure = index + 1;
+        for i in 0..path.len() {
+            self.expanded.insert(path[..i].to_vec());
+        }
+        self.filter = None;
+        self.select(path);
+        self.message = Some(format!("failing leaf {} of {}", index + 1, leaves.len()));
+    }
+
+    /// Move the cursor to the node at `path`, if it is listed.
+    fn select(&mut self, path: &[usize]) {
+        if let Some(index) = self.listed().iter().position(|listed| listed == path) {
+            self.cursor = index;
+        }
+    }
+
+    fn clamp_cursor(&mut self) {
+        self.cursor = self.cursor.min(self.listed().len().saturating_sub(1));
+    }
+}
+
+/// The first `width` characters of `line`.
+fn truncate(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+/// `text` broken into rows of at most `width` characters.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    text.lines()
+        .flat_map(|line| {
+            let chars: Vec<char> = line.chars().collect();
+            if chars.is_empty() {
+                vec![String::new()]
+            } else {
+                chars
+                    .chunks(width)
+                    .map(|row| row.iter().collect())
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+/// Explore `root` full-screen in the terminal until the user quits.
+pub fn run(root: ExplainNode) -> anyhow::Result<()> {
+    let mut tui = Tui::new(root);
+    let mut out = std::io::stdout();
+
+    terminal::enable_raw_mode()?;
+    // Put the terminal back however the loop ends.
+    struct Restore;
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let _ = execute!(
+                std::io::stdout(),
+                cursor::Show,
+                terminal::LeaveAlternateScreen
+            );
+            let _ = terminal::disable_raw_mode();
+        }
+    }
+    let _restore = Restore;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    loop {
+        let (width, height) = terminal::size()?;
+        draw(&mut tui, &mut out, width.into(), height.into())?;
+        let key = match event::read()? {
+            Event::Key(key) if key.kind != KeyEventKind::Release => key,
+            _ => continue,
+        };
+        let key = match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::PageUp => Key::PageUp,
+            KeyCode::PageDown => Key::PageDown,
+            KeyCode::Home => Key::Home,
+            KeyCode::End => Key::End,
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Esc => Key::Esc,
+            KeyCode::Char(c) => Key::Char(c),
+            _ => continue,
+        };
+        if !tui.handle_key(key) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn draw(tui: &mut Tui, out: &mut impl Write, width: usize, height: usize) -> anyhow::Result<()> {
+    let (rows, selected) = tui.render(width, height);
+    queue!(out, terminal::Clear(terminal::ClearType::All))?;
+    for (index, row) in rows.iter().enumerate() {
+        queue!(out, cursor::MoveTo(0, index as u16))?;
+        if Some(index) == selected {
+            queue!(
+                out,
+                SetAttribute(Attribute::Reverse),
+                Print(row),
+                SetAttribute(Attribute::Reset)
+            )?;
+        } else {
+            queue!(out, Print(row))?;
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use super::{Key, Tui};
+use crate::explain::tests::{node, sample_tree};
+
+/// Render `tui` at `width` by `height`, marking the selected row with `>`
+/// and every other row with `|`.
+fn screen(tui: &mut Tui, width: usize, height: usize) -> String {
+    let (rows, selected) = tui.render(width, height);
+    rows.iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let mark = if Some(index) == selected { '>' } else { '|' };
+            format!("{mark}{row}\n")
+        })
+        .collect()
+}
+
+fn press(tui: &mut Tui, keys: &[Key]) {
+    for &key in keys {
+        assert!(tui.handle_key(key), "{key:?} quit the explorer");
+    }
+}
+
+fn type_text(tui: &mut Tui, text: &str) {
+    press(tui, &text.chars().map(Key::Char).collect::<Vec<_>>());
+}
+
+#[test]
+fn starts_at_failing_leaf() {
+    let mut tui = Tui::new(sample_tree());
+    expect_test::expect![[r#"
+        |▾ ✗ check_program
+        |  ▾ ✗ check_class
+        |      ✓ check_field
+        >      ✗ prove_is_copy
+        |
+        |prove_is_copy { a: Data, env: Env { local_variables: {x: Dat
+        |a} }, live_after: {} }
+        |
+        |failing leaf 1 of 1
+    "#]]
+    .assert_eq(&screen(&mut tui, 60, 9));
+}
+
+#[test]
+fn left_goes_to_parent_then_collapses() {
+    let mut tui = Tui::new(sample_tree());
+    press(&mut tui, &[Key::Left, Key::Left]);
+    expect_test::expect![[r#"
+        |▾ ✗ check_program
+        >  ▸ ✗ check_class
+        |
+        |check_class { decl: class Main { } }
+        |2/2 -- press ? for help
+    "#]]
+    .assert_eq(&screen(&mut tui, 60, 5));
+
+    press(&mut tui, &[Key::Right, Key::Down]);
+    expect_test::expect![[r#"
+        |▾ ✗ check_program
+        |  ▾ ✗ check_class
+        >      ✓ check_field
+        |check_field { decl: x : Int ; }
+        |3/4 -- press ? for help
+    "#]]
+    .assert_eq(&screen(&mut tui, 60, 5));
+}
+
+#[test]
+fn filter_and_show_env() {
+    let mut tui = Tui::new(sample_tree());
+    press(&mut tui, &[Key::Char('/')]);
+    type_text(&mut tui, "prove_is_copy");
+    assert_eq!(tui.render(60, 5).0.last().unwrap(), "filter: prove_is_copy");
+    press(&mut tui, &[Key::Enter, Key::Char('e')]);
+    expect_test::expect![[r#"
+        >  ✗ prove_is_copy
+        |
+        |
+        |Env { local_variables: {x: Data} }
+        |1/1 (filter: prove_is_copy) -- press ? for help
+    "#]]
+    .assert_eq(&screen(&mut tui, 60, 5));
+}
+
+#[test]
+fn filter_without_matches_is_reported() {
+    let mut tui = Tui::new(sample_tree());
+    press(&mut tui, &[Key::Char('/')]);
+    type_text(&mut tui, "prove_is_mvoe");
+    press(&mut tui, &[Key::Backspace, Key::Backspace, Key::Char('e')]);
+    press(&mut tui, &[Key::Enter]);
+    assert_eq!(
+        tui.render(60, 5).0.last().unwrap(),
+        "no nodes match the filter"
+    );
+
+    // Esc abandons a filter being typed, leaving the listing as it was.
+    press(&mut tui, &[Key::Char('/'), Key::Char('x'), Key::Esc]);
+    assert_eq!(
+        tui.render(60, 5).0.last().unwrap(),
+        "0/0 (filter: prove_is_me) -- press ? for help"
+    );
+}
+
+#[test]
+fn failing_leaves_cycle() {
+    let tree = node(
+        "check_class { decl: class Main { } }",
+        true,
+        vec![
+            node("prove_is_copy { a: Data }", true, vec![]),
+            node("prove_is_move { a: Data }", true, vec![]),
+        ],
+    );
+    let mut tui = Tui::new(tree);
+    let mut positions = vec![];
+    for _ in 0..3 {
+        let (rows, selected) = tui.render(60, 6);
+        positions.push(format!("{}: {}", rows[5], rows[selected.unwrap()]));
+        press(&mut tui, &[Key::Char('f')]);
+    }
+    expect_test::expect![[r#"
+        [
+            "failing leaf 1 of 2:     ✗ prove_is_copy",
+            "failing leaf 2 of 2:     ✗ prove_is_move",
+            "failing leaf 1 of 2:     ✗ prove_is_copy",
+        ]
+    "#]]
+    .assert_debug_eq(&positions);
+}
+
+#[test]
+fn cursor_scrolls_into_view() {
+    let mut tui = Tui::new(sample_tree());
+    press(&mut tui, &[Key::Home]);
+    expect_test::expect![[r#"
+        >▾ ✗ check_program
+        |check_program { program: ElaboratedProgram { .. } }
+        |1/4 -- press ? for help
+    "#]]
+    .assert_eq(&screen(&mut tui, 60, 3));
+
+    press(&mut tui, &[Key::End]);
+    expect_test::expect![[r#"
+        >      ✗ prove_is_copy
+        |prove_is_copy { a: Data, env: Env { local_variables: {x: Dat
+        |4/4 -- press ? for help
+    "#]]
+    .assert_eq(&screen(&mut tui, 60, 3));
+}
+
+#[test]
+fn q_quits_unless_typing_a_filter() {
+    let mut tui = Tui::new(sample_tree());
+    press(&mut tui, &[Key::Char('/'), Key::Char('q'), Key::Esc]);
+    assert!(!tui.handle_key(Key::Char('q')));
+}
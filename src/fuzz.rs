@@ -0,0 +1,601 @@
+//! Differential fuzzing of the type checker against the interpreter.
+//!
+//! The type system is meant to be sound: a program that passes
+//! [`check_program`](crate::type_system::check_program) should never fault when
+//! run by the [`Interpreter`]. The fuzzer generates random programs built from
+//! classes with `given`/`share`/`shared` predicates, fields, methods and place
+//! expressions using `ref`/`mut`/`give`/`drop`, checks them, and runs the ones
+//! that pass. Most generated programs are rejected by the checker; the
+//! interesting ones are those that are accepted and then fault.
+//!
+//! Every such program is reduced to a minimal reproducer with
+//! [`reduce`](crate::reduce::reduce), for as long as the result still type
+//! checks and then faults ([`Predicate::Unsound`]).
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use crate::dada_lang;
+use crate::elaborator::ElaboratedProgram;
+use crate::grammar::Program;
+use crate::interpreter::{
+    with_stack, Interpreter, LimitExceeded, DEFAULT_FUEL, DEFAULT_MAX_CALL_DEPTH,
+};
+use crate::reduce::{self, Predicate};
+use crate::type_system;
+
+#[cfg(test)]
+mod tests;
+
+/// A small xorshift generator, so that a seed always produces the same program.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck at zero, and small seeds start out poorly mixed.
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..n`. `n` must be non-zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True with probability `1 / n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// A program being generated, in a form that keeps track of the classes and
+/// fields that later parts of the program can refer to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ProgramSpec {
+    classes: Vec<ClassSpec>,
+
+    /// The body of `Main.main`, which always ends with `0;`.
+    main: Vec<StatementSpec>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ClassSpec {
+    name: String,
+
+    /// `"given "`, `""` or `"shared "`.
+    predicate: &'static str,
+    fields: Vec<FieldSpec>,
+    methods: Vec<MethodSpec>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FieldSpec {
+    name: String,
+    ty: FieldTy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum FieldTy {
+    Int,
+
+    /// A permission (possibly empty) applied to an earlier class.
+    Class(&'static str, String),
+}
+
+/// A method returning `Int`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MethodSpec {
+    name: String,
+
+    /// Permission of `self`: `given`, `ref` or `mut`.
+    this_perm: &'static str,
+    body: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum StatementSpec {
+    /// A complete statement, including its `;`.
+    Simple(String),
+
+    /// `{ ... };`
+    Block(Vec<StatementSpec>),
+
+    /// `if cond { ... } else { ... };`
+    If(String, Vec<StatementSpec>, Vec<StatementSpec>),
+}
+
+impl ProgramSpec {
+    /// Render the program as Dada source.
+    fn to_source(&self) -> String {
+        let mut out = String::new();
+        for class in &self.classes {
+            out.push_str(&format!("{}class {} {{\n", class.predicate, class.name));
+            for field in &class.fields {
+                let ty = match &field.ty {
+                    FieldTy::Int => "Int".to_string(),
+                    FieldTy::Class(perm, name) => format!("{perm}{name}"),
+                };
+                out.push_str(&format!("    {}: {ty};\n", field.name));
+            }
+            for method in &class.methods {
+                out.push_str(&format!(
+                    "    fn {}({} self) -> Int {{\n",
+                    method.name, method.this_perm
+                ));
+                for statement in &method.body {
+                    out.push_str(&format!("        {statement}\n"));
+                }
+                out.push_str("    }\n");
+            }
+            out.push_str("}\n");
+        }
+        out.push_str("class Main {\n    fn main(given self) -> Int {\n");
+        render_statements(&self.main, 2, &mut out);
+        out.push_str("        0;\n    }\n}\n");
+        out
+    }
+
+    fn class(&self, name: &str) -> Option<&ClassSpec> {
+        self.classes.iter().find(|class| class.name == name)
+    }
+}
+
+fn render_statements(statements: &[StatementSpec], depth: usize, out: &mut String) {
+    let indent = "    ".repeat(depth);
+    for statement in statements {
+        match statement {
+            StatementSpec::Simple(text) => out.push_str(&format!("{indent}{text}\n")),
+            StatementSpec::Block(body) => {
+                out.push_str(&format!("{indent}{{\n"));
+                render_statements(body, depth + 1, out);
+                out.push_str(&format!("{indent}}};\n"));
+            }
+            StatementSpec::If(cond, if_true, if_false) => {
+                out.push_str(&format!("{indent}if {cond} {{\n"));
+                render_statements(if_true, depth + 1, out);
+                out.push_str(&format!("{indent}}} else {{\n"));
+                render_statements(if_false, depth + 1, out);
+                out.push_str(&format!("{indent}}};\n"));
+            }
+        }
+    }
+}
+
+/// The type the generator believes a variable or place has. Generated
+/// programs are not guaranteed to type check, so this is only a guide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum GenTy {
+    Int,
+    Class(String),
+}
+
+const ACCESSES: &[&str] = &["ref", "mut", "give", "drop"];
+
+/// Generate a random program from `seed`. The generator only produces
+/// programs that parse, so an error here is a bug in the generator.
+pub fn generate(seed: u64) -> anyhow::Result<Arc<Program>> {
+    dada_lang::try_term(&generate_spec(seed).to_source())
+}
+
+fn generate_spec(seed: u64) -> ProgramSpec {
+    Generator {
+        rng: Rng::new(seed),
+        spec: ProgramSpec {
+            classes: vec![],
+            main: vec![],
+        },
+        next_var: 0,
+    }
+    .generate()
+}
+
+struct Generator {
+    rng: Rng,
+    spec: ProgramSpec,
+    next_var: usize,
+}
+
+impl Generator {
+    fn generate(mut self) -> ProgramSpec {
+        let num_classes = 1 + self.rng.below(3);
+        for index in 0..num_classes {
+            let class = self.class(index);
+            self.spec.classes.push(class);
+        }
+
+        let mut scope = vec![];
+        let num_statements = 1 + self.rng.below(6);
+        self.spec.main = self.statements(&mut scope, num_statements, 0);
+        self.spec
+    }
+
+    fn class(&mut self, index: usize) -> ClassSpec {
+        let predicate = *self.rng.choose(&["given ", "", "shared "]);
+
+        let mut fields = vec![];
+        for field_index in 0..self.rng.below(3) {
+            // Fields only name earlier classes, so every class can be constructed.
+            let ty = if index > 0 && !self.rng.one_in(3) {
+                let class = self.rng.below(index);
+                let perm = *self.rng.choose(&["", "", "given ", "shared "]);
+                FieldTy::Class(perm, self.spec.classes[class].name.clone())
+            } else {
+                FieldTy::Int
+            };
+            fields.push(FieldSpec {
+                name: format!("f{field_index}"),
+                ty,
+            });
+        }
+
+        let mut methods = vec![];
+        for method_index in 0..self.rng.below(3) {
+            let this_perm = *self.rng.choose(&["given", "ref", "mut"]);
+            let mut body = vec![];
+            if let Some(field) = fields.get(self.rng.below(fields.len() + 1)) {
+                let access = self.rng.choose(ACCESSES);
+                body.push(format!("self.{}.{access};", field.name));
+            }
+            if self.rng.one_in(2) {
+                let access = self.rng.choose(ACCESSES);
+                body.push(format!("print(self.{access});"));
+            }
+            let int_field = fields.iter().find(|field| field.ty == FieldTy::Int);
+            match int_field {
+                Some(field) if self.rng.one_in(2) => {
+                    body.push(format!("self.{}.give;", field.name))
+                }
+                _ => body.push(format!("{};", self.rng.below(10))),
+            }
+            methods.push(MethodSpec {
+                name: format!("m{method_index}"),
+                this_perm,
+                body,
+            });
+        }
+
+        ClassSpec {
+            name: format!("C{index}"),
+            predicate,
+            fields,
+            methods,
+        }
+    }
+
+    fn statements(
+        &mut self,
+        scope: &mut Vec<(String, GenTy)>,
+        count: usize,
+        depth: usize,
+    ) -> Vec<StatementSpec> {
+        (0..count).map(|_| self.statement(scope, depth)).collect()
+    }
+
+    fn statement(&mut self, scope: &mut Vec<(String, GenTy)>, depth: usize) -> StatementSpec {
+        let kinds = if depth < 2 { 8 } else { 6 };
+        match self.rng.below(kinds) {
+            0 | 1 => {
+                let ty = self.any_ty();
+                let expr = self.expr(scope, &ty);
+                let name = format!("v{}", self.next_var);
+                self.next_var += 1;
+                scope.push((name.clone(), ty));
+                StatementSpec::Simple(format!("let {name} = {expr};"))
+            }
+            2 => match self.place(scope) {
+                Some((place, ty)) => {
+                    let expr = self.expr(scope, &ty);
+                    StatementSpec::Simple(format!("{place} = {expr};"))
+                }
+                None => self.let_new(scope),
+            },
+            3 => match self.place(scope) {
+                Some((place, _)) => {
+                    let access = self.rng.choose(ACCESSES);
+                    StatementSpec::Simple(format!("print({place}.{access});"))
+                }
+                None => self.let_new(scope),
+            },
+            4 => match self.method_call(scope) {
+                Some(call) => StatementSpec::Simple(format!("{call};")),
+                None => self.let_new(scope),
+            },
+            5 => match self.place(scope) {
+                Some((place, _)) => {
+                    let access = self.rng.choose(ACCESSES);
+                    StatementSpec::Simple(format!("{place}.{access};"))
+                }
+                None => self.let_new(scope),
+            },
+            6 => {
+                let count = 1 + self.rng.below(3);
+                let body = self.statements(&mut scope.clone(), count, depth + 1);
+                StatementSpec::Block(body)
+            }
+            _ => {
+                let cond = self.condition(scope);
+                let count = 1 + self.rng.below(3);
+                let if_true = self.statements(&mut scope.clone(), count, depth + 1);
+                let count = 1 + self.rng.below(3);
+                let if_false = self.statements(&mut scope.clone(), count, depth + 1);
+                StatementSpec::If(cond, if_true, if_false)
+            }
+        }
+    }
+
+    /// `let vN = new C(...);`, used when a statement needs a place but none is in scope.
+    fn let_new(&mut self, scope: &mut Vec<(String, GenTy)>) -> StatementSpec {
+        let class = self.rng.below(self.spec.classes.len());
+        let class = self.spec.classes[class].name.clone();
+        let expr = self.new_expr(scope, &class);
+        let name = format!("v{}", self.next_var);
+        self.next_var += 1;
+        scope.push((name.clone(), GenTy::Class(class)));
+        StatementSpec::Simple(format!("let {name} = {expr};"))
+    }
+
+    fn any_ty(&mut self) -> GenTy {
+        if self.rng.one_in(4) {
+            GenTy::Int
+        } else {
+            let class = self.rng.below(self.spec.classes.len());
+            GenTy::Class(self.spec.classes[class].name.clone())
+        }
+    }
+
+    /// A random place in scope, such as `v1` or `v1.f0.f1`, with its type.
+    fn place(&mut self, scope: &[(String, GenTy)]) -> Option<(String, GenTy)> {
+        if scope.is_empty() {
+            return None;
+        }
+        let (mut place, mut ty) = self.rng.choose(scope).clone();
+        while let GenTy::Class(name) = &ty {
+            let fields = &self.spec.class(name)?.fields;
+            if fields.is_empty() || self.rng.one_in(2) {
+                break;
+            }
+            let field = self.rng.choose(fields).clone();
+            place = format!("{place}.{}", field.name);
+            ty = match field.ty {
+                FieldTy::Int => GenTy::Int,
+                FieldTy::Class(_, class) => GenTy::Class(class),
+            };
+        }
+        Some((place, ty))
+    }
+
+    /// An expression that (probably) has type `ty`.
+    fn expr(&mut self, scope: &[(String, GenTy)], ty: &GenTy) -> String {
+        let candidates: Vec<(String, GenTy)> = (0..4)
+            .filter_map(|_| self.place(scope))
+            .filter(|(_, place_ty)| place_ty == ty)
+            .collect();
+        if let Some((place, _)) = candidates.first() {
+            if !self.rng.one_in(3) {
+                let access = self.rng.choose(ACCESSES);
+                return format!("{place}.{access}");
+            }
+        }
+        match ty {
+            GenTy::Int => match self.method_call(scope) {
+                Some(call) if self.rng.one_in(2) => call,
+                _ => format!("{}", self.rng.below(100)),
+            },
+            GenTy::Class(name) => self.new_expr(scope, name),
+        }
+    }
+
+    fn new_expr(&mut self, scope: &[(String, GenTy)], class: &str) -> String {
+        let fields = self.spec.class(class).unwrap().fields.clone();
+        let args: Vec<String> = fields
+            .iter()
+            .map(|field| {
+                let ty = match &field.ty {
+                    FieldTy::Int => GenTy::Int,
+                    FieldTy::Class(_, name) => GenTy::Class(name.clone()),
+                };
+                self.expr(scope, &ty)
+            })
+            .collect();
+        format!("new {class}({})", args.join(", "))
+    }
+
+    /// A call `place.access.m()` of a method on some place in scope.
+    fn method_call(&mut self, scope: &[(String, GenTy)]) -> Option<String> {
+        let (place, ty) = self.place(scope)?;
+        let GenTy::Class(class) = ty else {
+            return None;
+        };
+        let methods = &self.spec.class(&class)?.methods;
+        if methods.is_empty() {
+            return None;
+        }
+        let method = self.rng.choose(methods).name.clone();
+        let access = self.rng.choose(ACCESSES);
+        Some(format!("{place}.{access}.{method}()"))
+    }
+
+    fn condition(&mut self, scope: &[(String, GenTy)]) -> String {
+        match self.place(scope) {
+            Some((place, GenTy::Int)) => format!("{place}.ref >= {}", self.rng.below(10)),
+            _ => self.rng.choose(&["true", "false"]).to_string(),
+        }
+    }
+}
+
+/// What happened when a program was checked and run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The program did not parse (the generator should never produce these).
+    ParseError(String),
+
+    /// The type checker rejected the program.
+    TypeError,
+
+    /// The type checker itself panicked.
+    CheckerPanic(String),
+
+    /// The program type checked and ran to completion.
+    Ok,
+
+    /// The program type checked and then faulted (or panicked) in the interpreter.
+    Fault(String),
+
+    /// The program ran out of fuel or exceeded the call-depth limit in the interpreter.
+    /// This is not a soundness bug: the type system does not promise termination.
+    Diverged(String),
+}
+
+/// Parse, type check and (if it type checks) interpret `source`.
+pub fn run_case(source: &str) -> Outcome {
+    let program: Arc<Program> = match dada_lang::try_term(source) {
+        Ok(program) => program,
+        Err(e) => return Outcome::ParseError(e.to_string()),
+    };
+    run_program(&program)
+}
+
+/// Type check and (if it type checks) interpret `program`.
+pub fn run_program(program: &Program) -> Outcome {
+    let elaborated = ElaboratedProgram::elaborate(program);
+
+    let checked = panic::catch_unwind(AssertUnwindSafe(|| {
+        type_system::memo::with_memo(|| type_system::check_program(&elaborated).is_proven()).0
+    }));
+    match checked {
+        Ok(true) => {}
+        Ok(false) => return Outcome::TypeError,
+        Err(payload) => return Outcome::CheckerPanic(panic_message(&*payload)),
+    }
+
+    interpret(elaborated)
+}
+
+/// Run `program` in the interpreter, whether or not it type checks, with the
+/// default limits (on a large enough stack, see [`with_stack`]). Returns
+/// [`Outcome::Ok`], [`Outcome::Fault`] (panics in the interpreter are reported
+/// as faults), or [`Outcome::Diverged`].
+pub fn interpret(program: ElaboratedProgram) -> Outcome {
+    with_stack(|| {
+        let mut interpreter = Interpreter::new(program);
+        interpreter.set_fuel(DEFAULT_FUEL);
+        interpreter.set_max_call_depth(DEFAULT_MAX_CALL_DEPTH);
+        match panic::catch_unwind(AssertUnwindSafe(|| interpreter.interpret())) {
+            Ok(Ok(_)) => Outcome::Ok,
+            Ok(Err(e)) if e.downcast_ref::<LimitExceeded>().is_some() => {
+                Outcome::Diverged(format!("{e:#}"))
+            }
+            Ok(Err(e)) => Outcome::Fault(format!("{e:#}")),
+            Err(payload) => Outcome::Fault(format!("panic: {}", panic_message(&*payload))),
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
+
+/// A program that type checks and then faults.
+#[derive(Clone, Debug)]
+pub struct SoundnessBug {
+    pub seed: u64,
+
+    /// The generated program.
+    pub program: Arc<Program>,
+
+    /// The interpreter's error.
+    pub fault: String,
+
+    /// A minimal program that still type checks and then faults,
+    /// though not necessarily with the same error.
+    pub reduced: Program,
+}
+
+impl std::fmt::Display for SoundnessBug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "seed {}: type checks, then faults: {}",
+            self.seed, self.fault
+        )?;
+        writeln!(f, "reduced program:")?;
+        write!(f, "{:?}", self.reduced)
+    }
+}
+
+/// Counts of the outcomes seen by [`fuzz`].
+#[derive(Clone, Debug, Default)]
+pub struct FuzzSummary {
+    pub programs: usize,
+    pub parse_errors: usize,
+    pub type_errors: usize,
+    pub checker_panics: usize,
+    pub ok: usize,
+    pub diverged: usize,
+    pub bugs: Vec<SoundnessBug>,
+}
+
+impl std::fmt::Display for FuzzSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} programs: {} ok, {} type errors, {} diverged, {} soundness bugs, {} checker panics, {} parse errors",
+            self.programs,
+            self.ok,
+            self.type_errors,
+            self.diverged,
+            self.bugs.len(),
+            self.checker_panics,
+            self.parse_errors,
+        )
+    }
+}
+
+/// Generate and run the programs for `seeds`, reducing any soundness bugs found.
+pub fn fuzz(seeds: impl IntoIterator<Item = u64>) -> FuzzSummary {
+    let mut summary = FuzzSummary::default();
+    for seed in seeds {
+        summary.programs += 1;
+        let program = match generate(seed) {
+            Ok(program) => program,
+            Err(_) => {
+                summary.parse_errors += 1;
+                continue;
+            }
+        };
+        match run_program(&program) {
+            Outcome::ParseError(_) => summary.parse_errors += 1,
+            Outcome::TypeError => summary.type_errors += 1,
+            Outcome::CheckerPanic(_) => summary.checker_panics += 1,
+            Outcome::Ok => summary.ok += 1,
+            Outcome::Diverged(_) => summary.diverged += 1,
+            Outcome::Fault(fault) => {
+                let reduced = reduce::reduce(&program, |program| Predicate::Unsound.holds(program));
+                summary.bugs.push(SoundnessBug {
+                    seed,
+                    program,
+                    fault,
+                    reduced,
+                });
+            }
+        }
+    }
+    summary
+}
@@ -0,0 +1,58 @@
+use super::{fuzz, generate, generate_spec, run_case, run_program, Outcome};
+
+#[test]
+fn generation_is_deterministic() {
+    for seed in 0..10 {
+        assert_eq!(generate_spec(seed), generate_spec(seed));
+    }
+    assert_ne!(generate_spec(1).to_source(), generate_spec(2).to_source());
+}
+
+#[test]
+fn generated_programs_parse() {
+    for seed in 0..50 {
+        if let Err(e) = generate(seed) {
+            let source = generate_spec(seed).to_source();
+            panic!("seed {seed} produced a program that does not parse: {e}\n{source}");
+        }
+    }
+}
+
+#[test]
+fn run_case_reports_faults_after_type_checking() {
+    // Faults in programs the checker rejects are not soundness bugs.
+    let outcome = run_case(
+        "class Data {}
+        class Main {
+            fn main(given self) -> Int {
+                let d = new Data();
+                let e = d.give;
+                d.give;
+                0;
+            }
+        }",
+    );
+    assert_eq!(outcome, Outcome::TypeError);
+
+    let outcome = run_case(
+        "class Main {
+            fn main(given self) -> Int {
+                let x = 22;
+                x.give;
+            }
+        }",
+    );
+    assert_eq!(outcome, Outcome::Ok);
+}
+
+#[test]
+fn fuzz_finds_no_unexplained_outcomes() {
+    let summary = fuzz(0..20);
+    assert_eq!(summary.programs, 20);
+    assert_eq!(summary.parse_errors, 0, "{summary}");
+    for bug in &summary.bugs {
+        // A reproducer must itself be a soundness bug.
+        let outcome = run_program(&bug.reduced);
+        assert!(matches!(outcome, Outcome::Fault(_)), "{bug}\n{outcome:?}");
+    }
+}
@@ -0,0 +1,255 @@
+//! Post-run audit of the heap: leaks, unbalanced refcounts, and double frees.
+//!
+//! When a program finishes, every allocation except those holding its result
+//! should have been freed (all of its words uninitialized). [`Interpreter::audit`]
+//! checks that, checks that the refcount of each array matches the number of
+//! owning (`Given` or `Shared`) pointers to it, and reports any word that
+//! `uninitialize_words` was asked to uninitialize twice. Each problem names the
+//! allocation, the `new` or `array_new` expression it came from, and the type
+//! of the value it was allocated for.
+
+use std::collections::BTreeSet;
+
+use crate::grammar::{Expr, Ty};
+
+use super::{Flags, Interpreter, ObjectValue, Pointer, Word, ARRAY_REF_COUNT_OFFSET};
+
+#[cfg(test)]
+mod tests;
+
+/// What an allocation was made for.
+#[derive(Clone, Debug)]
+pub(super) struct AllocOrigin {
+    /// The type of the value the allocation was made to hold.
+    ty: Ty,
+
+    /// The `new` or `array_new` expression that created the value, if any.
+    /// Copies of a value (e.g., made by `give`) inherit the original's.
+    expr: Option<String>,
+}
+
+impl AllocOrigin {
+    pub(super) fn new(ty: &Ty) -> Self {
+        AllocOrigin {
+            ty: ty.clone(),
+            expr: None,
+        }
+    }
+
+    pub(super) fn ty(&self) -> &Ty {
+        &self.ty
+    }
+}
+
+impl std::fmt::Display for AllocOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{:?}`", self.ty)?;
+        if let Some(expr) = &self.expr {
+            write!(f, " from `{expr}`")?;
+        }
+        Ok(())
+    }
+}
+
+/// Something wrong with the heap after a run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditProblem {
+    /// An allocation that is neither freed nor part of the result.
+    Leak {
+        alloc: String,
+        words: String,
+        origin: String,
+    },
+
+    /// An array whose refcount differs from the number of pointers that own it.
+    RefCount {
+        alloc: String,
+        refcount: i64,
+        owners: usize,
+        origin: String,
+    },
+
+    /// An owning pointer to an array that has been freed.
+    Dangling {
+        pointer: String,
+        alloc: String,
+        origin: String,
+    },
+
+    /// A word that was uninitialized by `uninitialize_words` while already uninitialized.
+    DoubleFree { word: String, origin: String },
+}
+
+impl std::fmt::Display for AuditProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditProblem::Leak {
+                alloc,
+                words,
+                origin,
+            } => write!(f, "leak: {alloc} is still live: [{words}] ({origin})"),
+            AuditProblem::RefCount {
+                alloc,
+                refcount,
+                owners,
+                origin,
+            } => write!(
+                f,
+                "refcount: {alloc} has RefCount({refcount}) but {owners} owning pointer(s) ({origin})"
+            ),
+            AuditProblem::Dangling {
+                pointer,
+                alloc,
+                origin,
+            } => write!(
+                f,
+                "dangling: {pointer} owns {alloc}, which has been freed ({origin})"
+            ),
+            AuditProblem::DoubleFree { word, origin } => {
+                write!(f, "double free: {word} was uninitialized twice ({origin})")
+            }
+        }
+    }
+}
+
+/// The result of [`Interpreter::audit`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HeapAudit {
+    pub problems: Vec<AuditProblem>,
+}
+
+impl HeapAudit {
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl std::fmt::Display for HeapAudit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.problems.is_empty() {
+            return writeln!(f, "heap audit: no problems");
+        }
+        writeln!(f, "heap audit: {} problem(s)", self.problems.len())?;
+        for problem in &self.problems {
+            writeln!(f, "  {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Interpreter {
+    /// Record that the value at `pointer` was created by `expr`.
+    pub(super) fn set_alloc_expr(&mut self, pointer: Pointer, expr: &Expr) {
+        self.alloc_origins[pointer.index].expr = Some(format!("{expr:?}"));
+    }
+
+    /// Record that the allocation at `to` is a copy of the value at `from`.
+    pub(super) fn inherit_alloc_origin(&mut self, to: Pointer, from: Pointer) {
+        let expr = self.alloc_origins[from.index].expr.clone();
+        self.alloc_origins[to.index].expr = expr;
+    }
+
+    /// Check the heap after a run. `result` is the value the program returned,
+    /// or `None` if it faulted (in which case everything live is reported as leaked).
+    pub fn audit(&self, result: Option<&ObjectValue>) -> HeapAudit {
+        let mut problems = vec![];
+
+        // Everything reachable from the result belongs to it.
+        let mut reachable = BTreeSet::new();
+        let mut pending: Vec<usize> = result.iter().map(|value| value.pointer.index).collect();
+        while let Some(index) = pending.pop() {
+            if !reachable.insert(index) {
+                continue;
+            }
+            for word in &self.allocs[index].data {
+                if let Word::Pointer(p) | Word::MutRef(p) = word {
+                    pending.push(p.index);
+                }
+            }
+        }
+
+        for (index, alloc) in self.allocs.iter().enumerate() {
+            if alloc.is_freed() || reachable.contains(&index) {
+                continue;
+            }
+            let words: Vec<String> = alloc
+                .data
+                .iter()
+                .map(|word| super::fmt_word(word, self.hex_width()))
+                .collect();
+            problems.push(AuditProblem::Leak {
+                alloc: self.fmt_alloc(index),
+                words: words.join(", "),
+                origin: self.alloc_origins[index].to_string(),
+            });
+        }
+
+        // Count the owning pointers to each array, from allocations that are still live.
+        let mut owners = vec![0; self.allocs.len()];
+        for (index, alloc) in self.allocs.iter().enumerate() {
+            if alloc.is_freed() {
+                continue;
+            }
+            for (offset, word) in alloc.data.iter().enumerate() {
+                let Word::Pointer(target) = word else {
+                    continue;
+                };
+                // The flags of a boxed value sit just before its pointer.
+                let owned = matches!(
+                    offset.checked_sub(1).map(|o| alloc.data[o]),
+                    Some(Word::Flags(Flags::Given | Flags::Shared))
+                );
+                if !owned {
+                    continue;
+                }
+                if self.allocs[target.index].is_freed() {
+                    problems.push(AuditProblem::Dangling {
+                        pointer: self.fmt_pointer(Pointer { index, offset }),
+                        alloc: self.fmt_alloc(target.index),
+                        origin: self.alloc_origins[target.index].to_string(),
+                    });
+                } else {
+                    owners[target.index] += 1;
+                }
+            }
+        }
+        for (index, alloc) in self.allocs.iter().enumerate() {
+            if let Some(Word::RefCount(refcount)) = alloc.data.get(ARRAY_REF_COUNT_OFFSET) {
+                if *refcount != owners[index] as i64 {
+                    problems.push(AuditProblem::RefCount {
+                        alloc: self.fmt_alloc(index),
+                        refcount: *refcount,
+                        owners: owners[index],
+                        origin: self.alloc_origins[index].to_string(),
+                    });
+                }
+            }
+        }
+
+        for word in &self.double_frees {
+            problems.push(AuditProblem::DoubleFree {
+                word: self.fmt_pointer(*word),
+                origin: self.alloc_origins[word.index].to_string(),
+            });
+        }
+
+        HeapAudit { problems }
+    }
+
+    /// Hex digits used for allocation indices, as in `dump_heap`.
+    fn hex_width(&self) -> usize {
+        if self.allocs.len() <= 0x100 {
+            2
+        } else {
+            3
+        }
+    }
+
+    fn fmt_alloc(&self, index: usize) -> String {
+        format!("0x{index:0>width$x}", width = self.hex_width())
+    }
+
+    fn fmt_pointer(&self, pointer: Pointer) -> String {
+        format!("{}+{}", self.fmt_alloc(pointer.index), pointer.offset)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::interpreter::{Interpreter, Pointer, Word};
+use crate::test_util::parse_program;
+
+use super::AuditProblem;
+
+const POINT: &str = "
+    class Point {
+        x: Int;
+        y: Int;
+    }
+
+    class Main {
+        fn main(given self) -> Point {
+            let p = new Point(22, 44);
+            p.give;
+        }
+    }
+";
+
+/// Faults with the array, and the `mut` reference to it, still live.
+const ARRAY_FAULT: &str = "
+    class Main {
+        fn main(given self) -> Int {
+            let a = array_new[Int](2);
+            array_write[Int, mut[a]](a.mut, 3, 42);
+            0;
+        }
+    }
+";
+
+/// Reassigns a variable and a field whose values were moved out.
+const REASSIGN_MOVED: &str = "
+    class Data {
+        x: Int;
+    }
+
+    class Pair {
+        a: Data;
+        b: Data;
+    }
+
+    class Main {
+        fn main(given self) -> Pair {
+            let d = new Data(1);
+            let e = d.give;
+            d = new Data(2);
+            let p = new Pair(d.give, e.give);
+            let f = p.a.give;
+            p.a = new Data(3);
+            p.give;
+        }
+    }
+";
+
+fn interpreter(program: &str) -> Interpreter {
+    Interpreter::new(parse_program(&[program]))
+}
+
+#[test]
+fn finished_program_is_clean() {
+    let mut interpreter = interpreter(POINT);
+    let result = interpreter.interpret().unwrap();
+    let audit = interpreter.audit(Some(&result));
+    assert!(audit.is_clean(), "{audit}");
+    assert_eq!(audit.to_string(), "heap audit: no problems\n");
+}
+
+#[test]
+fn result_counts_as_a_leak_without_it() {
+    let mut interpreter = interpreter(POINT);
+    interpreter.interpret().unwrap();
+    let audit = interpreter.audit(None);
+    assert_eq!(audit.problems.len(), 1, "{audit}");
+    let problem = audit.problems[0].to_string();
+    assert!(
+        problem.contains("[Int(22), Int(44)]")
+            && problem.contains("`Point` from `new Point (22, 44)`"),
+        "{audit}"
+    );
+}
+
+#[test]
+fn fault_leaks_live_values() {
+    let mut interpreter = interpreter(ARRAY_FAULT);
+    interpreter.interpret().unwrap_err();
+    let audit = interpreter.audit(None);
+    let leaks: Vec<String> = audit
+        .problems
+        .iter()
+        .filter(|problem| matches!(problem, AuditProblem::Leak { .. }))
+        .map(|problem| problem.to_string())
+        .collect();
+    assert_eq!(leaks.len(), 3, "{audit}");
+    assert!(
+        leaks[0].contains("[RefCount(1), Capacity(2), Uninitialized, Uninitialized]")
+            && leaks[0].contains("`Array[Int]` from `array_new [Int](2)`"),
+        "{audit}"
+    );
+    assert!(
+        leaks[1].contains("[Flags(Given), Pointer(")
+            && leaks[1].contains("`Array[Int]` from `array_new [Int](2)`"),
+        "{audit}"
+    );
+    assert!(leaks[2].contains("[MutRef("), "{audit}");
+
+    // The refcount still matches the one owning pointer.
+    assert_eq!(leaks.len(), audit.problems.len(), "{audit}");
+}
+
+#[test]
+fn unbalanced_refcount() {
+    let mut interpreter = interpreter(ARRAY_FAULT);
+    interpreter.interpret().unwrap_err();
+    let index = interpreter
+        .allocs
+        .iter()
+        .position(|alloc| matches!(alloc.data.first(), Some(Word::RefCount(_))))
+        .unwrap();
+    interpreter.write_word(Pointer { index, offset: 0 }, Word::RefCount(2));
+
+    let audit = interpreter.audit(None);
+    assert!(
+        audit.problems.iter().any(|problem| matches!(
+            problem,
+            AuditProblem::RefCount {
+                refcount: 2,
+                owners: 1,
+                ..
+            }
+        )),
+        "{audit}"
+    );
+}
+
+#[test]
+fn double_free() {
+    let mut interpreter = interpreter(POINT);
+    let result = interpreter.interpret().unwrap();
+    interpreter.uninitialize_words(result.pointer, 1);
+    interpreter.uninitialize_words(result.pointer, 1);
+
+    let audit = interpreter.audit(Some(&result));
+    assert_eq!(audit.problems.len(), 1, "{audit}");
+    assert!(
+        audit.problems[0].to_string().starts_with("double free: "),
+        "{audit}"
+    );
+}
+
+/// Dropping a flat value twice is reported for each of its words; only
+/// end-of-scope cleanup skips the parts of a variable that were moved out.
+#[test]
+fn flat_double_free() {
+    let mut interpreter = interpreter(POINT);
+    let result = interpreter.interpret().unwrap();
+    interpreter.drop_object(result.clone()).unwrap();
+    interpreter.drop_object(result).unwrap();
+
+    let audit = interpreter.audit(None);
+    let double_frees = audit
+        .problems
+        .iter()
+        .filter(|problem| matches!(problem, AuditProblem::DoubleFree { .. }))
+        .count();
+    assert_eq!(double_frees, 2, "{audit}");
+    assert_eq!(double_frees, audit.problems.len(), "{audit}");
+}
+
+/// Overwriting a place whose value was moved out only drops what is left of
+/// it, so it is not reported as a double free.
+#[test]
+fn reassigning_a_moved_place_is_clean() {
+    let mut interpreter = interpreter(REASSIGN_MOVED);
+    let result = interpreter.interpret().unwrap();
+    let audit = interpreter.audit(Some(&result));
+    assert!(audit.is_clean(), "{audit}");
+}
@@ -0,0 +1,121 @@
+//! Places of the caller, as seen from a method call's frame.
+//!
+//! Each method call runs in a fresh frame whose env holds only the method's
+//! own variables, under their own names. The types of the receiver, the
+//! arguments and the generic parameters may still name places of the caller
+//! (e.g., `v` in `mut[v] Vec[Data]`). Those caller variables are imported into
+//! the callee's env as fresh variables (`@ fresh(0)`, ...), with their types,
+//! and the result's type is mapped back to the caller's names on return.
+//!
+//! Only the variables reachable from the parameters' types are imported, so
+//! passing a value or a reference on (`x.give`) costs the same at every depth
+//! of a recursion. A reborrow (`x.mut`) at each level is the exception: its
+//! type names `x`, whose type names the caller's place, and so on, so each
+//! call imports the whole chain and is linear in the depth of the recursion.
+
+use crate::grammar::{NamedTy, Parameter, Perm, Ty, Var};
+use crate::type_system::env::Env;
+use crate::type_system::in_flight::{InFlight, Transform};
+
+/// The substitution between the caller's variables and the fresh variables
+/// that stand for them in the callee's env.
+pub(super) struct CallerPlaces {
+    caller_vars: Vec<Var>,
+    callee_vars: Vec<Var>,
+}
+
+impl CallerPlaces {
+    /// Import every caller variable named by `parameters` (directly, or through
+    /// the types of other imported variables) into `env`, which must not have
+    /// any fresh variables yet.
+    pub(super) fn import(
+        caller_env: &Env,
+        mut env: Env,
+        parameters: &[Parameter],
+    ) -> anyhow::Result<(Env, Self)> {
+        let mut caller_vars: Vec<Var> = vec![];
+        let mut pending = vec![];
+        for parameter in parameters {
+            parameter_vars(parameter, &mut pending);
+        }
+        while let Some(var) = pending.pop() {
+            if caller_vars.contains(&var) {
+                continue;
+            }
+            ty_vars(caller_env.var_ty(&var)?, &mut pending);
+            caller_vars.push(var);
+        }
+
+        let callee_vars = (0..caller_vars.len()).map(Var::Fresh).collect();
+        let places = CallerPlaces {
+            caller_vars,
+            callee_vars,
+        };
+        for (caller_var, callee_var) in places.caller_vars.iter().zip(&places.callee_vars) {
+            let ty = places.to_callee(caller_env.var_ty(caller_var)?);
+            let (fresh_env, fresh_var) = env.push_fresh_variable(ty);
+            assert_eq!(
+                fresh_var, *callee_var,
+                "callee env already had fresh variables"
+            );
+            env = fresh_env;
+        }
+        Ok((env, places))
+    }
+
+    /// Rewrite `term` from the caller's names to the callee's.
+    pub(super) fn to_callee<T: InFlight>(&self, term: &T) -> T {
+        term.with_places_transformed(Transform::Rename(&self.caller_vars, &self.callee_vars))
+    }
+
+    /// Rewrite `term` from the callee's names back to the caller's.
+    pub(super) fn to_caller<T: InFlight>(&self, term: &T) -> T {
+        term.with_places_transformed(Transform::Rename(&self.callee_vars, &self.caller_vars))
+    }
+}
+
+fn parameter_vars(parameter: &Parameter, vars: &mut Vec<Var>) {
+    match parameter {
+        Parameter::Ty(ty) => ty_vars(ty, vars),
+        Parameter::Perm(perm) => perm_vars(perm, vars),
+    }
+}
+
+fn ty_vars(ty: &Ty, vars: &mut Vec<Var>) {
+    match ty {
+        Ty::NamedTy(NamedTy {
+            name: _,
+            parameters,
+        }) => {
+            for parameter in parameters {
+                parameter_vars(parameter, vars);
+            }
+        }
+        Ty::Var(_) => {}
+        Ty::ApplyPerm(perm, ty) => {
+            perm_vars(perm, vars);
+            ty_vars(ty, vars);
+        }
+    }
+}
+
+fn perm_vars(perm: &Perm, vars: &mut Vec<Var>) {
+    match perm {
+        Perm::Given | Perm::Shared | Perm::Var(_) => {}
+        Perm::Mv(places) | Perm::Rf(places) | Perm::Mt(places) => {
+            vars.extend(places.iter().map(|place| place.var.clone()));
+        }
+        Perm::Apply(left, right) => {
+            perm_vars(left, vars);
+            perm_vars(right, vars);
+        }
+        Perm::Or(perms) => {
+            for perm in perms {
+                perm_vars(perm, vars);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
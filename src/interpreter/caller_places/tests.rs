@@ -0,0 +1,119 @@
+use crate::elaborator::ElaboratedProgram;
+use crate::grammar::{Parameter, Ty, Var};
+use crate::test_util::parse_program;
+use crate::type_system::env::Env;
+
+use super::CallerPlaces;
+
+const DATA: &str = "class Data { }";
+
+fn ty(text: &str) -> Ty {
+    crate::dada_lang::term(text)
+}
+
+fn var(text: &str) -> Var {
+    crate::dada_lang::term(text)
+}
+
+/// An env with the local variables `vars`, given as `(name, type)`.
+fn env_with(program: &ElaboratedProgram, vars: &[(&str, &str)]) -> Env {
+    vars.iter()
+        .fold(Env::new(program.clone()), |env, (name, ty_text)| {
+            env.push_local_variable(var(name), ty(ty_text)).unwrap()
+        })
+}
+
+/// Import the places named by one argument of type `argument_ty` into a fresh callee env.
+fn import(program: &ElaboratedProgram, caller_env: &Env, argument_ty: &Ty) -> (Env, CallerPlaces) {
+    let callee_env = Env::new(program.clone());
+    CallerPlaces::import(
+        caller_env,
+        callee_env,
+        &[Parameter::Ty(argument_ty.clone())],
+    )
+    .unwrap()
+}
+
+/// A `mut[p]` argument imports `p`, and `d`, which the type of `p` names;
+/// `q` is not named at all, so it is not imported.
+#[test]
+fn mut_argument_imports_the_variables_its_type_names() {
+    let program = parse_program(&[DATA]);
+    let caller_env = env_with(
+        &program,
+        &[("d", "Data"), ("p", "mut[d] Data"), ("q", "Data")],
+    );
+    let (env, places) = import(&program, &caller_env, &ty("mut[p] Data"));
+
+    assert_eq!(places.caller_vars, vec![var("p"), var("d")]);
+    assert_eq!(
+        format!("{:?}", places.to_callee(&ty("mut[p] Data"))),
+        "mut [@ fresh(0)] Data"
+    );
+    assert_eq!(
+        format!("{:?}", env.var_ty(Var::Fresh(0)).unwrap()),
+        "mut [@ fresh(1)] Data"
+    );
+    assert_eq!(format!("{:?}", env.var_ty(Var::Fresh(1)).unwrap()), "Data");
+    assert!(env.var_ty(Var::Fresh(2)).is_err());
+}
+
+/// A `ref[p]` argument whose type names another caller variable.
+#[test]
+fn ref_argument_imports_the_variables_its_type_names() {
+    let program = parse_program(&[DATA]);
+    let caller_env = env_with(
+        &program,
+        &[("d", "Data"), ("e", "Data"), ("p", "ref[d, e] Data")],
+    );
+    let (env, places) = import(&program, &caller_env, &ty("ref[p] Data"));
+
+    assert_eq!(places.caller_vars.len(), 3);
+    for (caller_var, callee_var) in places.caller_vars.iter().zip(&places.callee_vars) {
+        let callee_ty = env.var_ty(callee_var).unwrap();
+        assert_eq!(
+            &places.to_caller(callee_ty),
+            caller_env.var_ty(caller_var).unwrap(),
+            "{caller_var:?}"
+        );
+    }
+    assert_eq!(
+        format!("{:?}", places.to_callee(&ty("ref[p] Data"))),
+        "ref [@ fresh(0)] Data"
+    );
+}
+
+/// A result type in the callee's names is mapped back to the caller's,
+/// leaving the callee's own variables alone.
+#[test]
+fn result_type_is_mapped_back_to_caller_names() {
+    let program = parse_program(&[DATA]);
+    let caller_env = env_with(&program, &[("d", "Data"), ("p", "mut[d] Data")]);
+    let (_env, places) = import(&program, &caller_env, &ty("mut[p] Data"));
+
+    let result_ty = places.to_callee(&ty("ref[d] Data"));
+    assert_eq!(format!("{result_ty:?}"), "ref [@ fresh(1)] Data");
+    assert_eq!(places.to_caller(&result_ty), ty("ref[d] Data"));
+    assert_eq!(places.to_caller(&ty("ref[x] Data")), ty("ref[x] Data"));
+}
+
+/// Each call of a deep recursion that passes a `mut` reference on (`x.give`)
+/// imports only the place it refers to, so the calls take constant time each
+/// and the whole recursion takes time linear in its depth.
+#[test]
+fn deep_recursion_imports_a_bounded_number_of_variables() {
+    let program = parse_program(&[DATA]);
+    let mut caller_env = env_with(&program, &[("d", "Data")]);
+    let mut argument_ty = ty("mut[d] Data");
+    for _ in 0..1000 {
+        let (env, places) = import(&program, &caller_env, &argument_ty);
+        assert_eq!(places.caller_vars.len(), 1);
+
+        // The callee binds the argument to `x`, and passes `x.give` to the next call.
+        argument_ty = places.to_callee(&argument_ty);
+        caller_env = env
+            .push_local_variable(var("x"), argument_ty.clone())
+            .unwrap();
+    }
+    assert_eq!(format!("{argument_ty:?}"), "mut [@ fresh(0)] Data");
+}
@@ -0,0 +1,269 @@
+//! Interactive step debugger for the interpreter (`dada-model debug`).
+//!
+//! When a [`Debugger`] is attached with [`Interpreter::set_debugger`], the
+//! interpreter consults it before evaluating each statement. If the debugger
+//! decides to pause (because it is stepping, or a breakpoint was hit), it reads
+//! commands until told to resume. While paused, the heap and the variables of
+//! the current [`StackFrame`] can be inspected.
+
+use std::io::{BufRead, Write};
+
+use crate::grammar::{Statement, Var};
+
+use super::heap_graph::{GraphFormat, HeapGraph};
+use super::{fmt_word, Interpreter, ObjectValue, Pointer, StackFrame};
+
+#[cfg(test)]
+mod tests;
+
+/// Where to pause next, besides breakpoints.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Mode {
+    /// Before the next statement.
+    Step,
+
+    /// Before the next statement at this call depth or shallower.
+    Next(usize),
+
+    /// Before the next statement at a call depth shallower than this.
+    Finish(usize),
+
+    /// Only at breakpoints.
+    Continue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Breakpoint {
+    /// Pause before the first statement of each call to `Class.method`.
+    Method(String),
+
+    /// Pause before the `N`th statement executed (as numbered when pausing).
+    Statement(usize),
+}
+
+impl std::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Method(name) => write!(f, "{name}"),
+            Breakpoint::Statement(n) => write!(f, "#{n}"),
+        }
+    }
+}
+
+const HELP: &str = "\
+commands:
+  step, s            run to the next statement, stepping into method calls
+  next, n            run to the next statement without stepping into method calls
+  finish, f          run until the current method returns
+  continue, c        run until a breakpoint is hit
+  break Class.method pause whenever `Class.method` is entered
+  break N            pause before statement #N
+  break              list breakpoints
+  delete             remove all breakpoints
+  print x, p x       show the value of variable `x`
+  frame, v           show the variables of the current frame and their words in memory
+  heap               show the live allocations
+  graph [dot]        draw the allocations reachable from this frame as a Mermaid
+                     (or, with `dot`, Graphviz) graph
+  where, bt          show the methods being executed
+  help               show this message
+  quit               stop the program
+";
+
+pub struct Debugger {
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+    mode: Mode,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl Debugger {
+    /// A debugger that pauses before the first statement, reads commands from
+    /// `input`, and writes to `output`. When `input` is exhausted, the program
+    /// runs to completion.
+    pub fn new(input: impl BufRead + 'static, output: impl Write + 'static) -> Self {
+        Debugger {
+            input: Box::new(input),
+            output: Box::new(output),
+            mode: Mode::Step,
+            breakpoints: vec![],
+        }
+    }
+
+    /// Called before `statement` is evaluated in `frame`. `entered_call` is true
+    /// for the first statement evaluated by a method call.
+    pub(super) fn before_statement(
+        &mut self,
+        interpreter: &Interpreter,
+        frame: &StackFrame,
+        statement: &Statement,
+        entered_call: bool,
+    ) -> anyhow::Result<()> {
+        let depth = interpreter.call_stack.len();
+        let step = interpreter.statements_executed;
+        let method = interpreter.call_stack.last().map_or("?", String::as_str);
+
+        let at_breakpoint = self.breakpoints.iter().any(|breakpoint| match breakpoint {
+            Breakpoint::Method(name) => entered_call && name == method,
+            Breakpoint::Statement(n) => *n == step,
+        });
+        let pause = at_breakpoint
+            || match self.mode {
+                Mode::Step => true,
+                Mode::Next(d) => depth <= d,
+                Mode::Finish(d) => depth < d,
+                Mode::Continue => false,
+            };
+        if !pause {
+            return Ok(());
+        }
+
+        writeln!(self.output, "#{step} {method}: {statement:?}")?;
+        loop {
+            write!(self.output, "(dada) ")?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                // No more commands: let the program finish.
+                writeln!(self.output)?;
+                self.mode = Mode::Continue;
+                self.breakpoints.clear();
+                return Ok(());
+            }
+
+            let mut words = line.split_whitespace();
+            let command = words.next().unwrap_or("step");
+            let argument = words.next();
+            match (command, argument) {
+                ("step" | "s", _) => {
+                    self.mode = Mode::Step;
+                    return Ok(());
+                }
+                ("next" | "n", _) => {
+                    self.mode = Mode::Next(depth);
+                    return Ok(());
+                }
+                ("finish" | "f", _) => {
+                    self.mode = Mode::Finish(depth);
+                    return Ok(());
+                }
+                ("continue" | "c", _) => {
+                    self.mode = Mode::Continue;
+                    return Ok(());
+                }
+                ("break" | "b", Some(target)) => {
+                    let breakpoint = match target.trim_start_matches('#').parse() {
+                        Ok(n) => Breakpoint::Statement(n),
+                        Err(_) => Breakpoint::Method(target.to_string()),
+                    };
+                    writeln!(self.output, "breakpoint at {breakpoint}")?;
+                    self.breakpoints.push(breakpoint);
+                }
+                ("break" | "b", None) => {
+                    if self.breakpoints.is_empty() {
+                        writeln!(self.output, "no breakpoints")?;
+                    }
+                    for breakpoint in &self.breakpoints {
+                        writeln!(self.output, "breakpoint at {breakpoint}")?;
+                    }
+                }
+                ("delete" | "d", _) => self.breakpoints.clear(),
+                ("print" | "p", Some(name)) => self.print_variable(interpreter, frame, name)?,
+                ("frame" | "v", _) => self.print_frame(interpreter, frame)?,
+                ("heap", _) => {
+                    for line in interpreter.dump_heap() {
+                        writeln!(self.output, "{line}")?;
+                    }
+                }
+                ("graph" | "g", format) => {
+                    let format = match format {
+                        Some("dot") => GraphFormat::Dot,
+                        _ => GraphFormat::Mermaid,
+                    };
+                    match HeapGraph::of_frame(interpreter, frame) {
+                        Ok(graph) => write!(self.output, "{}", graph.render(format))?,
+                        Err(e) => writeln!(self.output, "<error: {e}>")?,
+                    }
+                }
+                ("where" | "bt", _) => {
+                    for (index, method) in interpreter.call_stack.iter().enumerate().rev() {
+                        writeln!(self.output, "  {index}: {method}")?;
+                    }
+                }
+                ("help" | "h" | "?", _) => write!(self.output, "{HELP}")?,
+                ("quit" | "q", _) => anyhow::bail!("stopped in the debugger"),
+                _ => writeln!(
+                    self.output,
+                    "unrecognized command `{}`; try `help`",
+                    line.trim()
+                )?,
+            }
+        }
+    }
+
+    fn print_variable(
+        &mut self,
+        interpreter: &Interpreter,
+        frame: &StackFrame,
+        name: &str,
+    ) -> anyhow::Result<()> {
+        let found = frame
+            .variables
+            .iter()
+            .rev()
+            .find(|(var, _)| format!("{var:?}") == name);
+        match found {
+            Some((var, pointer)) => {
+                let value = describe_variable(interpreter, frame, var, *pointer);
+                writeln!(self.output, "{var:?}: {value}")?;
+            }
+            None => writeln!(self.output, "no variable `{name}` in this frame")?,
+        }
+        Ok(())
+    }
+
+    fn print_frame(&mut self, interpreter: &Interpreter, frame: &StackFrame) -> anyhow::Result<()> {
+        if frame.variables.is_empty() {
+            writeln!(self.output, "no variables")?;
+        }
+        for (var, pointer) in &frame.variables {
+            let value = describe_variable(interpreter, frame, var, *pointer);
+            let words = match frame.env.var_ty(var) {
+                Ok(ty) => match interpreter.size_of(&frame.env, ty) {
+                    Ok(size) => (0..size)
+                        .map(|offset| fmt_word(&interpreter.read_word_raw(*pointer + offset), 2))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    Err(e) => format!("<error: {e}>"),
+                },
+                Err(e) => format!("<error: {e}>"),
+            };
+            writeln!(self.output, "{var:?}: {value}")?;
+            writeln!(self.output, "    [{words}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// `var: ty = value`, or a description of why the value cannot be shown.
+fn describe_variable(
+    interpreter: &Interpreter,
+    frame: &StackFrame,
+    var: &Var,
+    pointer: Pointer,
+) -> String {
+    let ty = match frame.env.var_ty(var) {
+        Ok(ty) => ty.clone(),
+        Err(e) => return format!("<error: {e}>"),
+    };
+    let value = ObjectValue {
+        pointer,
+        ty: ty.clone(),
+    };
+    let shown = interpreter
+        .display_value(&frame.env, &value)
+        .unwrap_or_else(|e| format!("<error: {e}>"));
+    format!("{ty:?} = {shown}")
+}
+
@@ -0,0 +1,141 @@
+use std::cell::RefCell;
+use std::io::{Cursor, Write};
+use std::rc::Rc;
+
+use crate::interpreter::Interpreter;
+use crate::test_util::parse_program;
+use crate::type_system::env::Env;
+
+use super::Debugger;
+
+const PROGRAM: &str = "
+    class Point {
+        x: Int;
+        y: Int;
+
+        fn sum(ref self) -> Int {
+            self.x.give + self.y.give;
+        }
+    }
+
+    class Main {
+        fn main(given self) -> Int {
+            let p = new Point(1, 2);
+            let s = p.ref.sum();
+            s.give;
+        }
+    }
+";
+
+/// Collects what the debugger writes.
+#[derive(Clone, Default)]
+struct Transcript(Rc<RefCell<Vec<u8>>>);
+
+impl Write for Transcript {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Run `PROGRAM` under the debugger with `commands` as input.
+/// Returns the result of the program and the debugger transcript.
+fn debug(commands: &str) -> (anyhow::Result<String>, String) {
+    let program = parse_program(&[PROGRAM]);
+    let transcript = Transcript::default();
+    let mut interpreter = Interpreter::new(program.clone());
+    interpreter.set_debugger(Debugger::new(
+        Cursor::new(commands.to_string()),
+        transcript.clone(),
+    ));
+    let result = interpreter
+        .interpret()
+        .and_then(|value| interpreter.display_value(&Env::new(program), &value));
+    let transcript = String::from_utf8(transcript.0.borrow().clone()).unwrap();
+    (result, transcript)
+}
+
+#[test]
+fn step_enters_calls() {
+    let (result, transcript) = debug("step\nstep\nstep\n");
+    assert_eq!(result.unwrap(), "3");
+    assert!(
+        transcript.contains("#1 Main.main: let p = new Point (1, 2) ;"),
+        "{transcript}"
+    );
+    assert!(transcript.contains("#3 Point.sum:"), "{transcript}");
+}
+
+#[test]
+fn next_steps_over_calls() {
+    let (result, transcript) = debug("next\nnext\n");
+    assert_eq!(result.unwrap(), "3");
+    assert!(transcript.contains("#2 Main.main:"), "{transcript}");
+    assert!(transcript.contains("#4 Main.main:"), "{transcript}");
+    assert!(!transcript.contains("Point.sum:"), "{transcript}");
+}
+
+#[test]
+fn finish_returns_to_caller() {
+    let (_, transcript) = debug("step\nstep\nfinish\n");
+    assert!(transcript.contains("#3 Point.sum:"), "{transcript}");
+    assert!(transcript.contains("#4 Main.main:"), "{transcript}");
+}
+
+#[test]
+fn break_on_method() {
+    let (result, transcript) = debug("break Point.sum\ncontinue\nwhere\ncontinue\n");
+    assert_eq!(result.unwrap(), "3");
+    assert!(
+        transcript.contains("breakpoint at Point.sum"),
+        "{transcript}"
+    );
+    assert!(transcript.contains("#3 Point.sum:"), "{transcript}");
+    assert!(
+        transcript.contains("  1: Point.sum\n  0: Main.main\n"),
+        "{transcript}"
+    );
+}
+
+#[test]
+fn break_on_statement() {
+    let (_, transcript) = debug("break 4\ncontinue\n");
+    assert!(transcript.contains("#4 Main.main:"), "{transcript}");
+    assert!(!transcript.contains("#3 "), "{transcript}");
+}
+
+#[test]
+fn print_variables_and_frame() {
+    let (_, transcript) = debug("next\nnext\nprint p\nprint s\nframe\n");
+    assert!(
+        transcript.contains("p: Point = Point { x: 1, y: 2 }"),
+        "{transcript}"
+    );
+    assert!(transcript.contains("s: Int = 3"), "{transcript}");
+    assert!(transcript.contains("[Int(1), Int(2)]"), "{transcript}");
+}
+
+#[test]
+fn quit_stops_the_program() {
+    let (result, _) = debug("quit\n");
+    let error = result.unwrap_err();
+    assert!(
+        format!("{error:#}").contains("stopped in the debugger"),
+        "{error:#}"
+    );
+}
+
+#[test]
+fn graph_draws_the_frame() {
+    let (_, transcript) = debug("next\nnext\ngraph\n");
+    assert!(transcript.contains("flowchart LR"), "{transcript}");
+    assert!(
+        transcript.contains("p.x: Int = Int(1)<br/>p.y: Int = Int(2)"),
+        "{transcript}"
+    );
+    assert!(transcript.contains("roots -->|\"p\"|"), "{transcript}");
+}
@@ -0,0 +1,326 @@
+//! Running Dada code from Rust, without going through `Main.main`.
+//!
+//! [`Interpreter::interpret`] runs a whole program. To drive a program from
+//! Rust instead (e.g., from a property test), build the receiver and the
+//! arguments with [`Interpreter::alloc_value`] or [`Interpreter::instantiate`],
+//! call a method with [`Interpreter::call`], and read the result back with
+//! [`Interpreter::read_value`]. A [`Value`] mirrors what a value holds, with
+//! its permissions stripped.
+
+use formality_core::Upcast;
+
+use crate::grammar::{FieldId, MethodId, NamedTy, Parameter, Ty, TypeName, ValueId};
+use crate::type_system::in_flight::InFlight;
+use crate::type_system::liveness::LivePlaces;
+use crate::type_system::memo;
+use crate::type_system::subtypes::sub;
+
+use super::{
+    extract_array_element_ty, Alloc, Interpreter, ObjectValue, Pointer, StackFrame, Word,
+    ARRAY_CAPACITY_OFFSET, ARRAY_ELEMENTS_OFFSET, POINTER_DATA_OFFSET,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The contents of a Dada value, as Rust data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+
+    /// A tuple; `()` is the empty tuple.
+    Tuple(Vec<Value>),
+
+    /// An instance of a class, with its fields in declaration order.
+    Object {
+        class: String,
+        fields: Vec<(String, Value)>,
+    },
+
+    /// The elements of an array (read only).
+    Array(Vec<Value>),
+
+    /// A word that was moved out or never written (read only).
+    Uninitialized,
+}
+
+impl Interpreter {
+    /// Allocate a copy of `value`. Objects must be of classes without generic
+    /// parameters (use [`Interpreter::instantiate`] for the others), and their
+    /// fields must be given in declaration order.
+    pub fn alloc_value(&mut self, value: &Value) -> anyhow::Result<ObjectValue> {
+        let mut words = vec![];
+        let ty = self.value_words(value, &mut words)?;
+        let pointer = self.alloc_raw(Alloc { data: words }, &ty);
+        Ok(ObjectValue { pointer, ty })
+    }
+
+    /// Create an instance of `class_name`, like `new Class[parameters](fields)`.
+    /// The field values are moved into the new object.
+    pub fn instantiate(
+        &mut self,
+        class_name: &str,
+        parameters: &[Ty],
+        fields: Vec<ObjectValue>,
+    ) -> anyhow::Result<ObjectValue> {
+        let class_name: ValueId = crate::dada_lang::try_term(class_name)?;
+        let parameters: Vec<Parameter> = parameters.iter().map(|ty| ty.upcast()).collect();
+        let env = self.base_env();
+        let object = self.instantiate_class(&env, &class_name, &parameters, &fields)?;
+        for field in &fields {
+            self.uninitialize(&env, field)?;
+        }
+        Ok(object)
+    }
+
+    /// Call the method `method_name` on `this`, which must be an instance
+    /// of a class, like `this.give.method_name[method_parameters](inputs)`.
+    /// As with a `given` receiver in Dada, `this` and `inputs` are given to
+    /// the method, which drops them; to inspect them afterwards, have the
+    /// method return them.
+    pub fn call(
+        &mut self,
+        this: ObjectValue,
+        method_name: &str,
+        method_parameters: &[Parameter],
+        inputs: Vec<ObjectValue>,
+    ) -> anyhow::Result<ObjectValue> {
+        self.fuel_used = 0;
+        let result =
+            memo::with_memo(|| self.call_from_rust(this, method_name, method_parameters, inputs)).0;
+        self.finish_events(result)
+    }
+
+    fn call_from_rust(
+        &mut self,
+        this: ObjectValue,
+        method_name: &str,
+        method_parameters: &[Parameter],
+        inputs: Vec<ObjectValue>,
+    ) -> anyhow::Result<ObjectValue> {
+        let method_id: MethodId = crate::dada_lang::try_term(method_name)?;
+        let Some(NamedTy {
+            name: TypeName::Id(class_name),
+            parameters,
+        }) = this.ty.to_named_ty()
+        else {
+            anyhow::bail!("cannot call method `{method_name}` on `{:?}`", this.ty);
+        };
+
+        self.check_call_arguments(
+            &this,
+            &class_name,
+            &parameters,
+            &method_id,
+            method_parameters,
+            &inputs,
+        )?;
+        let mut root_frame = StackFrame {
+            env: self.base_env(),
+            variables: Vec::new(),
+        };
+        self.call_method(
+            &mut root_frame,
+            &class_name,
+            &parameters,
+            &method_id,
+            method_parameters,
+            this,
+            inputs,
+        )
+    }
+
+    /// The interpreter trusts the type checker to have matched arguments
+    /// with parameters; from Rust, nothing has, so check here that each of
+    /// `inputs` is a subtype of its parameter's declared type, permissions
+    /// included, the way a method call in Dada is typed.
+    fn check_call_arguments(
+        &self,
+        this: &ObjectValue,
+        class_name: &ValueId,
+        class_parameters: &[Parameter],
+        method_id: &MethodId,
+        method_parameters: &[Parameter],
+        inputs: &[ObjectValue],
+    ) -> anyhow::Result<()> {
+        let method_data =
+            self.find_method(class_name, class_parameters, method_id, method_parameters)?;
+        if method_data.inputs.len() != inputs.len() {
+            anyhow::bail!(
+                "method `{method_id:?}` of class `{class_name:?}` has {} parameters but {} were provided",
+                method_data.inputs.len(),
+                inputs.len()
+            );
+        }
+
+        // As in the type checker, the receiver and each argument are stored
+        // in fresh variables, which the declared types are renamed to refer to.
+        let (mut env, this_var) = self.base_env().push_fresh_variable(&this.ty);
+        let mut input_tys: Vec<Ty> = method_data
+            .inputs
+            .iter()
+            .map(|input| input.ty.clone())
+            .collect::<Vec<_>>()
+            .with_this_stored_to(this_var);
+        for (index, (input, value)) in method_data.inputs.iter().zip(inputs).enumerate() {
+            let (next_env, input_var) = env.push_fresh_variable(&value.ty);
+            env = next_env;
+            input_tys = input_tys.with_var_stored_to(&input.name, input_var);
+            let input_ty = &input_tys[index];
+            if !sub(&env, LivePlaces::default(), &value.ty, input_ty).is_proven() {
+                anyhow::bail!(
+                    "argument `{:?}` of `{class_name:?}.{method_id:?}` has type `{input_ty:?}`, \
+                     not `{:?}`",
+                    input.name,
+                    value.ty
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop `value`, running `drop` bodies. Dropping it again is reported by
+    /// the heap audit as a double free.
+    pub fn drop_object(&mut self, value: ObjectValue) -> anyhow::Result<()> {
+        let env = self.base_env();
+        self.fuel_used = 0;
+        let result = memo::with_memo(|| self.drop_value(&env, &value)).0;
+        self.finish_events(result)
+    }
+
+    /// Read what `value` holds. `mut` references are followed.
+    pub fn read_value(&self, value: &ObjectValue) -> anyhow::Result<Value> {
+        memo::with_memo(|| self.read_value_at(value.pointer, &value.ty)).0
+    }
+
+    fn read_value_at(&self, pointer: Pointer, ty: &Ty) -> anyhow::Result<Value> {
+        let env = self.base_env();
+        if self.is_mut_ref_type(&env, ty) {
+            return match self.read_word_raw(pointer) {
+                Word::MutRef(target) => self.read_value_at(target, &ty.strip_perm()),
+                Word::Uninitialized => Ok(Value::Uninitialized),
+                word => anyhow::bail!("expected a `mut` reference, found `{word:?}`"),
+            };
+        }
+
+        let NamedTy { name, parameters } = self.named_ty(ty);
+        match name {
+            TypeName::Int => match self.read_word_raw(pointer) {
+                Word::Int(n) => Ok(Value::Int(n)),
+                Word::Uninitialized => Ok(Value::Uninitialized),
+                word => anyhow::bail!("expected an `Int`, found `{word:?}`"),
+            },
+            TypeName::Bool => match self.read_word_raw(pointer) {
+                Word::Int(n) => Ok(Value::Bool(n != 0)),
+                Word::Uninitialized => Ok(Value::Uninitialized),
+                word => anyhow::bail!("expected a `Bool`, found `{word:?}`"),
+            },
+            TypeName::Tuple(_) => {
+                let mut elements = vec![];
+                let mut offset = 0;
+                for parameter in &parameters {
+                    let Parameter::Ty(element_ty) = parameter else {
+                        anyhow::bail!("tuple parameter is not a type: `{parameter:?}`");
+                    };
+                    elements.push(self.read_value_at(pointer + offset, element_ty)?);
+                    offset += self.size_of(&env, element_ty)?;
+                }
+                Ok(Value::Tuple(elements))
+            }
+            TypeName::Id(class_name) => {
+                let class_decl = self.program.class_named(&class_name)?;
+                let class_data = class_decl.binder.instantiate_with(&parameters)?;
+                let mut fields = vec![];
+                let mut offset = 0;
+                for field in &class_data.fields {
+                    let value = self.read_value_at(pointer + offset, &field.ty)?;
+                    fields.push((format!("{:?}", field.name), value));
+                    offset += self.size_of(&env, &field.ty)?;
+                }
+                Ok(Value::Object {
+                    class: format!("{class_name:?}"),
+                    fields,
+                })
+            }
+            TypeName::Array => {
+                let element_ty = extract_array_element_ty(&parameters)?;
+                let array = match self.read_word_raw(pointer + POINTER_DATA_OFFSET) {
+                    Word::Pointer(array) => array,
+                    Word::Uninitialized => return Ok(Value::Uninitialized),
+                    word => anyhow::bail!("expected an array pointer, found `{word:?}`"),
+                };
+                let Word::Capacity(capacity) = self.read_word_raw(array + ARRAY_CAPACITY_OFFSET)
+                else {
+                    return Ok(Value::Uninitialized);
+                };
+                let element_size = self.size_of(&env, &element_ty)?;
+                let elements = (0..capacity)
+                    .map(|i| {
+                        let element = array + ARRAY_ELEMENTS_OFFSET + i * element_size;
+                        self.read_value_at(element, &element_ty)
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+                Ok(Value::Array(elements))
+            }
+        }
+    }
+
+    /// Append the words of `value` to `words`, returning its type.
+    fn value_words(&self, value: &Value, words: &mut Vec<Word>) -> anyhow::Result<Ty> {
+        match value {
+            Value::Int(n) => {
+                words.push(Word::Int(*n));
+                Ok(Ty::int())
+            }
+            Value::Bool(b) => {
+                words.push(Word::Int(if *b { 1 } else { 0 }));
+                Ok(Ty::bool())
+            }
+            Value::Tuple(elements) => {
+                let tys = elements
+                    .iter()
+                    .map(|element| self.value_words(element, words))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Ty::tuple(tys))
+            }
+            Value::Object { class, fields } => {
+                let class_name: ValueId = crate::dada_lang::try_term(class)?;
+                let class_decl = self.program.class_named(&class_name)?;
+                let class_data = class_decl.binder.instantiate_with(&[])?;
+                if class_data.fields.len() != fields.len() {
+                    anyhow::bail!(
+                        "class `{class}` has {} fields but {} were provided",
+                        class_data.fields.len(),
+                        fields.len()
+                    );
+                }
+                for (field, (name, value)) in class_data.fields.iter().zip(fields) {
+                    let name: FieldId = crate::dada_lang::try_term(name)?;
+                    if field.name != name {
+                        anyhow::bail!(
+                            "expected field `{:?}` of class `{class}`, found `{name:?}`",
+                            field.name
+                        );
+                    }
+                    let ty = self.value_words(value, words)?;
+                    if self.is_mut_ref_type(&self.base_env(), &field.ty)
+                        || field.ty.strip_perm() != ty
+                    {
+                        anyhow::bail!(
+                            "field `{name:?}` of class `{class}` has type `{:?}`, not `{ty:?}`",
+                            field.ty
+                        );
+                    }
+                }
+                Ok(Ty::NamedTy(NamedTy {
+                    name: TypeName::Id(class_name),
+                    parameters: vec![],
+                }))
+            }
+            Value::Array(_) | Value::Uninitialized => {
+                anyhow::bail!("cannot allocate `{value:?}` from Rust")
+            }
+        }
+    }
+}
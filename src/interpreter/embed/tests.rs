@@ -0,0 +1,185 @@
+use crate::grammar::{Parameter, Ty};
+use crate::interpreter::Interpreter;
+use crate::test_util::parse_program;
+
+use super::Value;
+
+const POINTS: &str = "
+    class Point {
+        x: Int;
+        y: Int;
+
+        fn swap(given self) -> Point {
+            new Point(self.y.give, self.x.give);
+        }
+
+        fn shift(given self, dx: Int) -> Point {
+            new Point(self.x.give + dx.give, self.y.give);
+        }
+
+        fn on_y_axis(given self) -> Bool {
+            self.x.give == 0;
+        }
+
+        fn keep[ty U](given self, u: given U) -> U {
+            u.give;
+        }
+
+        fn reset(given self, q: mut[self] Point) -> Int {
+            0;
+        }
+    }
+
+    class Pair[T] {
+        a: T;
+        b: T;
+
+        fn first(given self) -> T {
+            self.a.give;
+        }
+    }
+";
+
+fn interpreter() -> Interpreter {
+    Interpreter::new(parse_program(&[POINTS]))
+}
+
+fn point(x: i64, y: i64) -> Value {
+    Value::Object {
+        class: "Point".to_string(),
+        fields: vec![
+            ("x".to_string(), Value::Int(x)),
+            ("y".to_string(), Value::Int(y)),
+        ],
+    }
+}
+
+#[test]
+fn call_method_without_main() {
+    let mut interpreter = interpreter();
+    let p = interpreter.alloc_value(&point(22, 44)).unwrap();
+    let result = interpreter.call(p, "swap", &[], vec![]).unwrap();
+    assert_eq!(interpreter.read_value(&result).unwrap(), point(44, 22));
+}
+
+#[test]
+fn call_method_with_arguments() {
+    let mut interpreter = interpreter();
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let dx = interpreter.alloc_value(&Value::Int(10)).unwrap();
+    let result = interpreter.call(p, "shift", &[], vec![dx]).unwrap();
+    assert_eq!(interpreter.read_value(&result).unwrap(), point(12, 3));
+
+    let p = interpreter.alloc_value(&point(0, 7)).unwrap();
+    let result = interpreter.call(p, "on_y_axis", &[], vec![]).unwrap();
+    assert_eq!(interpreter.read_value(&result).unwrap(), Value::Bool(true));
+}
+
+#[test]
+fn arguments_of_the_wrong_type_are_rejected() {
+    let mut interpreter = interpreter();
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let dx = interpreter.alloc_value(&Value::Bool(true)).unwrap();
+    let error = interpreter.call(p, "shift", &[], vec![dx]).unwrap_err();
+    assert!(error.to_string().contains("has type `Int`"), "{error}");
+
+    let error = interpreter
+        .alloc_value(&Value::Object {
+            class: "Point".to_string(),
+            fields: vec![("x".to_string(), Value::Int(1))],
+        })
+        .unwrap_err();
+    assert!(error.to_string().contains("has 2 fields"), "{error}");
+}
+
+#[test]
+fn call_generic_method() {
+    let mut interpreter = interpreter();
+    let int: Ty = crate::dada_lang::try_term("Int").unwrap();
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let n = interpreter.alloc_value(&Value::Int(10)).unwrap();
+    let result = interpreter
+        .call(p, "keep", &[Parameter::Ty(int)], vec![n])
+        .unwrap();
+    assert_eq!(interpreter.read_value(&result).unwrap(), Value::Int(10));
+
+    // Without its parameter, the method cannot be instantiated.
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let n = interpreter.alloc_value(&Value::Int(10)).unwrap();
+    assert!(interpreter.call(p, "keep", &[], vec![n]).is_err());
+}
+
+/// Arguments are checked against their parameter's type with its permission,
+/// and a missing argument is an error rather than skipped.
+#[test]
+fn arguments_are_checked_against_the_full_declared_type() {
+    let mut interpreter = interpreter();
+    let bool_ty: Ty = crate::dada_lang::try_term("Bool").unwrap();
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let n = interpreter.alloc_value(&Value::Int(10)).unwrap();
+    let error = interpreter
+        .call(p, "keep", &[Parameter::Ty(bool_ty)], vec![n])
+        .unwrap_err();
+    assert!(error.to_string().contains("argument `u`"), "{error}");
+
+    // A `given` point is not a `mut` reference to one.
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let q = interpreter.alloc_value(&point(4, 5)).unwrap();
+    let error = interpreter.call(p, "reset", &[], vec![q]).unwrap_err();
+    assert!(error.to_string().contains("argument `q`"), "{error}");
+
+    let p = interpreter.alloc_value(&point(2, 3)).unwrap();
+    let error = interpreter.call(p, "shift", &[], vec![]).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("has 1 parameters but 0 were provided"),
+        "{error}"
+    );
+}
+
+#[test]
+fn instantiate_generic_class() {
+    let mut interpreter = interpreter();
+    let a = interpreter.alloc_value(&point(1, 2)).unwrap();
+    let b = interpreter.alloc_value(&point(3, 4)).unwrap();
+    let point_ty: Ty = crate::dada_lang::try_term("Point").unwrap();
+    let pair = interpreter
+        .instantiate("Pair", &[point_ty], vec![a, b])
+        .unwrap();
+    assert_eq!(
+        interpreter.read_value(&pair).unwrap(),
+        Value::Object {
+            class: "Pair".to_string(),
+            fields: vec![
+                ("a".to_string(), point(1, 2)),
+                ("b".to_string(), point(3, 4))
+            ],
+        }
+    );
+
+    let first = interpreter.call(pair, "first", &[], vec![]).unwrap();
+    assert_eq!(interpreter.read_value(&first).unwrap(), point(1, 2));
+    interpreter.drop_object(first).unwrap();
+}
+
+#[test]
+fn tuples_round_trip() {
+    let mut interpreter = interpreter();
+    let value = Value::Tuple(vec![Value::Int(1), Value::Bool(false), point(5, 6)]);
+    let tuple = interpreter.alloc_value(&value).unwrap();
+    assert_eq!(interpreter.read_value(&tuple).unwrap(), value);
+}
+
+/// Fuel is per call, so a host that calls into the interpreter many times
+/// does not run out.
+#[test]
+fn fuel_is_per_call() {
+    let mut interpreter = interpreter();
+    interpreter.set_fuel(1);
+    let mut p = interpreter.alloc_value(&point(1, 2)).unwrap();
+    for _ in 0..10 {
+        p = interpreter.call(p, "swap", &[], vec![]).unwrap();
+    }
+    assert_eq!(interpreter.read_value(&p).unwrap(), point(1, 2));
+}
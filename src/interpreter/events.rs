@@ -0,0 +1,271 @@
+//! A structured record of what the interpreter does, one JSON object per line.
+//!
+//! The `Trace:` lines in [`Interpreter::output`] are meant for people and are
+//! mixed in with `print` output. When an event sink is attached with
+//! [`Interpreter::set_event_sink`], the interpreter also writes an [`Event`] to
+//! it for each method call, allocation, word written, place accessed, value
+//! dropped, drop body run, and refcount change, so that runs can be compared by tools
+//! (e.g., across revisions of the model). Every run ends with an
+//! [`Event::End`], whether it returned or faulted.
+
+use std::io::Write;
+
+use serde_json::{json, Value};
+
+use crate::grammar::{Access, Place, Ty};
+
+use super::{fmt_word, Interpreter, Pointer, Word};
+
+#[cfg(test)]
+mod tests;
+
+/// Something the interpreter did. Pointers are `[index, offset]` pairs, and
+/// types are written as they appear in the trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// Entered a method; `call_id` numbers the calls made during the run.
+    /// `pointer` and `ty` are the receiver's.
+    Enter {
+        call_id: usize,
+        method: String,
+        pointer: Pointer,
+        ty: Ty,
+    },
+
+    /// Returned from a method; `pointer` and `ty` are the result's.
+    Exit {
+        call_id: usize,
+        method: String,
+        pointer: Pointer,
+        ty: Ty,
+    },
+
+    /// A panic unwound out of a method.
+    Unwind { call_id: usize, method: String },
+
+    /// A fault other than a panic left a method. Unlike a panic, it drops
+    /// nothing on the way out.
+    Abort { call_id: usize, method: String },
+
+    /// Made a new allocation for a value of type `ty`.
+    Alloc {
+        pointer: Pointer,
+        ty: Ty,
+        words: Vec<Word>,
+    },
+
+    /// Wrote `word` into an allocation made for a value of type `ty`.
+    Write {
+        pointer: Pointer,
+        ty: Ty,
+        word: Word,
+    },
+
+    /// Evaluated `place.give`, `place.ref`, `place.mut` or `place.drop`.
+    /// `pointer` is where the place's object data starts.
+    Access {
+        access: Access,
+        place: Place,
+        pointer: Pointer,
+        ty: Ty,
+    },
+
+    /// Dropped the value at `pointer`: a variable whose scope ended, or a
+    /// value that is no longer needed. Its drop body, if any, runs next.
+    Drop { pointer: Pointer, ty: Ty },
+
+    /// Ran the drop body of the value at `pointer`.
+    DropBody { pointer: Pointer, ty: Ty },
+
+    /// Changed the refcount of the array allocation at `pointer`.
+    RefCount {
+        pointer: Pointer,
+        ty: Ty,
+        from: i64,
+        to: i64,
+    },
+
+    /// The run, or a call or drop made from Rust, is over. `fault` is the
+    /// error it faulted with, if any. This is always the last event.
+    End { fault: Option<String> },
+}
+
+impl Event {
+    /// The event as a single line of JSON (without the newline).
+    pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+
+    /// The event as a JSON object, with `event` naming it and the other
+    /// fields in the order they are declared.
+    fn to_value(&self) -> Value {
+        match self {
+            Event::Enter {
+                call_id,
+                method,
+                pointer,
+                ty,
+            } => json!({
+                "event": "enter",
+                "call_id": call_id,
+                "method": method,
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+            }),
+            Event::Exit {
+                call_id,
+                method,
+                pointer,
+                ty,
+            } => json!({
+                "event": "exit",
+                "call_id": call_id,
+                "method": method,
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+            }),
+            Event::Unwind { call_id, method } => json!({
+                "event": "unwind",
+                "call_id": call_id,
+                "method": method,
+            }),
+            Event::Abort { call_id, method } => json!({
+                "event": "abort",
+                "call_id": call_id,
+                "method": method,
+            }),
+            Event::Alloc { pointer, ty, words } => json!({
+                "event": "alloc",
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+                "words": words.iter().map(|word| fmt_word(word, 1)).collect::<Vec<_>>(),
+            }),
+            Event::Write { pointer, ty, word } => json!({
+                "event": "write",
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+                "word": fmt_word(word, 1),
+            }),
+            Event::Access {
+                access,
+                place,
+                pointer,
+                ty,
+            } => json!({
+                "event": match access {
+                    Access::Gv => "give",
+                    Access::Rf => "ref",
+                    Access::Mt => "mut",
+                    Access::Drop => "drop",
+                },
+                "place": format!("{place:?}"),
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+            }),
+            Event::Drop { pointer, ty } => json!({
+                "event": "drop_value",
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+            }),
+            Event::DropBody { pointer, ty } => json!({
+                "event": "drop_body",
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+            }),
+            Event::RefCount {
+                pointer,
+                ty,
+                from,
+                to,
+            } => json!({
+                "event": "refcount",
+                "pointer": json_pointer(*pointer),
+                "ty": format!("{ty:?}"),
+                "from": from,
+                "to": to,
+            }),
+            Event::End { fault } => json!({
+                "event": "end",
+                "fault": fault,
+            }),
+        }
+    }
+}
+
+fn json_pointer(pointer: Pointer) -> Value {
+    json!([pointer.index, pointer.offset])
+}
+
+/// Where events go. Once a write fails, no more events are written, and the
+/// error is reported when the run finishes.
+pub(super) struct EventSink {
+    out: Box<dyn Write>,
+    error: Option<std::io::Error>,
+}
+
+impl EventSink {
+    fn write(&mut self, event: &Event) {
+        if self.error.is_none() {
+            if let Err(e) = writeln!(self.out, "{}", event.to_json()) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// Flush the sink, returning the first error writing to it.
+    pub(super) fn finish(&mut self) -> std::io::Result<()> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        self.out.flush()
+    }
+}
+
+impl Interpreter {
+    /// Write an [`Event`] to `out`, as a line of JSON, for everything the
+    /// interpreter does from now on.
+    pub fn set_event_sink(&mut self, out: impl Write + 'static) {
+        self.events = Some(EventSink {
+            out: Box::new(out),
+            error: None,
+        });
+    }
+
+    /// Write the event built by `event`, if a sink is attached. Events are
+    /// built lazily so that runs without a sink don't pay for them.
+    pub(super) fn emit(&mut self, event: impl FnOnce(&Self) -> Event) {
+        if self.events.is_none() {
+            return;
+        }
+        let event = event(self);
+        if let Some(sink) = &mut self.events {
+            sink.write(&event);
+        }
+    }
+
+    /// End a run (or a call or drop made from Rust) that produced `result`:
+    /// write the [`Event::End`] event and flush the sink, reporting any error
+    /// writing to it along with `result`.
+    pub(super) fn finish_events<T>(&mut self, result: anyhow::Result<T>) -> anyhow::Result<T> {
+        self.emit(|_| Event::End {
+            fault: result.as_ref().err().map(|error| format!("{error:#}")),
+        });
+        let Some(sink) = &mut self.events else {
+            return result;
+        };
+        match (result, sink.finish()) {
+            (result, Ok(())) => result,
+            (Ok(_), Err(e)) => Err(anyhow::anyhow!("cannot write interpreter events: {e}")),
+            // Keep the fault itself (e.g., a `Panic`) as the error, so that callers
+            // can still downcast to it, and add the sink's error as context.
+            (Err(error), Err(e)) => Err(error.context(format!(
+                "the run faulted, and interpreter events could not be written: {e}"
+            ))),
+        }
+    }
+
+    /// The type of the value that the allocation holding `pointer` was made for.
+    pub(super) fn alloc_ty(&self, pointer: Pointer) -> Ty {
+        self.alloc_origins[pointer.index].ty().clone()
+    }
+}
@@ -0,0 +1,306 @@
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+use crate::grammar::Ty;
+use crate::interpreter::embed::Value;
+use crate::interpreter::{Interpreter, Panic, Pointer, Word};
+use crate::test_util::parse_program;
+
+use super::Event;
+
+/// Collects the events written by the interpreter.
+#[derive(Clone, Default)]
+struct Sink(Rc<RefCell<Vec<u8>>>);
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A sink that cannot be written to.
+struct BrokenSink;
+
+impl Write for BrokenSink {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Run `program` and return the lines written to the event sink.
+fn events(program: &str) -> Vec<String> {
+    let sink = Sink::default();
+    let mut interpreter = Interpreter::new(parse_program(&[program]));
+    interpreter.set_event_sink(sink.clone());
+    interpreter.interpret().unwrap();
+    sink.lines()
+}
+
+impl Sink {
+    fn lines(&self) -> Vec<String> {
+        let bytes = self.0.borrow().clone();
+        String::from_utf8(bytes)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// The lines of `events` that contain all of `parts`.
+fn matching<'e>(events: &'e [String], parts: &[&str]) -> Vec<&'e str> {
+    events
+        .iter()
+        .filter(|event| parts.iter().all(|part| event.contains(part)))
+        .map(String::as_str)
+        .collect()
+}
+
+const POINT: &str = "
+    class Point {
+        x: Int;
+        y: Int;
+    }
+
+    class Main {
+        fn main(given self) -> Point {
+            let p = new Point(22, 44);
+            p.give;
+        }
+    }
+";
+
+#[test]
+fn every_line_is_an_event_object() {
+    let events = events(POINT);
+    assert!(!events.is_empty());
+    for event in &events {
+        assert!(
+            event.starts_with("{\"event\":\"") && event.ends_with('}'),
+            "{event}"
+        );
+    }
+}
+
+#[test]
+fn method_calls_are_bracketed_by_enter_and_exit() {
+    let events = events(POINT);
+    let enter = matching(&events, &["\"event\":\"enter\""]);
+    let exit = matching(&events, &["\"event\":\"exit\""]);
+    assert_eq!(enter.len(), 1, "{events:#?}");
+    assert!(
+        enter[0].contains("\"call_id\":1,\"method\":\"Main.main\""),
+        "{}",
+        enter[0]
+    );
+    assert_eq!(exit.len(), 1, "{events:#?}");
+    assert!(exit[0].contains("\"ty\":\"Point\""), "{}", exit[0]);
+    assert_eq!(events[events.len() - 2], exit[0]);
+}
+
+#[test]
+fn a_run_that_returns_ends_without_a_fault() {
+    let events = events(POINT);
+    assert_eq!(
+        events.last().map(String::as_str),
+        Some(r#"{"event":"end","fault":null}"#)
+    );
+}
+
+/// A fault that is not a panic aborts each method it leaves, and still ends
+/// the run.
+#[test]
+fn a_run_that_faults_ends_with_the_fault() {
+    let sink = Sink::default();
+    let mut interpreter = Interpreter::new(parse_program(&["
+        class Main {
+            fn main(given self) -> Int {
+                self.give.main();
+            }
+        }
+    "]));
+    interpreter.set_max_call_depth(3);
+    interpreter.set_event_sink(sink.clone());
+    interpreter.interpret().unwrap_err();
+    let events = sink.lines();
+    let abort = matching(&events, &["\"event\":\"abort\"", "Main.main"]);
+    assert_eq!(abort.len(), 3, "{events:#?}");
+    let last = events.last().unwrap();
+    assert!(
+        last.starts_with(r#"{"event":"end","fault":""#) && last.contains("stack overflow"),
+        "{last}"
+    );
+}
+
+/// A call made from Rust ends its events, and flushes the sink, like a run.
+#[test]
+fn a_call_from_rust_ends_its_events() {
+    let sink = Sink::default();
+    let mut interpreter = Interpreter::new(parse_program(&["
+        class Point {
+            x: Int;
+
+            fn get(given self) -> Int {
+                self.x.give;
+            }
+        }
+    "]));
+    interpreter.set_event_sink(sink.clone());
+    let p = interpreter
+        .alloc_value(&Value::Object {
+            class: "Point".to_string(),
+            fields: vec![("x".to_string(), Value::Int(1))],
+        })
+        .unwrap();
+    interpreter.call(p, "get", &[], vec![]).unwrap();
+    let events = sink.lines();
+    assert!(!matching(&events, &["\"event\":\"exit\"", "Point.get"]).is_empty());
+    assert_eq!(
+        events.last().map(String::as_str),
+        Some(r#"{"event":"end","fault":null}"#)
+    );
+
+    let n = interpreter.alloc_value(&Value::Int(1)).unwrap();
+    interpreter.set_event_sink(BrokenSink);
+    let error = interpreter.drop_object(n).unwrap_err();
+    assert!(error.to_string().contains("disk full"), "{error}");
+}
+
+#[test]
+fn allocations_writes_and_accesses_are_recorded() {
+    let events = events(POINT);
+    let alloc = matching(
+        &events,
+        &[
+            "\"event\":\"alloc\"",
+            "\"ty\":\"Point\",\"words\":[\"Int(22)\",\"Int(44)\"]",
+        ],
+    );
+    // One for `new Point(22, 44)`, one for the copy made by `p.give`.
+    assert_eq!(alloc.len(), 2, "{events:#?}");
+
+    // Giving `p` moves it: the access is recorded, then its words are uninitialized.
+    let give = matching(&events, &["\"event\":\"give\"", "\"place\":\"p\""]);
+    assert_eq!(give.len(), 1, "{events:#?}");
+    let uninitialized = matching(
+        &events,
+        &["\"event\":\"write\"", "\"word\":\"Uninitialized\""],
+    );
+    assert!(!uninitialized.is_empty(), "{events:#?}");
+}
+
+#[test]
+fn refcount_changes_are_recorded() {
+    let events = events(
+        "
+        class Main {
+            fn main(given self) -> () {
+                let a: given Array[Int] = array_new[Int](1);
+                let s = a.give.share;
+                let s2: shared Array[Int] = s.give;
+                ();
+            }
+        }
+    ",
+    );
+    let up = matching(&events, &["\"event\":\"refcount\"", "\"from\":1,\"to\":2"]);
+    assert_eq!(up.len(), 1, "{events:#?}");
+    let down = matching(&events, &["\"event\":\"refcount\"", "\"from\":2,\"to\":1"]);
+    assert_eq!(down.len(), 1, "{events:#?}");
+}
+
+#[test]
+fn drop_bodies_are_recorded() {
+    let events = events(
+        "
+        class Data {
+            x: Int;
+
+            drop {
+                print(self.x.give);
+            }
+        }
+
+        class Main {
+            fn main(given self) -> () {
+                let d = new Data(42);
+                ();
+            }
+        }
+    ",
+    );
+    let drop_body = matching(&events, &["\"event\":\"drop_body\"", "Data"]);
+    assert_eq!(drop_body.len(), 1, "{events:#?}");
+}
+
+#[test]
+fn strings_are_escaped() {
+    let event = Event::Alloc {
+        pointer: Pointer {
+            index: 3,
+            offset: 0,
+        },
+        ty: Ty::int(),
+        words: vec![Word::Int(1)],
+    };
+    assert_eq!(
+        event.to_json(),
+        r#"{"event":"alloc","pointer":[3,0],"ty":"Int","words":["Int(1)"]}"#
+    );
+    let event = Event::Unwind {
+        call_id: 1,
+        method: "a\"b\\c\nd\u{1}".to_string(),
+    };
+    assert_eq!(
+        event.to_json(),
+        r#"{"event":"unwind","call_id":1,"method":"a\"b\\c\nd\u0001"}"#
+    );
+}
+
+#[test]
+fn drops_are_recorded() {
+    let events = events(POINT);
+    let drops = matching(&events, &["\"event\":\"drop_value\""]);
+    // `p` when its scope ends (its words were already moved out by `p.give`).
+    assert!(
+        drops.iter().any(|event| event.contains("\"ty\":\"Point\"")),
+        "{events:#?}"
+    );
+}
+
+/// When the run faults and the sink fails too, both are reported, and the
+/// fault can still be downcast.
+#[test]
+fn sink_error_does_not_hide_a_fault() {
+    let mut interpreter = Interpreter::new(parse_program(&["
+        class Main {
+            fn main(given self) -> Int {
+                let x: Int = !;
+                x.give;
+            }
+        }
+    "]));
+    interpreter.set_event_sink(BrokenSink);
+    let error = interpreter.interpret().unwrap_err();
+    assert!(error.is::<Panic>(), "{error:#}");
+    assert!(format!("{error:#}").contains("disk full"), "{error:#}");
+}
+
+/// When only the sink fails, its error is reported.
+#[test]
+fn sink_error_is_reported() {
+    let mut interpreter = Interpreter::new(parse_program(&[POINT]));
+    interpreter.set_event_sink(BrokenSink);
+    let error = interpreter.interpret().unwrap_err();
+    assert!(error.to_string().contains("disk full"), "{error}");
+}
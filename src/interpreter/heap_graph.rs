@@ -0,0 +1,447 @@
+//! Draw the interpreter's heap as a graph, in Graphviz (`dot`) or Mermaid syntax.
+//!
+//! Every live allocation becomes a node with one row per word. Starting from
+//! some typed roots (the variables of a [`StackFrame`], or the result of a
+//! program), rows are labelled with the field or array element they hold,
+//! following the same layouts as the interpreter (see `value_layout`).
+//! `Pointer` and `MutRef` words become edges: an edge from a `Pointer` is
+//! labelled with the `Flags` word stored just before it, and an edge from a
+//! `MutRef` is labelled `mut`. Freed allocations that are still pointed to
+//! are drawn dashed.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use formality_core::Upcast;
+
+use crate::grammar::ty_impls::PermTy;
+use crate::grammar::{NamedTy, Parameter, Ty, TypeName};
+use crate::type_system::env::Env;
+
+use super::{
+    extract_array_element_ty, fmt_word, Interpreter, ObjectValue, ObjectValueLayout, Pointer,
+    StackFrame, Word, ARRAY_CAPACITY_OFFSET, ARRAY_ELEMENTS_OFFSET, ARRAY_REF_COUNT_OFFSET,
+    POINTER_DATA_OFFSET,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The syntax produced by [`HeapGraph::render`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz, e.g. for `dot -Tsvg heap.dot > heap.svg`.
+    Dot,
+
+    /// Mermaid, which the book can embed in a `mermaid` code block.
+    Mermaid,
+}
+
+/// A snapshot of the heap, ready to be rendered.
+pub struct HeapGraph {
+    /// The name and type of each root, and the word it points at.
+    roots: Vec<Root>,
+
+    /// The rows of each allocation that is drawn, by index;
+    /// `None` if the allocation has been freed.
+    nodes: BTreeMap<usize, Option<Vec<String>>>,
+
+    /// One edge per `Pointer` or `MutRef` word.
+    edges: Vec<Edge>,
+
+    /// Number of hex digits used for allocation indices, as in `dump_heap`.
+    hex_width: usize,
+}
+
+struct Root {
+    name: String,
+    ty: String,
+    pointer: Pointer,
+}
+
+struct Edge {
+    from: Pointer,
+    to: Pointer,
+    label: String,
+}
+
+impl HeapGraph {
+    /// The heap as seen from the variables of `frame`.
+    pub fn of_frame(interpreter: &Interpreter, frame: &StackFrame) -> anyhow::Result<Self> {
+        let mut roots = vec![];
+        for (var, pointer) in &frame.variables {
+            let ty = frame.env.var_ty(var)?.clone();
+            let value = ObjectValue {
+                pointer: *pointer,
+                ty,
+            };
+            roots.push((format!("{var:?}"), value));
+        }
+        Self::new(interpreter, &frame.env, &roots)
+    }
+
+    /// The heap as seen from `roots`, each of which is drawn with its name.
+    pub fn new(
+        interpreter: &Interpreter,
+        env: &Env,
+        roots: &[(String, ObjectValue)],
+    ) -> anyhow::Result<Self> {
+        let mut labeller = Labeller {
+            interpreter,
+            env,
+            labels: BTreeMap::new(),
+        };
+        for (name, value) in roots {
+            labeller.visit(value.pointer, &value.ty, name)?;
+        }
+        let labels = labeller.labels;
+
+        let hex_width = if interpreter.allocs.len() <= 0x100 {
+            2
+        } else {
+            3
+        };
+        let mut nodes = BTreeMap::new();
+        let mut edges = vec![];
+        for (index, alloc) in interpreter.allocs.iter().enumerate() {
+            if alloc.is_freed() {
+                continue;
+            }
+            let mut rows = vec![];
+            for (offset, word) in alloc.data.iter().enumerate() {
+                let shown = fmt_word(word, hex_width);
+                rows.push(match labels.get(&(index, offset)) {
+                    Some(label) => format!("{label} = {shown}"),
+                    None => shown,
+                });
+
+                let from = Pointer { index, offset };
+                match word {
+                    Word::Pointer(to) => {
+                        // The flags of a boxed value sit just before its pointer.
+                        let label = match offset.checked_sub(1).map(|o| alloc.data[o]) {
+                            Some(Word::Flags(flags)) => format!("{flags:?}"),
+                            _ => String::new(),
+                        };
+                        edges.push(Edge {
+                            from,
+                            to: *to,
+                            label,
+                        });
+                    }
+                    Word::MutRef(to) => edges.push(Edge {
+                        from,
+                        to: *to,
+                        label: "mut".to_string(),
+                    }),
+                    _ => {}
+                }
+            }
+            nodes.insert(index, Some(rows));
+        }
+
+        let roots: Vec<Root> = roots
+            .iter()
+            .map(|(name, value)| Root {
+                name: name.clone(),
+                ty: format!("{:?}", interpreter.simplify_ty(env, &value.ty)),
+                pointer: value.pointer,
+            })
+            .collect();
+
+        // Draw anything that is pointed to but not live: unit values (which
+        // are empty allocations) and, so that dangling pointers stand out,
+        // freed allocations.
+        let targets = roots
+            .iter()
+            .map(|root| root.pointer)
+            .chain(edges.iter().map(|edge| edge.to));
+        for target in targets {
+            let is_unit = interpreter
+                .allocs
+                .get(target.index)
+                .is_some_and(|alloc| alloc.data.is_empty());
+            nodes
+                .entry(target.index)
+                .or_insert_with(|| is_unit.then(Vec::new));
+        }
+
+        Ok(HeapGraph {
+            roots,
+            nodes,
+            edges,
+            hex_width,
+        })
+    }
+
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.render_dot(),
+            GraphFormat::Mermaid => self.render_mermaid(),
+        }
+    }
+
+    fn render_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph heap {{").unwrap();
+        writeln!(out, "    rankdir=LR;").unwrap();
+        writeln!(out, "    node [shape=record, fontname=monospace];").unwrap();
+        if !self.roots.is_empty() {
+            let rows: Vec<String> = self
+                .roots
+                .iter()
+                .enumerate()
+                .map(|(i, root)| format!("<r{i}> {}", escape_dot(&root.row())))
+                .collect();
+            writeln!(out, "    roots [label=\"{}\"];", rows.join("|")).unwrap();
+        }
+        for (index, rows) in &self.nodes {
+            let id = node_id(*index);
+            let name = self.alloc_name(*index);
+            match rows {
+                Some(rows) => {
+                    let mut fields = vec![name];
+                    fields.extend(
+                        rows.iter()
+                            .enumerate()
+                            .map(|(offset, row)| format!("<w{offset}> {}", escape_dot(row))),
+                    );
+                    writeln!(out, "    {id} [label=\"{}\"];", fields.join("|")).unwrap();
+                }
+                None => {
+                    writeln!(out, "    {id} [label=\"{name} (freed)\", style=dashed];").unwrap()
+                }
+            }
+        }
+        for (i, root) in self.roots.iter().enumerate() {
+            writeln!(out, "    roots:r{i} -> {};", self.dot_target(root.pointer)).unwrap();
+        }
+        for edge in &self.edges {
+            write!(
+                out,
+                "    {}:w{} -> {}",
+                node_id(edge.from.index),
+                edge.from.offset,
+                self.dot_target(edge.to)
+            )
+            .unwrap();
+            if edge.label.is_empty() {
+                writeln!(out, ";").unwrap();
+            } else {
+                writeln!(out, " [label=\"{}\"];", escape_dot(&edge.label)).unwrap();
+            }
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    fn render_mermaid(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "flowchart LR").unwrap();
+        if !self.roots.is_empty() {
+            let rows: Vec<String> = self
+                .roots
+                .iter()
+                .map(|root| escape_mermaid(&root.row()))
+                .collect();
+            writeln!(out, "    roots[\"{}\"]", rows.join("<br/>")).unwrap();
+        }
+        for (index, rows) in &self.nodes {
+            let id = node_id(*index);
+            let name = self.alloc_name(*index);
+            match rows {
+                Some(rows) => {
+                    let mut lines = vec![name];
+                    lines.extend(rows.iter().map(|row| escape_mermaid(row)));
+                    writeln!(out, "    {id}[\"{}\"]", lines.join("<br/>")).unwrap();
+                }
+                None => {
+                    writeln!(out, "    {id}[\"{name} (freed)\"]").unwrap();
+                    writeln!(out, "    style {id} stroke-dasharray: 5 5").unwrap();
+                }
+            }
+        }
+        for root in &self.roots {
+            writeln!(
+                out,
+                "    roots -->|\"{}\"| {}",
+                escape_mermaid(&root.name),
+                node_id(root.pointer.index)
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let from = node_id(edge.from.index);
+            let to = node_id(edge.to.index);
+            if edge.label.is_empty() {
+                writeln!(out, "    {from} --> {to}").unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "    {from} -->|\"{}\"| {to}",
+                    escape_mermaid(&edge.label)
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+
+    /// The allocation's index as shown by `dump_heap`, e.g. `0x03`.
+    fn alloc_name(&self, index: usize) -> String {
+        format!("0x{index:0>width$x}", width = self.hex_width)
+    }
+
+    /// The DOT port for the word at `pointer`, or the whole node if the word is not drawn.
+    fn dot_target(&self, pointer: Pointer) -> String {
+        let id = node_id(pointer.index);
+        match self.nodes.get(&pointer.index) {
+            Some(Some(rows)) if pointer.offset < rows.len() => format!("{id}:w{}", pointer.offset),
+            _ => id,
+        }
+    }
+}
+
+impl Root {
+    fn row(&self) -> String {
+        format!("{}: {}", self.name, self.ty)
+    }
+}
+
+fn node_id(index: usize) -> String {
+    format!("a{index}")
+}
+
+/// Escape the characters that are special in a DOT record label.
+fn escape_dot(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escape the characters that are special inside a quoted Mermaid label.
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+/// Walks values from their types, naming the words they occupy.
+struct Labeller<'i> {
+    interpreter: &'i Interpreter,
+    env: &'i Env,
+
+    /// Label of each word, keyed by `(index, offset)`.
+    labels: BTreeMap<(usize, usize), String>,
+}
+
+impl Labeller<'_> {
+    fn word(&self, pointer: Pointer) -> Option<Word> {
+        let alloc = self.interpreter.allocs.get(pointer.index)?;
+        alloc.data.get(pointer.offset).copied()
+    }
+
+    /// Label the word at `pointer`, unless some other path already has.
+    /// Returns false if it was already labelled.
+    fn label(&mut self, pointer: Pointer, label: String) -> bool {
+        match self.labels.entry((pointer.index, pointer.offset)) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(label);
+                true
+            }
+        }
+    }
+
+    /// Label the words of the value of type `ty` at `pointer`, which is called
+    /// `name`, and those of the values it points to.
+    fn visit(&mut self, pointer: Pointer, ty: &Ty, name: &str) -> anyhow::Result<()> {
+        let interpreter = self.interpreter;
+        let env = self.env;
+        let value = ObjectValue {
+            pointer,
+            ty: interpreter.simplify_ty(env, ty),
+        };
+        match interpreter.value_layout(env, &value)? {
+            ObjectValueLayout::MutRef(pointer, ty) => {
+                if !self.label(pointer, format!("{name}: {ty:?}")) {
+                    return Ok(());
+                }
+                if let Some(Word::MutRef(target)) = self.word(pointer) {
+                    let PermTy(_, inner_ty) = ty.upcast();
+                    self.visit(target, &inner_ty, &format!("*{name}"))?;
+                }
+            }
+
+            ObjectValueLayout::Boxed(pointer, ty) => {
+                if !self.label(pointer, format!("{name}: {ty:?}")) {
+                    return Ok(());
+                }
+                let NamedTy {
+                    name: TypeName::Array,
+                    parameters,
+                } = interpreter.named_ty(ty)
+                else {
+                    return Ok(());
+                };
+                let Some(Word::Pointer(array)) = self.word(pointer + POINTER_DATA_OFFSET) else {
+                    return Ok(());
+                };
+                // A shared array's backing allocation is reachable from each of its owners.
+                if !self.label(array + ARRAY_REF_COUNT_OFFSET, format!("{name}.refcount")) {
+                    return Ok(());
+                }
+                self.label(array + ARRAY_CAPACITY_OFFSET, format!("{name}.capacity"));
+                let Some(Word::Capacity(capacity)) = self.word(array + ARRAY_CAPACITY_OFFSET)
+                else {
+                    return Ok(());
+                };
+                let element_ty = extract_array_element_ty(&parameters)?;
+                let element_size = interpreter.size_of(env, &element_ty)?;
+                for i in 0..capacity {
+                    let element = array + ARRAY_ELEMENTS_OFFSET + i * element_size;
+                    self.visit(element, &element_ty, &format!("{name}[{i}]"))?;
+                }
+            }
+
+            ObjectValueLayout::Flat(pointer, ty) => {
+                let NamedTy {
+                    name: type_name,
+                    parameters,
+                } = interpreter.named_ty(ty);
+                match &type_name {
+                    TypeName::Id(class_name) => {
+                        let class_decl = interpreter.program.class_named(class_name)?;
+                        let class_data = class_decl.binder.instantiate_with(&parameters)?;
+                        let mut offset = 0;
+                        for field in &class_data.fields {
+                            let field_name = format!("{name}.{:?}", field.name);
+                            self.visit(pointer + offset, &field.ty, &field_name)?;
+                            offset += interpreter.size_of(env, &field.ty)?;
+                        }
+                    }
+                    TypeName::Tuple(_) => {
+                        let mut offset = 0;
+                        for (i, parameter) in parameters.iter().enumerate() {
+                            let Parameter::Ty(element_ty) = parameter else {
+                                anyhow::bail!("tuple parameter is not a type: `{parameter:?}`");
+                            };
+                            self.visit(pointer + offset, element_ty, &format!("{name}.{i}"))?;
+                            offset += interpreter.size_of(env, element_ty)?;
+                        }
+                    }
+                    TypeName::Int | TypeName::Bool | TypeName::Array => {
+                        self.label(pointer, format!("{name}: {ty:?}"));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::interpreter::Interpreter;
+use crate::test_util::parse_program;
+use crate::type_system::env::Env;
+
+use super::{escape_dot, escape_mermaid, GraphFormat, HeapGraph};
+
+/// Run `program` and draw the heap it leaves behind, starting from its result.
+fn graph_of_result(program: &str, format: GraphFormat) -> String {
+    let program = parse_program(&[program]);
+    let mut interpreter = Interpreter::new(program.clone());
+    let result = interpreter.interpret().unwrap();
+    let roots = [("result".to_string(), result)];
+    HeapGraph::new(&interpreter, &Env::new(program), &roots)
+        .unwrap()
+        .render(format)
+}
+
+const WRAPPER: &str = "
+    class Wrapper {
+        items: Array[Int];
+        n: Int;
+    }
+
+    class Main {
+        fn main(given self) -> Wrapper {
+            let a = array_new[Int](2);
+            array_write[Int, mut[a]](a.mut, 0, 7);
+            new Wrapper(a.give, 3);
+        }
+    }
+";
+
+#[test]
+fn mermaid_labels_fields_and_elements() {
+    let graph = graph_of_result(WRAPPER, GraphFormat::Mermaid);
+    assert!(graph.starts_with("flowchart LR\n"), "{graph}");
+    assert!(graph.contains("roots[\"result: Wrapper\"]"), "{graph}");
+    for row in [
+        "result.items: Array[Int] = Flags(Given)",
+        "result.n: Int = Int(3)",
+        "result.items.refcount = RefCount(1)",
+        "result.items.capacity = Capacity(2)",
+        "result.items[0]: Int = Int(7)",
+        "result.items[1]: Int = Uninitialized",
+    ] {
+        assert!(graph.contains(row), "missing `{row}`:\n{graph}");
+    }
+    assert!(graph.contains("-->|\"Given\"|"), "{graph}");
+}
+
+#[test]
+fn dot_uses_record_ports() {
+    let graph = graph_of_result(WRAPPER, GraphFormat::Dot);
+    assert!(graph.starts_with("digraph heap {\n"), "{graph}");
+    assert!(
+        graph.contains("roots [label=\"<r0> result: Wrapper\"];"),
+        "{graph}"
+    );
+    assert!(graph.contains("roots:r0 -> "), "{graph}");
+    assert!(graph.contains(":w1 -> "), "{graph}");
+    assert!(graph.contains(":w0 [label=\"Given\"];"), "{graph}");
+    assert!(graph.trim_end().ends_with('}'), "{graph}");
+}
+
+#[test]
+fn labels_are_escaped() {
+    assert_eq!(escape_dot("x: {a|b} <c>"), "x: \\{a\\|b\\} \\<c\\>");
+    assert_eq!(escape_mermaid("\"a\" <b>"), "#quot;a#quot; #lt;b#gt;");
+}
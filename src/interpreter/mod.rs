@@ -1,5 +1,13 @@
-pub mod alpha_rename;
-
+pub mod audit;
+mod caller_places;
+pub mod debugger;
+pub mod embed;
+pub mod events;
+pub mod heap_graph;
+pub mod native;
+pub mod replay;
+
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use formality_core::{set, Upcast};
@@ -13,6 +21,7 @@ use crate::grammar::{
 
 use crate::type_system::env::Env;
 use crate::type_system::liveness::LivePlaces;
+use crate::type_system::memo;
 use crate::type_system::pop_normalize::normalize_ty_for_pop;
 use crate::type_system::predicates::{
     prove_is_boxed, prove_is_copy, prove_is_copy_owned, prove_is_given, prove_is_move,
@@ -28,6 +37,84 @@ const ARRAY_ELEMENTS_OFFSET: usize = 2;
 const POINTER_FLAGS_OFFSET: usize = 0;
 const POINTER_DATA_OFFSET: usize = 1;
 
+/// The fuel used by the tests and the command line (see [`Interpreter::set_fuel`]).
+pub const DEFAULT_FUEL: usize = 100_000;
+
+/// The call depth used by the tests and the command line
+/// (see [`Interpreter::set_max_call_depth`]). Deep enough for recursion over
+/// lists of a few hundred elements; run such programs with [`with_stack`].
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 512;
+
+/// The stack size of the thread that [`with_stack`] runs its closure on.
+/// Method calls recurse on the Rust stack, so this leaves about 1 MiB for each
+/// of [`DEFAULT_MAX_CALL_DEPTH`] nested calls, even in an unoptimized build.
+/// The memory is only reserved; pages are touched as the stack grows.
+pub const STACK_SIZE: usize = 512 << 20;
+
+/// Run `f` on a thread with a [`STACK_SIZE`] stack, so that the interpreter can
+/// nest [`DEFAULT_MAX_CALL_DEPTH`] method calls; the stack of a test thread (2 MiB
+/// by default) holds only a few dozen. A panic in `f` is resumed on this thread.
+pub fn with_stack<R: Send>(f: impl FnOnce() -> R + Send) -> R {
+    std::thread::scope(|scope| {
+        let thread = std::thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, f)
+            .expect("cannot spawn a thread for the interpreter");
+        thread
+            .join()
+            .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+    })
+}
+
+/// The fault produced when a run exceeds one of the interpreter's limits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LimitExceeded {
+    /// More statements and loop iterations were evaluated than the fuel allows.
+    Fuel(usize),
+
+    /// Method calls were nested more deeply than allowed.
+    CallDepth(usize),
+}
+
+impl std::fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitExceeded::Fuel(fuel) => {
+                write!(
+                    f,
+                    "out of fuel: evaluated more than {fuel} statements and loop iterations"
+                )
+            }
+            LimitExceeded::CallDepth(depth) => {
+                write!(f, "stack overflow: more than {depth} nested method calls")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+/// The fault produced by `!`. Before it is reported, the panic unwinds: each
+/// block and method call it passes through drops its live variables, running
+/// their `drop` bodies, just as if the block had finished normally.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Panic {
+    /// The methods that were unwound, innermost first.
+    pub unwound: Vec<String>,
+}
+
+impl std::fmt::Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "panic!")?;
+        if !self.unwound.is_empty() {
+            write!(f, " (unwound {})", self.unwound.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Panic {}
+
 /// Result of evaluating a statement or expression.
 enum Outcome {
     /// Normal result with a value.
@@ -49,10 +136,17 @@ struct Alloc {
 }
 // ANCHOR_END: Alloc
 
+impl Alloc {
+    /// An allocation is "freed" if it is empty or all words are Uninitialized.
+    fn is_freed(&self) -> bool {
+        self.data.iter().all(|w| matches!(w, Word::Uninitialized))
+    }
+}
+
 // ANCHOR: Word
 /// A single word of memory.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Word {
+pub enum Word {
     Int(i64),
     Flags(Flags),
     Pointer(Pointer),
@@ -66,7 +160,7 @@ enum Word {
 // ANCHOR: Flags
 /// Permission flag for unique objects.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Flags {
+pub enum Flags {
     /// Indicates that the data here is fully owned.
     Given,
 
@@ -163,8 +257,8 @@ enum FieldPointer<'a> {
 
 // ANCHOR: Pointer
 /// Identifies a position within an allocation.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-struct Pointer {
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Pointer {
     index: usize,
     offset: usize,
 }
@@ -181,6 +275,18 @@ pub struct ObjectValue {
 }
 // ANCHOR_END: TypedValue
 
+impl ObjectValue {
+    /// Where the value's words start.
+    pub fn pointer(&self) -> Pointer {
+        self.pointer
+    }
+
+    /// The type needed to interpret the words.
+    pub fn ty(&self) -> &Ty {
+        &self.ty
+    }
+}
+
 /// Categorize the possible layouts of an object value in memory.
 enum ObjectValueLayout<'a> {
     /// This is a mutable reference; pointers should point to a `Word::MutRef`.
@@ -222,11 +328,34 @@ pub struct Interpreter {
     allocs: Vec<Alloc>,
     output: String,
     indent: usize,
-    /// Monotonically increasing counter for alpha-renaming method bodies.
-    /// Each method invocation gets a unique ID so renamed variables
-    /// (e.g., `_1_self`, `_2_self`) never collide, even across sequential
-    /// calls at the same stack depth.
+    /// Monotonically increasing counter identifying method invocations
+    /// (e.g., in [`events::Event::Enter`]), so that sequential calls at the
+    /// same stack depth can be told apart.
     next_call_id: usize,
+    /// Methods currently executing, innermost last, as `Class.method`.
+    call_stack: Vec<String>,
+    /// Number of statements evaluated so far, including the current one.
+    statements_executed: usize,
+    /// True between entering a method and evaluating its first statement.
+    entered_call: bool,
+    debugger: Option<debugger::Debugger>,
+    /// Maximum number of statements and loop iterations to evaluate, if limited.
+    fuel: Option<usize>,
+    /// Fuel used so far by this run (`interpret`, or one `call` or `drop_object`):
+    /// one unit per statement and per loop iteration.
+    fuel_used: usize,
+    /// Maximum number of nested method calls, if limited.
+    max_call_depth: Option<usize>,
+    /// What each allocation was made for, parallel to `allocs` (see `audit`).
+    alloc_origins: Vec<audit::AllocOrigin>,
+    /// Words `uninitialize_words` was asked to uninitialize a second time (see `audit`).
+    double_frees: Vec<Pointer>,
+    /// Implementations of trusted methods, keyed by `Class.method` (see `register_native`).
+    natives: HashMap<String, native::NativeMethod>,
+    /// Where to write structured events, if anywhere (see `set_event_sink`).
+    events: Option<events::EventSink>,
+    /// The run so far, if recording (see `start_recording`).
+    recorder: Option<replay::Recorder>,
 }
 // ANCHOR_END: Interpreter
 
@@ -238,9 +367,41 @@ impl Interpreter {
             output: String::new(),
             indent: 0,
             next_call_id: 0,
+            call_stack: Vec::new(),
+            statements_executed: 0,
+            entered_call: false,
+            debugger: None,
+            fuel: None,
+            fuel_used: 0,
+            max_call_depth: None,
+            alloc_origins: Vec::new(),
+            double_frees: Vec::new(),
+            natives: HashMap::new(),
+            events: None,
+            recorder: None,
         }
     }
 
+    /// Consult `debugger` before evaluating each statement.
+    pub fn set_debugger(&mut self, debugger: debugger::Debugger) {
+        self.debugger = Some(debugger);
+    }
+
+    /// Fault with [`LimitExceeded::Fuel`] instead of evaluating more than `fuel` statements
+    /// and loop iterations, so that a program that loops forever (even `loop { }`) cannot
+    /// hang the caller. The limit is per run: `interpret`, and each `call` and
+    /// `drop_object` of an embedded interpreter, start with all of the fuel.
+    pub fn set_fuel(&mut self, fuel: usize) {
+        self.fuel = Some(fuel);
+    }
+
+    /// Fault with [`LimitExceeded::CallDepth`] instead of nesting more than `depth`
+    /// method calls. Calls recurse on the Rust stack, so without a limit,
+    /// unbounded recursion overflows it.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = Some(depth);
+    }
+
     fn trace(&mut self, msg: impl std::fmt::Display) {
         let indent = "  ".repeat(self.indent);
         self.output.push_str(&format!("Trace: {indent}{msg}\n"));
@@ -257,23 +418,37 @@ impl Interpreter {
     // Low-level allocation and word operations
     // ---------------------------------------------------------------
 
-    /// Allocate a new `Alloc` and return a pointer to its start.
-    fn alloc_raw(&mut self, alloc: Alloc) -> Pointer {
+    /// Allocate a new `Alloc` to hold a value of type `ty` and return a pointer to its start.
+    fn alloc_raw(&mut self, alloc: Alloc, ty: &Ty) -> Pointer {
         let index = self.allocs.len();
+        let pointer = Pointer { index, offset: 0 };
+        self.emit(|_| events::Event::Alloc {
+            pointer,
+            ty: ty.clone(),
+            words: alloc.data.clone(),
+        });
+        self.record(|| replay::Change::Alloc {
+            pointer,
+            words: alloc.data.clone(),
+        });
         self.allocs.push(alloc);
-        Pointer { index, offset: 0 }
+        self.alloc_origins.push(audit::AllocOrigin::new(ty));
+        pointer
     }
 
     /// Allocate a single integer word.
     fn alloc_int(&mut self, n: i64) -> Pointer {
-        self.alloc_raw(Alloc {
-            data: vec![Word::Int(n)],
-        })
+        self.alloc_raw(
+            Alloc {
+                data: vec![Word::Int(n)],
+            },
+            &Ty::int(),
+        )
     }
 
     /// Allocate a zero-sized allocation (used for unit values).
     fn alloc_unit(&mut self) -> Pointer {
-        self.alloc_raw(Alloc { data: vec![] })
+        self.alloc_raw(Alloc { data: vec![] }, &Ty::unit())
     }
 
     /// Allocate a unit value and wrap it in a TypedValue.
@@ -344,8 +519,12 @@ impl Interpreter {
     }
 
     /// Set `count` words at `ptr` to `Word::Uninitialized`.
+    /// Words that are already uninitialized are recorded as double frees.
     fn uninitialize_words(&mut self, ptr: Pointer, count: usize) {
         for i in 0..count {
+            if !self.is_word_initialized(ptr + i) {
+                self.double_frees.push(ptr + i);
+            }
             self.uninitialize_word(ptr + i);
         }
     }
@@ -357,7 +536,17 @@ impl Interpreter {
 
     /// Write one word at a pointer.
     fn write_word(&mut self, ptr: Pointer, word: Word) {
-        self.allocs[ptr.index].data[ptr.offset] = word;
+        self.emit(|this| events::Event::Write {
+            pointer: ptr,
+            ty: this.alloc_ty(ptr),
+            word,
+        });
+        let old = std::mem::replace(&mut self.allocs[ptr.index].data[ptr.offset], word);
+        self.record(|| replay::Change::Write {
+            pointer: ptr,
+            old,
+            new: word,
+        });
     }
 
     /// Read `count` words starting at a pointer.
@@ -371,9 +560,8 @@ impl Interpreter {
 
     /// Write a slice of words starting at a pointer.
     fn write_words(&mut self, ptr: Pointer, words: &[Word]) {
-        let start = ptr.offset;
         for (i, &word) in words.iter().enumerate() {
-            self.allocs[ptr.index].data[start + i] = word;
+            self.write_word(ptr + i, word);
         }
     }
 
@@ -504,17 +692,27 @@ impl Interpreter {
         if self.is_boxed_type(env, &object_data.named_ty) {
             // For a boxed object (e.g., array), we copy the pointer and give a "Flags Given" to start.
             // A later pass will update the flags.
-            Ok(self.alloc_raw(Alloc {
-                data: vec![
-                    Word::Flags(Flags::Given),
-                    Word::Pointer(object_data.pointer),
-                ],
-            }))
+            let copied = self.alloc_raw(
+                Alloc {
+                    data: vec![
+                        Word::Flags(Flags::Given),
+                        Word::Pointer(object_data.pointer),
+                    ],
+                },
+                &Ty::NamedTy(object_data.named_ty.clone()),
+            );
+            self.inherit_alloc_origin(copied, object_data.pointer);
+            Ok(copied)
         } else {
             // For a flat object, we copy all words and omit flags word
             let size = self.size_of_named_ty(env, &object_data.named_ty)?;
             let words = self.read_words(object_data.pointer, size)?;
-            Ok(self.alloc_raw(Alloc { data: words }))
+            let copied = self.alloc_raw(
+                Alloc { data: words },
+                &Ty::NamedTy(object_data.named_ty.clone()),
+            );
+            self.inherit_alloc_origin(copied, object_data.pointer);
+            Ok(copied)
         }
     }
 
@@ -593,7 +791,13 @@ impl Interpreter {
 
     /// Write a new refcount to an array allocation (at offset 0).
     fn write_refcount(&mut self, array_alloc_ptr: Pointer, refcount: i64) -> anyhow::Result<()> {
-        self.read_refcount(array_alloc_ptr)?; // must be a ref-count already
+        let old_refcount = self.read_refcount(array_alloc_ptr)?; // must be a ref-count already
+        self.emit(|this| events::Event::RefCount {
+            pointer: array_alloc_ptr,
+            ty: this.alloc_ty(array_alloc_ptr),
+            from: old_refcount,
+            to: refcount,
+        });
         self.write_word(array_alloc_ptr, Word::RefCount(refcount));
         Ok(())
     }
@@ -845,12 +1049,10 @@ impl Interpreter {
         match field_pointer {
             FieldPointer::Boxed(pointer, ty) => {
                 let Some(flags) = self.try_read_flags(pointer + POINTER_FLAGS_OFFSET)? else {
-                    // Already moved/dropped (uninitialized): nothing to do.
-                    // This arises because the interpreter's end-of-scope cleanup
-                    // currently drops ALL variables unconditionally, without
-                    // checking wholeness first. Phase 4 will add proper
-                    // whole-place checks, at which point this guard can become
-                    // an error.
+                    // Already moved/dropped: dropping it again is a double free.
+                    // (End-of-scope cleanup and reassignment skip moved parts;
+                    // see `drop_initialized_parts`.)
+                    self.uninitialize_words(pointer, 2);
                     return Ok(());
                 };
                 match flags {
@@ -900,6 +1102,31 @@ impl Interpreter {
         Ok(())
     }
 
+    /// Like `and_drop_fields`, but skips the parts of the value that are
+    /// entirely uninitialized (moved out, or already dropped).
+    ///
+    /// Intended for use with `traverse_value` when a variable goes out of scope.
+    fn and_drop_initialized_fields(
+        &mut self,
+        env: &Env,
+        field_pointer: FieldPointer<'_>,
+    ) -> anyhow::Result<()> {
+        let moved = match &field_pointer {
+            FieldPointer::MutRef(pointer) => !self.is_word_initialized(*pointer),
+            FieldPointer::Boxed(pointer, _ty) => {
+                !self.is_word_initialized(*pointer + POINTER_FLAGS_OFFSET)
+            }
+            FieldPointer::Leaf(pointer, ty) => {
+                let size = self.size_of_named_ty(env, ty)?;
+                (0..size).all(|i| !self.is_word_initialized(*pointer + i))
+            }
+        };
+        if moved {
+            return Ok(());
+        }
+        self.and_drop_fields(env, field_pointer)
+    }
+
     /// Drop the contents of an owned object.
     ///
     /// Intended for use with `for_each_owned_heap_value` when we copy a shared place.
@@ -953,6 +1180,32 @@ impl Interpreter {
     /// Given and Shared converge at every leaf — a Given
     /// array with refcount 1 decrements the same way as a Shared array.
     fn drop_value(&mut self, env: &Env, value: &ObjectValue) -> anyhow::Result<()> {
+        self.emit_drop(value);
+        self.run_drop_body_if_whole(env, value)?;
+        self.traverse_value(env, value, &mut Self::and_drop_fields)
+    }
+
+    /// Drop what is left of a place: a variable whose scope is ending, or the
+    /// old value of a place that is being reassigned. Unlike `drop_value`,
+    /// parts of the place that were moved out are skipped rather than dropped
+    /// again, so that a double free of a value that is still owned is not masked.
+    fn drop_initialized_parts(&mut self, env: &Env, value: &ObjectValue) -> anyhow::Result<()> {
+        self.emit_drop(value);
+        self.run_drop_body_if_whole(env, value)?;
+        self.traverse_value(env, value, &mut Self::and_drop_initialized_fields)
+    }
+
+    /// Record that `value` is being dropped, if an event sink is attached.
+    fn emit_drop(&mut self, value: &ObjectValue) {
+        self.emit(|_| events::Event::Drop {
+            pointer: value.pointer,
+            ty: value.ty.clone(),
+        });
+    }
+
+    /// Run the drop body of `value`, if it is an owned, whole instance of a
+    /// class that has one.
+    fn run_drop_body_if_whole(&mut self, env: &Env, value: &ObjectValue) -> anyhow::Result<()> {
         // Check if this is an owned, initialized class with a drop body.
         // Only owned handles (given/shared) execute the drop body.
         // Only run the drop body if the value is "whole" (all fields initialized).
@@ -976,7 +1229,7 @@ impl Interpreter {
                 }
             }
         }
-        self.traverse_value(env, value, &mut Self::and_drop_fields)
+        Ok(())
     }
 
     /// Check if a value is "whole" — all accessible places within it are initialized.
@@ -1088,7 +1341,10 @@ impl Interpreter {
             env,
             variables: Vec::new(),
         };
-        stack_frame.insert_variable(Var::Magic, value.pointer);
+        self.record(|| replay::Change::PushFrame {
+            name: format!("drop {class_name:?}"),
+        });
+        self.bind_variable(&mut stack_frame, Var::Magic, value.pointer);
 
         // Resolve Magic to get the object data pointer. This handles boxed-class
         // dereferencing (reading through [Flags, Pointer]) via the same code path
@@ -1110,12 +1366,24 @@ impl Interpreter {
         };
 
         stack_frame.env = stack_frame.env.push_local_variable(Var::This, self_ty)?;
-        stack_frame.insert_variable(Var::This, magic_data.pointer);
+        self.bind_variable(&mut stack_frame, Var::This, magic_data.pointer);
 
         self.trace(format_args!("drop {class_name:?}"));
+        self.emit(|_| events::Event::DropBody {
+            pointer: value.pointer,
+            ty: value.ty.clone(),
+        });
         self.indent += 1;
 
-        match self.eval_block(&mut stack_frame, block)? {
+        let outcome = self.eval_block(&mut stack_frame, block);
+        if outcome.is_err() {
+            // Keep the trace indentation right if a panic unwinds past us.
+            self.indent -= 1;
+            if outcome.as_ref().is_err_and(|e| e.is::<Panic>()) {
+                self.record_pop_frame();
+            }
+        }
+        match outcome? {
             Outcome::Value(tv) => {
                 self.drop_value(&stack_frame.env, &tv)?;
             }
@@ -1137,10 +1405,11 @@ impl Interpreter {
             }
             let ty = env.var_ty(var)?.clone();
             let tv = ObjectValue { pointer: *ptr, ty };
-            self.drop_value(env, &tv)?;
+            self.drop_initialized_parts(env, &tv)?;
         }
 
         self.indent -= 1;
+        self.record_pop_frame();
 
         Ok(())
     }
@@ -1291,9 +1560,12 @@ impl Interpreter {
             }
         }
 
-        let new_ptr = self.alloc_raw(Alloc {
-            data: vec![Word::MutRef(object_data.pointer)],
-        });
+        let new_ptr = self.alloc_raw(
+            Alloc {
+                data: vec![Word::MutRef(object_data.pointer)],
+            },
+            value_ty,
+        );
         Ok(ObjectValue {
             pointer: new_ptr,
             ty: value_ty.clone(),
@@ -1569,7 +1841,7 @@ impl Interpreter {
 
     /// Dump live (non-freed) allocations, one line per alloc.
     /// Uses zero-padded hex indices for visual alignment.
-    /// An allocation is "freed" if it is empty or all words are Uninitialized.
+    /// Freed allocations (see `Alloc::is_freed`) are skipped.
     pub fn dump_heap(&self) -> Vec<String> {
         let max_index = self.allocs.len();
         // Compute hex digit width: at least 2 digits, enough for all indices
@@ -1577,10 +1849,7 @@ impl Interpreter {
         self.allocs
             .iter()
             .enumerate()
-            .filter(|(_, alloc)| {
-                !alloc.data.is_empty()
-                    && !alloc.data.iter().all(|w| matches!(w, Word::Uninitialized))
-            })
+            .filter(|(_, alloc)| !alloc.is_freed())
             .map(|(i, alloc)| {
                 let words: Vec<String> =
                     alloc.data.iter().map(|w| fmt_word(w, hex_width)).collect();
@@ -1785,11 +2054,11 @@ impl Interpreter {
             data.extend_from_slice(&words);
         }
 
-        let ptr = self.alloc_raw(Alloc { data });
         let ty = Ty::NamedTy(NamedTy {
             name: class_name.upcast(),
             parameters: parameters.to_vec(),
         });
+        let ptr = self.alloc_raw(Alloc { data }, &ty);
         Ok(ObjectValue { pointer: ptr, ty })
     }
 
@@ -1838,8 +2107,29 @@ impl Interpreter {
         this: ObjectValue,
         input_values: Vec<ObjectValue>,
     ) -> anyhow::Result<ObjectValue> {
-        let method_data =
-            self.find_method(class_name, class_parameters, method_id, method_parameters)?;
+        if let Some(max_call_depth) = self.max_call_depth {
+            if self.call_stack.len() >= max_call_depth {
+                return Err(LimitExceeded::CallDepth(max_call_depth).into());
+            }
+        }
+
+        // The method runs in a fresh frame, under its own variable names.
+        // Caller places named by the types of the receiver, the arguments,
+        // or the generic parameters are imported into the frame's env.
+        let call_types: Vec<Parameter> = std::iter::once(Parameter::Ty(this.ty.clone()))
+            .chain(input_values.iter().map(|value| Parameter::Ty(value.ty.clone())))
+            .chain(class_parameters.iter().cloned())
+            .chain(method_parameters.iter().cloned())
+            .collect();
+        let (env, caller_places) =
+            caller_places::CallerPlaces::import(&caller_frame.env, self.base_env(), &call_types)?;
+
+        let method_data = self.find_method(
+            class_name,
+            &caller_places.to_callee(&class_parameters.to_vec()),
+            method_id,
+            &caller_places.to_callee(&method_parameters.to_vec()),
+        )?;
 
         if method_data.inputs.len() != input_values.len() {
             anyhow::bail!(
@@ -1849,99 +2139,92 @@ impl Interpreter {
             );
         }
 
-        // Alpha-rename the method body so its variables don't collide
-        // with the caller's scope. This lets us extend the caller's env
-        // so that place references from the caller (e.g., `v` in `mut[v]`)
-        // remain resolvable inside the method.
         self.next_call_id += 1;
         let call_id = self.next_call_id;
-        let (renamed, rename_map) = alpha_rename::alpha_rename_method(&method_data, call_id);
 
         let MethodDeclBoundData {
             this: _this_decl,
             inputs,
-            output: _,
+            output,
             predicates: _,
             body,
-        } = renamed;
-
-        // Extend the caller's env with the method's renamed bindings.
-        let mut env = caller_frame.env.clone();
+        } = method_data;
 
-        let self_var = rename_map[&Var::This].clone();
-
-        // Use the receiver's type directly as the type of the renamed self.
+        // Use the receiver's type directly as the type of self.
         // The receiver already carries the correct permission from the
         // access mode used at the call site (e.g., `v.mut` produces
         // `mut[v] Vec[T]`). Applying `this_decl.perm` on top would
         // double-wrap (e.g., `mut[v] mut[v] Vec[T]`).
         let this_ty = this.ty;
-        env = env.push_local_variable(self_var.clone(), this_ty.clone())?;
+        let env = env.push_local_variable(Var::This, caller_places.to_callee(&this_ty))?;
 
         let mut method_frame = StackFrame {
             env,
             variables: Vec::new(),
         };
-        method_frame.insert_variable(self_var, this.pointer);
+        self.record(|| replay::Change::PushFrame {
+            name: format!("{class_name:?}.{method_id:?}"),
+        });
+        self.bind_variable(&mut method_frame, Var::This, this.pointer);
         for (input, input_value) in inputs.iter().zip(input_values) {
             let var = Var::Id(input.name.clone());
             method_frame.env = method_frame
                 .env
-                .push_local_variable(var.clone(), input_value.ty)?;
-            method_frame.insert_variable(var.clone(), input_value.pointer);
+                .push_local_variable(var.clone(), caller_places.to_callee(&input_value.ty))?;
+            self.bind_variable(&mut method_frame, var.clone(), input_value.pointer);
         }
 
         self.trace(format_args!("enter {class_name:?}.{method_id:?}"));
+        self.emit(|_| events::Event::Enter {
+            call_id,
+            method: format!("{class_name:?}.{method_id:?}"),
+            pointer: this.pointer,
+            ty: this_ty,
+        });
         self.indent += 1;
+        self.call_stack.push(format!("{class_name:?}.{method_id:?}"));
+        self.entered_call = true;
 
         let result: anyhow::Result<ObjectValue> = match &body {
-            crate::grammar::MethodBody::Trusted => anyhow::bail!(
-                "method `{method_id:?}` of class `{class_name:?}` is trusted and cannot be called by the interpreter",
+            crate::grammar::MethodBody::Trusted => self.call_native(
+                &method_frame,
+                &format!("{class_name:?}.{method_id:?}"),
+                &output,
             ),
             crate::grammar::MethodBody::Block(block) => {
-                let result_tv = match self.eval_block(&mut method_frame, block)? {
-                    Outcome::Value(tv) => tv,
-                    Outcome::Return(tv) => tv,
-                    Outcome::Break => anyhow::bail!("break outside of loop"),
-                };
-                // Normalize the result type before dropping method params.
-                // The method_frame.env still has all param bindings, and
-                // all method params are dead (the method body has completed).
-                let popped_vars: Vec<Var> = method_frame
-                    .variables
-                    .iter()
-                    .map(|(var, _)| var.clone())
-                    .collect();
-                let live_after = LivePlaces::default(); // all method params are dead
-                let (normalized_ty, _proof) = normalize_ty_for_pop(
-                    &method_frame.env,
-                    &live_after,
-                    &result_tv.ty,
-                    &popped_vars,
-                )
-                .into_singleton()?;
-                let result_tv = ObjectValue {
-                    pointer: result_tv.pointer,
-                    ty: normalized_ty,
-                };
-
-                // Free any variables remaining in the method's stack frame
-                // (end-of-scope cleanup). With block-scoped drops, only
-                // method parameters remain here.
-                let env = &method_frame.env;
-                for (var, ptr) in &method_frame.variables {
-                    let ty = env.var_ty(var)?.clone();
-                    let tv = ObjectValue { pointer: *ptr, ty };
-                    self.drop_value(env, &tv)?;
-                }
-
-                Ok(result_tv)
+                self.eval_method_body(&mut method_frame, block)
             }
         };
 
         self.indent -= 1;
+        self.call_stack.pop();
+        // After a fault, a recording keeps the frame, to show where it happened.
+        if result.as_ref().map_or_else(|e| e.is::<Panic>(), |_| true) {
+            self.record_pop_frame();
+        }
 
-        let result_tv = result?;
+        let result_tv = match result {
+            Ok(result_tv) => ObjectValue {
+                pointer: result_tv.pointer,
+                ty: caller_places.to_caller(&result_tv.ty),
+            },
+            Err(mut error) => {
+                if let Some(panic) = error.downcast_mut::<Panic>() {
+                    panic.unwound.push(format!("{class_name:?}.{method_id:?}"));
+                    self.trace(format_args!("unwind {class_name:?}.{method_id:?}"));
+                    self.emit(|_| events::Event::Unwind {
+                        call_id,
+                        method: format!("{class_name:?}.{method_id:?}"),
+                    });
+                } else {
+                    self.emit(|_| events::Event::Abort {
+                        call_id,
+                        method: format!("{class_name:?}.{method_id:?}"),
+                    });
+                }
+                return Err(error);
+            }
+        };
 
         // Preservation check: the result type must be well-formed in the
         // caller's env. This ensures no method-scoped variables leak into
@@ -1959,16 +2242,104 @@ impl Interpreter {
         self.trace(format_args!(
             "exit {class_name:?}.{method_id:?} => {result_display}"
         ));
+        self.emit(|_| events::Event::Exit {
+            call_id,
+            method: format!("{class_name:?}.{method_id:?}"),
+            pointer: result_tv.pointer,
+            ty: result_tv.ty.clone(),
+        });
+
+        Ok(result_tv)
+    }
+
+    /// Evaluate the body of a method in `method_frame`, then drop the
+    /// method's parameters. If the body panics, the parameters are dropped
+    /// as the panic unwinds.
+    fn eval_method_body(
+        &mut self,
+        method_frame: &mut StackFrame,
+        block: &crate::grammar::Block,
+    ) -> anyhow::Result<ObjectValue> {
+        let outcome = match self.eval_block(method_frame, block) {
+            Ok(outcome) => outcome,
+            Err(error) => {
+                return Err(self.unwind(error, |this| this.drop_frame_variables(method_frame)))
+            }
+        };
+        let result_tv = match outcome {
+            Outcome::Value(tv) => tv,
+            Outcome::Return(tv) => tv,
+            Outcome::Break => anyhow::bail!("break outside of loop"),
+        };
+        // Normalize the result type before dropping method params.
+        // The method_frame.env still has all param bindings, and
+        // all method params are dead (the method body has completed).
+        let popped_vars: Vec<Var> = method_frame
+            .variables
+            .iter()
+            .map(|(var, _)| var.clone())
+            .collect();
+        let live_after = LivePlaces::default(); // all method params are dead
+        let (normalized_ty, _proof) =
+            normalize_ty_for_pop(&method_frame.env, &live_after, &result_tv.ty, &popped_vars)
+                .into_singleton()?;
+        let result_tv = ObjectValue {
+            pointer: result_tv.pointer,
+            ty: normalized_ty,
+        };
+
+        // Free any variables remaining in the method's stack frame
+        // (end-of-scope cleanup). With block-scoped drops, only
+        // method parameters remain here.
+        self.drop_frame_variables(method_frame)?;
 
         Ok(result_tv)
     }
 
+    /// Drop every variable in `stack_frame`, in declaration order.
+    fn drop_frame_variables(&mut self, stack_frame: &StackFrame) -> anyhow::Result<()> {
+        let env = &stack_frame.env;
+        for (var, ptr) in &stack_frame.variables {
+            let ty = env.var_ty(var)?.clone();
+            let tv = ObjectValue { pointer: *ptr, ty };
+            self.drop_initialized_parts(env, &tv)?;
+        }
+        Ok(())
+    }
+
+    /// Called when evaluating a scope fails with `error`. If `error` is a
+    /// [`Panic`], run `cleanup` to drop the scope's live values before the
+    /// panic continues; any other fault abandons them. A fault during
+    /// `cleanup` (such as a second panic in a drop body) stops the unwinding.
+    fn unwind(
+        &mut self,
+        error: anyhow::Error,
+        cleanup: impl FnOnce(&mut Self) -> anyhow::Result<()>,
+    ) -> anyhow::Error {
+        if error.downcast_ref::<Panic>().is_none() {
+            return error;
+        }
+        match cleanup(self) {
+            Ok(()) => error,
+            Err(e) => anyhow::anyhow!("fault while unwinding from `{error}`: {e:#}"),
+        }
+    }
+
     // ---------------------------------------------------------------
     // Evaluation
     // ---------------------------------------------------------------
 
     /// Run a program by instantiating `Main()` and calling `main`.
+    ///
+    /// The type-system judgments used to inspect values (e.g., `prove_is_copy`)
+    /// are memoized for the duration of the run.
     pub fn interpret(&mut self) -> anyhow::Result<ObjectValue> {
+        self.fuel_used = 0;
+        let result = memo::with_memo(|| self.interpret_main()).0;
+        self.finish_events(result)
+    }
+
+    fn interpret_main(&mut self) -> anyhow::Result<ObjectValue> {
         let main_class: ValueId = crate::dada_lang::try_term("Main")?;
         let main_method: MethodId = crate::dada_lang::try_term("main")?;
         let env = self.base_env();
@@ -2000,7 +2371,16 @@ impl Interpreter {
 
         let mut final_value = self.unit_value();
         for statement in statements {
-            match self.eval_statement(stack_frame, statement)? {
+            let outcome = match self.eval_statement(stack_frame, statement) {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    return Err(self.unwind(error, |this| {
+                        this.drop_value(&stack_frame.env, &final_value)?;
+                        this.drop_block_scoped_vars(stack_frame, vars_before)
+                    }))
+                }
+            };
+            match outcome {
                 Outcome::Value(tv) => {
                     self.drop_value(&stack_frame.env, &final_value)?;
                     final_value = tv;
@@ -2058,22 +2438,66 @@ impl Interpreter {
     ) -> anyhow::Result<()> {
         while stack_frame.variables.len() > vars_before {
             let (var, ptr) = stack_frame.variables.pop().unwrap();
+            self.record(|| replay::Change::Unbind {
+                var: format!("{var:?}"),
+                pointer: ptr,
+            });
             let ty = stack_frame.env.var_ty(&var)?.clone();
             let tv = ObjectValue { pointer: ptr, ty };
 
-            self.drop_value(&stack_frame.env, &tv)?;
+            self.drop_initialized_parts(&stack_frame.env, &tv)?;
             stack_frame.env.pop_local_variables(vec![var])?;
         }
         Ok(())
     }
 
+    /// Give the debugger, if one is attached, the chance to pause before `statement`.
+    fn pause_in_debugger(
+        &mut self,
+        stack_frame: &StackFrame,
+        statement: &crate::grammar::Statement,
+    ) -> anyhow::Result<()> {
+        let entered_call = std::mem::take(&mut self.entered_call);
+        let Some(mut debugger) = self.debugger.take() else {
+            return Ok(());
+        };
+        let result = debugger.before_statement(self, stack_frame, statement, entered_call);
+        self.debugger = Some(debugger);
+        result
+    }
+
+    /// Use one unit of fuel, faulting if there is none left.
+    fn charge_fuel(&mut self) -> anyhow::Result<()> {
+        self.fuel_used += 1;
+        if let Some(fuel) = self.fuel {
+            if self.fuel_used > fuel {
+                return Err(LimitExceeded::Fuel(fuel).into());
+            }
+        }
+        Ok(())
+    }
+
     fn eval_statement(
         &mut self,
         stack_frame: &mut StackFrame,
         statement: &crate::grammar::Statement,
     ) -> anyhow::Result<Outcome> {
+        self.statements_executed += 1;
+        self.charge_fuel()?;
+        self.pause_in_debugger(stack_frame, statement)?;
         self.trace(format_args!("{statement:?}"));
 
+        self.record_statement_start(statement);
+        let outcome = self.eval_statement_kind(stack_frame, statement);
+        self.record_statement_end();
+        outcome
+    }
+
+    fn eval_statement_kind(
+        &mut self,
+        stack_frame: &mut StackFrame,
+        statement: &crate::grammar::Statement,
+    ) -> anyhow::Result<Outcome> {
         match statement {
             crate::grammar::Statement::Expr(expr) => self.eval_expr(stack_frame, expr),
 
@@ -2082,7 +2506,7 @@ impl Interpreter {
                 let var = Var::Id(name.clone());
                 let ty = tv.ty.clone();
                 stack_frame.env = stack_frame.env.push_local_variable(var.clone(), tv.ty)?;
-                stack_frame.insert_variable(var.clone(), tv.pointer);
+                self.bind_variable(stack_frame, var.clone(), tv.pointer);
 
                 let display_tv = ObjectValue {
                     pointer: tv.pointer,
@@ -2108,8 +2532,9 @@ impl Interpreter {
                     let field_value =
                         self.resolve_projection(env, &owner_object_data, &last_projection)?;
 
-                    // Drop the old value at the field before overwriting.
-                    self.drop_value(
+                    // Drop what is left of the old value at the field (it may
+                    // have been moved out) before overwriting.
+                    self.drop_initialized_parts(
                         env,
                         &ObjectValue {
                             pointer: field_value.pointer,
@@ -2128,8 +2553,9 @@ impl Interpreter {
                         .get_variable(&place.var)
                         .ok_or_else(|| anyhow::anyhow!("undefined variable `{:?}`", place.var))?;
 
-                    // Drop the old value before overwriting.
-                    self.drop_value(
+                    // Drop what is left of the old value (it may have been
+                    // moved out) before overwriting.
+                    self.drop_initialized_parts(
                         env,
                         &ObjectValue {
                             pointer: var_ptr,
@@ -2156,6 +2582,8 @@ impl Interpreter {
             }
 
             crate::grammar::Statement::Loop(body) => loop {
+                // Charged per iteration, since the body may have no statements to charge.
+                self.charge_fuel()?;
                 match self.eval_block(stack_frame, body)? {
                     Outcome::Value(tv) => {
                         self.drop_value(&stack_frame.env, &tv)?;
@@ -2247,10 +2675,7 @@ impl Interpreter {
                     let tv = self.eval_expr_value(stack_frame, expr)?;
                     self.drop_value(&stack_frame.env, &tv)?;
                 }
-                Ok(Outcome::Value(ObjectValue {
-                    pointer: self.alloc_raw(Alloc { data: vec![] }),
-                    ty: Ty::unit(),
-                }))
+                Ok(Outcome::Value(self.unit_value()))
             }
 
             crate::grammar::Expr::New(class_name, params, field_exprs) => {
@@ -2260,6 +2685,7 @@ impl Interpreter {
                     .collect::<Result<_, _>>()?;
                 let env = &stack_frame.env;
                 let result = self.instantiate_class(env, class_name, params, &field_values)?;
+                self.set_alloc_expr(result.pointer, expr);
                 for fv in &field_values {
                     // Scrub the temp without dropping — ownership moved into the class.
                     self.uninitialize(env, fv)?;
@@ -2271,6 +2697,12 @@ impl Interpreter {
                 let resolved = self.resolve_place_to_object_data(stack_frame, place)?;
                 let env = &stack_frame.env;
                 let place_ty = stack_frame.env.place_ty(place)?;
+                self.emit(|_| events::Event::Access {
+                    access: access.clone(),
+                    place: place.clone(),
+                    pointer: resolved.pointer,
+                    ty: place_ty.clone(),
+                });
                 let tv = match access {
                     crate::grammar::Access::Gv => self.give_place(env, &resolved, &place_ty)?,
                     crate::grammar::Access::Rf => self.ref_place(
@@ -2364,11 +2796,16 @@ impl Interpreter {
                 for _ in 0..length {
                     data.extend(std::iter::repeat(Word::Uninitialized).take(element_size));
                 }
-                let alloc_ptr = self.alloc_raw(Alloc { data });
-
-                let value_ptr = self.alloc_raw(Alloc {
-                    data: vec![Word::Flags(Flags::Given), Word::Pointer(alloc_ptr)],
-                });
+                let alloc_ptr = self.alloc_raw(Alloc { data }, &array_ty);
+                self.set_alloc_expr(alloc_ptr, expr);
+
+                let value_ptr = self.alloc_raw(
+                    Alloc {
+                        data: vec![Word::Flags(Flags::Given), Word::Pointer(alloc_ptr)],
+                    },
+                    &array_ty,
+                );
+                self.set_alloc_expr(value_ptr, expr);
                 Ok(Outcome::Value(ObjectValue {
                     pointer: value_ptr,
                     ty: array_ty,
@@ -2510,7 +2947,7 @@ impl Interpreter {
                 }))
             }
 
-            crate::grammar::Expr::Panic => anyhow::bail!("panic!"),
+            crate::grammar::Expr::Panic => Err(Panic::default().into()),
 
             crate::grammar::Expr::Clear(var) => {
                 let var_key = Var::Id(var.clone());
@@ -0,0 +1,294 @@
+//! Native implementations of trusted methods.
+//!
+//! A method whose body is `...;` is trusted: the type checker takes its
+//! signature on faith, and the interpreter has no body to run.
+//! [`Interpreter::register_native`] supplies a Rust closure to run instead, so
+//! that standard-library classes can be prototyped in Rust and the Dada code
+//! that calls them can be tested. The closure sees the receiver and arguments
+//! as [`ObjectValue`]s and reaches their words through a [`Heap`], which checks
+//! every access. The value it returns is checked against the method's declared
+//! output type.
+
+use std::rc::Rc;
+
+use crate::grammar::{ClassDeclBoundData, FieldId, NamedTy, Parameter, Ty, TypeName};
+use crate::type_system::env::Env;
+
+use super::{Alloc, Interpreter, ObjectValue, Pointer, StackFrame, Word};
+
+#[cfg(test)]
+mod tests;
+
+/// A native implementation of a trusted method. It is given the receiver
+/// (`self`) and the arguments, and returns the method's result.
+///
+/// The receiver and arguments belong to the interpreter: like the parameters
+/// of any method, they are dropped once the closure returns (or fails). To
+/// return (part of) one of them, copy its words into a new allocation with
+/// [`Heap::alloc`], which only builds values made of `Int`s and `Bool`s.
+///
+/// The closure is `Fn`, not `FnMut`, so that it stays registered while it runs
+/// and a call that reaches the same method again finds it.
+pub type NativeMethod =
+    Rc<dyn Fn(&mut Heap<'_>, &ObjectValue, &[ObjectValue]) -> anyhow::Result<ObjectValue>>;
+
+/// A native method's view of the interpreter's memory.
+pub struct Heap<'i> {
+    interpreter: &'i mut Interpreter,
+    env: &'i Env,
+}
+
+impl Heap<'_> {
+    /// Read the word at `pointer`. Faults if it is uninitialized.
+    pub fn read(&self, pointer: Pointer) -> anyhow::Result<Word> {
+        self.check_bounds(pointer)?;
+        self.interpreter.read_word(pointer)
+    }
+
+    /// Read the `Int` word at `pointer`.
+    pub fn read_int(&self, pointer: Pointer) -> anyhow::Result<i64> {
+        self.check_bounds(pointer)?;
+        self.interpreter.read_int(pointer)
+    }
+
+    /// Overwrite the `Int` (or `Bool`) word at `pointer` with another `Int` word.
+    /// Flags, pointers, refcounts and capacities are how the interpreter manages
+    /// memory, so they cannot be written, nor can uninitialized words.
+    pub fn write(&mut self, pointer: Pointer, word: Word) -> anyhow::Result<()> {
+        self.check_bounds(pointer)?;
+        let Word::Int(_) = word else {
+            anyhow::bail!("native method can only write `Int` words, not `{word:?}`");
+        };
+        let old = self.interpreter.allocs[pointer.index].data[pointer.offset];
+        let Word::Int(_) = old else {
+            anyhow::bail!(
+                "native method can only overwrite `Int` words, but {pointer:?} holds `{old:?}`"
+            );
+        };
+        self.interpreter.write_word(pointer, word);
+        Ok(())
+    }
+
+    /// The field `name` of `value`, which must be a class laid out flat
+    /// (or a `mut` reference to one). The field has its declared type.
+    pub fn field(&self, value: &ObjectValue, name: &str) -> anyhow::Result<ObjectValue> {
+        let mut pointer = value.pointer;
+        if self.interpreter.is_mut_ref_type(self.env, &value.ty) {
+            self.check_bounds(pointer)?;
+            pointer = self.interpreter.read_mut_ref(pointer)?;
+        } else if self.interpreter.is_boxed_type(self.env, &value.ty) {
+            anyhow::bail!(
+                "cannot access field `{name}` of boxed value `{:?}`",
+                value.ty
+            );
+        }
+        let named_ty = self.interpreter.named_ty(&value.ty);
+        let TypeName::Id(class_name) = &named_ty.name else {
+            anyhow::bail!("cannot access field `{name}` of `{:?}`", value.ty);
+        };
+        let field_id: FieldId = crate::dada_lang::try_term(name)?;
+        let (offset, ty) = self.interpreter.field_offset_by_name(
+            self.env,
+            class_name,
+            &named_ty.parameters,
+            &field_id,
+        )?;
+        Ok(ObjectValue {
+            pointer: pointer + offset,
+            ty,
+        })
+    }
+
+    /// A new `Int`.
+    pub fn int(&mut self, n: i64) -> ObjectValue {
+        ObjectValue {
+            pointer: self.interpreter.alloc_int(n),
+            ty: Ty::int(),
+        }
+    }
+
+    /// A new `Bool`.
+    pub fn bool(&mut self, b: bool) -> ObjectValue {
+        let pointer = self.interpreter.alloc_int(if b { 1 } else { 0 });
+        ObjectValue {
+            pointer,
+            ty: Ty::bool(),
+        }
+    }
+
+    /// A new `()`.
+    pub fn unit(&mut self) -> ObjectValue {
+        self.interpreter.unit_value()
+    }
+
+    /// A new allocation holding `words`, to be interpreted as a `ty`.
+    ///
+    /// `ty` must be laid out flat, with only `Int` and `Bool` words (e.g., a
+    /// class whose fields are all `Int`s), and `words` must be that many `Int`
+    /// words. Pointers, flags and refcounts are how the interpreter manages
+    /// memory, so a native method cannot make them up.
+    pub fn alloc(&mut self, ty: Ty, words: Vec<Word>) -> anyhow::Result<ObjectValue> {
+        if !self.interpreter.has_int_layout(self.env, &ty)? {
+            anyhow::bail!(
+                "native method can only allocate values made of `Int` and `Bool` words, \
+                 not `{ty:?}`"
+            );
+        }
+        let size = self.interpreter.size_of(self.env, &ty)?;
+        if words.len() != size {
+            anyhow::bail!(
+                "a `{ty:?}` is {size} words, but the native method gave {}",
+                words.len()
+            );
+        }
+        if let Some(word) = words.iter().find(|word| !matches!(word, Word::Int(_))) {
+            anyhow::bail!("native method can only allocate `Int` words, not `{word:?}`");
+        }
+        let pointer = self.interpreter.alloc_raw(Alloc { data: words }, &ty);
+        Ok(ObjectValue { pointer, ty })
+    }
+
+    fn check_bounds(&self, pointer: Pointer) -> anyhow::Result<()> {
+        let in_bounds = self
+            .interpreter
+            .allocs
+            .get(pointer.index)
+            .is_some_and(|alloc| pointer.offset < alloc.data.len());
+        if !in_bounds {
+            anyhow::bail!("native method accessed out-of-bounds word {pointer:?}");
+        }
+        Ok(())
+    }
+}
+
+impl Interpreter {
+    /// Run `method` in place of the trusted (`...;`) method `method_name` of
+    /// class `class_name`. Registering a second implementation replaces the first.
+    pub fn register_native<F>(&mut self, class_name: &str, method_name: &str, method: F)
+    where
+        F: Fn(&mut Heap<'_>, &ObjectValue, &[ObjectValue]) -> anyhow::Result<ObjectValue>,
+        F: 'static,
+    {
+        self.natives
+            .insert(format!("{class_name}.{method_name}"), Rc::new(method));
+    }
+
+    /// Call the native implementation of the trusted method `name`
+    /// (`Class.method`), whose receiver and arguments are the variables of
+    /// `method_frame`, then drop them. The result must be a `output`.
+    pub(super) fn call_native(
+        &mut self,
+        method_frame: &StackFrame,
+        name: &str,
+        output: &Ty,
+    ) -> anyhow::Result<ObjectValue> {
+        let Some(method) = self.natives.get(name).cloned() else {
+            anyhow::bail!(
+                "method `{name}` is trusted and has no native implementation \
+                 (see `Interpreter::register_native`)"
+            );
+        };
+
+        let mut values = vec![];
+        for (var, pointer) in &method_frame.variables {
+            let ty = method_frame.env.var_ty(var)?.clone();
+            values.push(ObjectValue {
+                pointer: *pointer,
+                ty,
+            });
+        }
+        let (this, inputs) = values
+            .split_first()
+            .expect("a method frame always holds `self`");
+
+        let mut heap = Heap {
+            interpreter: self,
+            env: &method_frame.env,
+        };
+        let result = method(&mut heap, this, inputs).and_then(|result| {
+            self.check_native_result(&method_frame.env, name, output, &result)?;
+            Ok(result)
+        });
+
+        // The receiver and arguments are dropped whether or not the method succeeded.
+        let dropped = self.drop_frame_variables(method_frame);
+        match (result, dropped) {
+            (Ok(result), Ok(())) => Ok(result),
+            (Ok(_), Err(error)) => Err(error),
+            (Err(error), Ok(())) => Err(error),
+            (Err(error), Err(e)) => Err(anyhow::anyhow!(
+                "fault while dropping the arguments of `{name}` after `{error}`: {e:#}"
+            )),
+        }
+    }
+
+    /// True if `ty` is laid out flat, with only `Int` and `Bool` words:
+    /// no `MutRef`, no boxed value, and no flags (e.g., a class whose fields
+    /// are all `Int`s).
+    fn has_int_layout(&self, env: &Env, ty: &Ty) -> anyhow::Result<bool> {
+        if self.is_mut_ref_type(env, ty) || self.is_boxed_type(env, ty) {
+            return Ok(false);
+        }
+        let Some(NamedTy { name, parameters }) = ty.to_named_ty() else {
+            return Ok(false);
+        };
+        match name {
+            TypeName::Int | TypeName::Bool => Ok(true),
+            TypeName::Array => Ok(false),
+            TypeName::Tuple(_) => {
+                for param in &parameters {
+                    let Parameter::Ty(ty) = param else {
+                        anyhow::bail!("tuple parameter is not a type: `{param:?}`");
+                    };
+                    if !self.has_int_layout(env, ty)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            TypeName::Id(class_name) => {
+                let class_decl = self.program.class_named(&class_name)?;
+                let ClassDeclBoundData { fields, .. } =
+                    class_decl.binder.instantiate_with(&parameters)?;
+                for field in &fields {
+                    if !self.has_int_layout(env, &field.ty)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    /// Check that `result`, returned by the native method `name`, has the
+    /// layout of its declared `output` type and lies within its allocation.
+    fn check_native_result(
+        &self,
+        env: &Env,
+        name: &str,
+        output: &Ty,
+        result: &ObjectValue,
+    ) -> anyhow::Result<()> {
+        let same_type = output.to_named_ty().is_some()
+            && output.to_named_ty() == result.ty.to_named_ty()
+            && self.is_mut_ref_type(env, output) == self.is_mut_ref_type(env, &result.ty);
+        if !same_type {
+            anyhow::bail!(
+                "native method `{name}` returned a `{:?}`, but it is declared to return `{output:?}`",
+                result.ty
+            );
+        }
+        let size = self.size_of(env, &result.ty)?;
+        let in_bounds = self
+            .allocs
+            .get(result.pointer.index)
+            .is_some_and(|alloc| result.pointer.offset + size <= alloc.data.len());
+        if !in_bounds {
+            anyhow::bail!(
+                "native method `{name}` returned a `{:?}` that does not fit in its allocation",
+                result.ty
+            );
+        }
+        Ok(())
+    }
+}
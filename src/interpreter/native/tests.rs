@@ -0,0 +1,190 @@
+use crate::grammar::Ty;
+use crate::interpreter::{Interpreter, Word};
+use crate::test_util::parse_program;
+use crate::type_system::env::Env;
+
+const COUNTER: &str = "
+    class Counter {
+        n: Int;
+
+        fn doubled(given self) -> Int
+        ...;
+
+        fn add(given self, x: Int, y: Int) -> Int
+        ...;
+
+        fn reset(given self) -> Counter
+        ...;
+    }
+
+    class Main {
+        fn main(given self) -> Int {
+            let c = new Counter(21);
+            c.give.doubled();
+        }
+    }
+";
+
+/// Run `program` after `setup` has registered its native methods,
+/// returning the interpreter and the result as displayed.
+fn run(
+    program: &str,
+    setup: impl FnOnce(&mut Interpreter),
+) -> (Interpreter, anyhow::Result<String>) {
+    let program = parse_program(&[program]);
+    let mut interpreter = Interpreter::new(program.clone());
+    setup(&mut interpreter);
+    let result = interpreter
+        .interpret()
+        .and_then(|result| interpreter.display_value(&Env::new(program), &result));
+    (interpreter, result)
+}
+
+#[test]
+fn native_reads_a_field() {
+    let (interpreter, result) = run(COUNTER, |interpreter| {
+        interpreter.register_native("Counter", "doubled", |heap, this, _| {
+            let n = heap.field(this, "n")?;
+            let n = heap.read_int(n.pointer())?;
+            Ok(heap.int(n * 2))
+        })
+    });
+    assert_eq!(result.unwrap(), "42");
+
+    // The receiver is dropped when the native method returns.
+    let audit = interpreter.audit(None);
+    assert_eq!(audit.problems.len(), 1, "{audit}");
+    assert!(
+        audit.problems[0].to_string().contains("[Int(42)]"),
+        "{audit}"
+    );
+}
+
+#[test]
+fn native_receives_arguments() {
+    let program = COUNTER.replace("c.give.doubled()", "c.give.add(1, 20)");
+    let (_, result) = run(&program, |interpreter| {
+        interpreter.register_native("Counter", "add", |heap, this, args| {
+            let n = heap.field(this, "n")?;
+            let mut sum = heap.read_int(n.pointer())?;
+            for arg in args {
+                sum += heap.read_int(arg.pointer())?;
+            }
+            Ok(heap.int(sum))
+        })
+    });
+    assert_eq!(result.unwrap(), "42");
+}
+
+#[test]
+fn native_builds_an_object() {
+    let program = COUNTER
+        .replace("-> Int {", "-> Counter {")
+        .replace("c.give.doubled()", "c.give.reset()");
+    let (_, result) = run(&program, |interpreter| {
+        interpreter.register_native("Counter", "reset", |heap, this, _| {
+            let n = heap.field(this, "n")?;
+            heap.write(n.pointer(), Word::Int(0))?;
+            let words = vec![heap.read(n.pointer())?];
+            heap.alloc(this.ty().clone(), words)
+        })
+    });
+    assert_eq!(result.unwrap(), "Counter { n: 0 }");
+}
+
+#[test]
+fn unregistered_trusted_method_faults() {
+    let (_, result) = run(COUNTER, |_| {});
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("`Counter.doubled` is trusted"), "{error}");
+}
+
+#[test]
+fn out_of_bounds_access_faults() {
+    let (_, result) = run(COUNTER, |interpreter| {
+        interpreter.register_native("Counter", "doubled", |heap, this, _| {
+            let n = heap.field(this, "n")?;
+            heap.read_int(n.pointer() + 1)?;
+            Ok(heap.int(0))
+        })
+    });
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("out-of-bounds"), "{error}");
+}
+
+#[test]
+fn writing_a_non_int_word_faults() {
+    let (_, result) = run(COUNTER, |interpreter| {
+        interpreter.register_native("Counter", "doubled", |heap, this, _| {
+            let n = heap.field(this, "n")?;
+            heap.write(n.pointer(), Word::RefCount(1))?;
+            Ok(heap.int(0))
+        })
+    });
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("can only write `Int` words"), "{error}");
+}
+
+/// A native method cannot make up a pointer, even for a value whose layout
+/// has room for one.
+#[test]
+fn allocating_a_non_int_word_faults() {
+    let program = COUNTER
+        .replace("-> Int {", "-> Counter {")
+        .replace("c.give.doubled()", "c.give.reset()");
+    let (_, result) = run(&program, |interpreter| {
+        interpreter.register_native("Counter", "reset", |heap, this, _| {
+            let pointer = heap.field(this, "n")?.pointer();
+            heap.alloc(this.ty().clone(), vec![Word::Pointer(pointer)])
+        })
+    });
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("can only allocate `Int` words"), "{error}");
+}
+
+/// Nor can it allocate a value whose layout holds pointers or flags.
+#[test]
+fn allocating_a_boxed_value_faults() {
+    let (_, result) = run(COUNTER, |interpreter| {
+        interpreter.register_native("Counter", "doubled", |heap, _, _| {
+            let ty: Ty = crate::dada_lang::term("Array[Int]");
+            heap.alloc(ty, vec![Word::Int(0), Word::Int(0)])?;
+            Ok(heap.int(0))
+        })
+    });
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.contains("values made of `Int` and `Bool` words"),
+        "{error}"
+    );
+}
+
+#[test]
+fn result_of_the_wrong_type_faults() {
+    let (_, result) = run(COUNTER, |interpreter| {
+        interpreter.register_native("Counter", "doubled", |heap, _, _| Ok(heap.unit()))
+    });
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("declared to return `Int`"), "{error}");
+}
+
+/// The receiver is dropped even when the native method fails.
+#[test]
+fn failing_native_drops_its_arguments() {
+    let (interpreter, result) = run(COUNTER, |interpreter| {
+        interpreter.register_native("Counter", "doubled", |_, _, _| {
+            Err(anyhow::anyhow!("not today"))
+        })
+    });
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("not today"), "{error}");
+
+    let audit = interpreter.audit(None);
+    assert!(
+        audit
+            .problems
+            .iter()
+            .all(|problem| !problem.to_string().contains("Int(21)")),
+        "{audit}"
+    );
+}
@@ -0,0 +1,309 @@
+//! Record a run of the interpreter, then replay it backwards and forwards.
+//!
+//! While recording (see [`Interpreter::start_recording`]), every allocation,
+//! every word written to the heap, and every variable bound or unbound in a
+//! [`StackFrame`] becomes a [`Step`] of the [`Recording`], tagged with the
+//! statement being evaluated. Each step holds enough to be undone, so a
+//! [`Replay`] can move to any step index and show the heap and frames as they
+//! were just before it; the end of the recording is the state the run stopped
+//! in (e.g., when it faulted).
+
+use crate::grammar::{Statement, Var};
+
+use super::{Interpreter, Pointer, StackFrame, Word};
+
+#[cfg(test)]
+mod tests;
+
+/// One change to the interpreter's state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// A new allocation was made at `pointer` (whose offset is 0).
+    Alloc { pointer: Pointer, words: Vec<Word> },
+
+    /// The word at `pointer` changed from `old` to `new`.
+    Write {
+        pointer: Pointer,
+        old: Word,
+        new: Word,
+    },
+
+    /// A stack frame was pushed, for a method call or a drop body.
+    PushFrame { name: String },
+
+    /// The innermost stack frame was popped; this is what it held.
+    PopFrame { frame: RecordedFrame },
+
+    /// A variable was bound in the innermost stack frame.
+    Bind { var: String, pointer: Pointer },
+
+    /// The most recently bound variable of the innermost stack frame was unbound.
+    Unbind { var: String, pointer: Pointer },
+}
+
+/// A change, and the statement that was being evaluated when it happened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub change: Change,
+
+    /// Index into [`Recording::statements`], or `None` for changes made
+    /// outside any statement (e.g., allocating `Main` before calling `main`).
+    pub statement: Option<usize>,
+}
+
+/// A statement evaluated during the run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedStatement {
+    /// The statement's number, as shown by the debugger (`#N`).
+    pub number: usize,
+
+    /// The method being executed, as `Class.method`.
+    pub method: String,
+
+    /// The statement, as shown in the trace.
+    pub text: String,
+}
+
+impl std::fmt::Display for RecordedStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{} {}: {}", self.number, self.method, self.text)
+    }
+}
+
+/// The variables of a stack frame, in the order they were bound.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecordedFrame {
+    pub name: String,
+    pub variables: Vec<(String, Pointer)>,
+}
+
+/// Everything recorded during a run.
+#[derive(Clone, Debug, Default)]
+pub struct Recording {
+    pub steps: Vec<Step>,
+    pub statements: Vec<RecordedStatement>,
+}
+
+impl Recording {
+    /// The statement that was being evaluated when step `index` happened.
+    pub fn statement(&self, index: usize) -> Option<&RecordedStatement> {
+        let statement = self.steps.get(index)?.statement?;
+        self.statements.get(statement)
+    }
+
+    /// The last step before step `before` that wrote the word at `pointer`
+    /// (including the allocation that created it), if any.
+    pub fn last_write(&self, pointer: Pointer, before: usize) -> Option<usize> {
+        self.steps[..before.min(self.steps.len())]
+            .iter()
+            .rposition(|step| match &step.change {
+                Change::Write { pointer: p, .. } => *p == pointer,
+                Change::Alloc { pointer: p, words } => {
+                    p.index == pointer.index && pointer.offset < words.len()
+                }
+                _ => false,
+            })
+    }
+}
+
+/// The state the interpreter was in just before some step of a [`Recording`].
+pub struct Replay<'r> {
+    recording: &'r Recording,
+    position: usize,
+    heap: Vec<Vec<Word>>,
+    frames: Vec<RecordedFrame>,
+}
+
+impl<'r> Replay<'r> {
+    /// Start replaying `recording`, positioned at its end.
+    pub fn new(recording: &'r Recording) -> Self {
+        let mut replay = Replay {
+            recording,
+            position: 0,
+            heap: vec![],
+            frames: vec![],
+        };
+        replay.seek(recording.steps.len());
+        replay
+    }
+
+    /// The index of the next step to apply; the state shown is from just
+    /// before it. Ranges from 0 (before anything happened) to the number of steps.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Move to just before step `position` (clamped to the end of the recording),
+    /// undoing or redoing steps as needed.
+    pub fn seek(&mut self, position: usize) {
+        let position = position.min(self.recording.steps.len());
+        while self.position < position {
+            let change = &self.recording.steps[self.position].change;
+            apply(change, &mut self.heap, &mut self.frames);
+            self.position += 1;
+        }
+        while self.position > position {
+            self.position -= 1;
+            let change = &self.recording.steps[self.position].change;
+            undo(change, &mut self.heap, &mut self.frames);
+        }
+    }
+
+    /// Move back by one step, if not at the start.
+    pub fn step_back(&mut self) {
+        self.seek(self.position.saturating_sub(1));
+    }
+
+    /// Move forward by one step, if not at the end.
+    pub fn step_forward(&mut self) {
+        self.seek(self.position + 1);
+    }
+
+    /// The words of every allocation made so far, indexed like the interpreter's heap.
+    pub fn heap(&self) -> &[Vec<Word>] {
+        &self.heap
+    }
+
+    /// The word at `pointer`, if it has been allocated yet.
+    pub fn word(&self, pointer: Pointer) -> Option<Word> {
+        self.heap.get(pointer.index)?.get(pointer.offset).copied()
+    }
+
+    /// The stack frames, innermost last.
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+}
+
+fn apply(change: &Change, heap: &mut Vec<Vec<Word>>, frames: &mut Vec<RecordedFrame>) {
+    match change {
+        Change::Alloc { pointer, words } => {
+            assert_eq!(pointer.index, heap.len(), "allocations are made in order");
+            heap.push(words.clone());
+        }
+        Change::Write { pointer, new, .. } => heap[pointer.index][pointer.offset] = *new,
+        Change::PushFrame { .. }
+        | Change::PopFrame { .. }
+        | Change::Bind { .. }
+        | Change::Unbind { .. } => apply_to_frames(change, frames),
+    }
+}
+
+fn undo(change: &Change, heap: &mut Vec<Vec<Word>>, frames: &mut Vec<RecordedFrame>) {
+    match change {
+        Change::Alloc { .. } => {
+            heap.pop();
+        }
+        Change::Write { pointer, old, .. } => heap[pointer.index][pointer.offset] = *old,
+        Change::PushFrame { .. } => {
+            frames.pop();
+        }
+        Change::PopFrame { frame } => frames.push(frame.clone()),
+        Change::Bind { .. } => {
+            innermost(frames).variables.pop();
+        }
+        Change::Unbind { var, pointer } => {
+            innermost(frames).variables.push((var.clone(), *pointer))
+        }
+    }
+}
+
+/// Apply a frame change; the recorder uses this to track the frames as they are.
+fn apply_to_frames(change: &Change, frames: &mut Vec<RecordedFrame>) {
+    match change {
+        Change::PushFrame { name } => frames.push(RecordedFrame {
+            name: name.clone(),
+            variables: vec![],
+        }),
+        Change::PopFrame { .. } => {
+            frames.pop();
+        }
+        Change::Bind { var, pointer } => innermost(frames).variables.push((var.clone(), *pointer)),
+        Change::Unbind { .. } => {
+            innermost(frames).variables.pop();
+        }
+        Change::Alloc { .. } | Change::Write { .. } => {}
+    }
+}
+
+fn innermost(frames: &mut [RecordedFrame]) -> &mut RecordedFrame {
+    frames.last_mut().expect("variables are bound in a frame")
+}
+
+/// The recording in progress.
+#[derive(Default)]
+pub(super) struct Recorder {
+    recording: Recording,
+
+    /// The statements being evaluated, innermost last, as indices into
+    /// `recording.statements`.
+    current: Vec<usize>,
+
+    /// The frames as they are now, so that popping one can record what it held.
+    frames: Vec<RecordedFrame>,
+}
+
+impl Recorder {
+    fn push(&mut self, change: Change) {
+        apply_to_frames(&change, &mut self.frames);
+        self.recording.steps.push(Step {
+            change,
+            statement: self.current.last().copied(),
+        });
+    }
+}
+
+impl Interpreter {
+    /// Record every change to the heap and to the stack frames from now on.
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(Recorder::default());
+    }
+
+    /// Stop recording and return what was recorded, if recording was started.
+    pub fn take_recording(&mut self) -> Option<Recording> {
+        self.recorder.take().map(|recorder| recorder.recording)
+    }
+
+    /// Record the change built by `change`, if recording.
+    pub(super) fn record(&mut self, change: impl FnOnce() -> Change) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push(change());
+        }
+    }
+
+    /// Record that the innermost stack frame was popped, if recording.
+    pub(super) fn record_pop_frame(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            let frame = recorder.frames.last().cloned().unwrap_or_default();
+            recorder.push(Change::PopFrame { frame });
+        }
+    }
+
+    /// Bind `var` to `pointer` in `frame`, recording it.
+    pub(super) fn bind_variable(&mut self, frame: &mut StackFrame, var: Var, pointer: Pointer) {
+        self.record(|| Change::Bind {
+            var: format!("{var:?}"),
+            pointer,
+        });
+        frame.insert_variable(var, pointer);
+    }
+
+    /// Note that `statement` is about to be evaluated, if recording.
+    pub(super) fn record_statement_start(&mut self, statement: &Statement) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.current.push(recorder.recording.statements.len());
+            recorder.recording.statements.push(RecordedStatement {
+                number: self.statements_executed,
+                method: self.call_stack.last().cloned().unwrap_or_default(),
+                text: format!("{statement:?}"),
+            });
+        }
+    }
+
+    /// Note that the innermost statement has finished, if recording.
+    pub(super) fn record_statement_end(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.current.pop();
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::interpreter::{Interpreter, Pointer, Word};
+use crate::test_util::parse_program;
+
+use super::{Change, Recording, Replay};
+
+const PROGRAM: &str = "
+    class Point {
+        x: Int;
+        y: Int;
+    }
+
+    class Main {
+        fn main(given self) -> Int {
+            let p = new Point(22, 44);
+            p.x = 1;
+            p.x.give;
+        }
+    }
+";
+
+/// Run `program` while recording, returning the interpreter and the recording.
+fn record(program: &str) -> (Interpreter, anyhow::Result<()>, Recording) {
+    let mut interpreter = Interpreter::new(parse_program(&[program]));
+    interpreter.start_recording();
+    let result = interpreter.interpret().map(|_| ());
+    let recording = interpreter.take_recording().unwrap();
+    (interpreter, result, recording)
+}
+
+/// Where the variable `var` was bound (the last time).
+fn binding(recording: &Recording, var: &str) -> Pointer {
+    recording
+        .steps
+        .iter()
+        .rev()
+        .find_map(|step| match &step.change {
+            Change::Bind { var: v, pointer } if v == var => Some(*pointer),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("`{var}` was never bound"))
+}
+
+#[test]
+fn replay_ends_with_the_final_heap() {
+    let (interpreter, result, recording) = record(PROGRAM);
+    result.unwrap();
+    let replay = Replay::new(&recording);
+    assert_eq!(replay.position(), recording.steps.len());
+    let heap: Vec<Vec<Word>> = interpreter
+        .allocs
+        .iter()
+        .map(|alloc| alloc.data.clone())
+        .collect();
+    assert_eq!(replay.heap(), &heap[..]);
+    assert!(replay.frames().is_empty(), "{:?}", replay.frames());
+}
+
+#[test]
+fn last_write_names_the_statement() {
+    let (_, result, recording) = record(PROGRAM);
+    result.unwrap();
+    let x = binding(&recording, "p");
+
+    // Walk back through the writes to `p.x` until we find the one that stored 1.
+    let mut before = recording.steps.len();
+    let index = loop {
+        let index = recording.last_write(x, before).expect("`p.x` was written");
+        if let Change::Write {
+            new: Word::Int(1), ..
+        } = recording.steps[index].change
+        {
+            break index;
+        }
+        before = index;
+    };
+    let statement = recording.statement(index).expect("written by a statement");
+    assert_eq!(statement.method, "Main.main");
+    assert!(statement.text.contains("p . x = 1"), "{statement}");
+}
+
+#[test]
+fn seek_restores_earlier_state() {
+    let (_, result, recording) = record(PROGRAM);
+    result.unwrap();
+    let x = binding(&recording, "p");
+    let index = recording
+        .steps
+        .iter()
+        .position(|step| {
+            matches!(step.change, Change::Write { pointer, new: Word::Int(1), .. } if pointer == x)
+        })
+        .unwrap();
+
+    let mut replay = Replay::new(&recording);
+    replay.seek(index);
+    assert_ne!(replay.word(x), Some(Word::Int(1)));
+    let frame = replay.frames().last().expect("inside `main`");
+    assert_eq!(frame.name, "Main.main");
+    let variables: Vec<&str> = frame
+        .variables
+        .iter()
+        .map(|(var, _)| var.as_str())
+        .collect();
+    assert_eq!(variables, ["self", "p"]);
+
+    replay.step_forward();
+    assert_eq!(replay.word(x), Some(Word::Int(1)));
+
+    replay.seek(0);
+    assert!(replay.heap().is_empty());
+    assert!(replay.frames().is_empty());
+}
+
+#[test]
+fn fault_keeps_the_frame_it_happened_in() {
+    let (_, result, recording) = record(
+        "
+        class Data { x: Int; }
+        class Main {
+            fn main(given self) -> Data {
+                let d = new Data(42);
+                let a = d.give;
+                d.give;
+            }
+        }
+    ",
+    );
+    assert!(result.is_err());
+    let replay = Replay::new(&recording);
+    let frame = replay.frames().last().expect("the frame of `main`");
+    assert_eq!(frame.name, "Main.main");
+
+    // `d` was uninitialized by `let a = d.give`.
+    let d = binding(&recording, "d");
+    assert_eq!(replay.word(d), Some(Word::Uninitialized));
+    let index = recording.last_write(d, recording.steps.len()).unwrap();
+    let statement = recording.statement(index).unwrap();
+    assert!(
+        statement.text.contains("let a = d . give"),
+        "{statement}"
+    );
+}
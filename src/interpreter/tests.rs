@@ -4,10 +4,12 @@ mod block_scoped_drops;
 mod copy_move;
 mod drop_body;
 mod generics;
+mod limits;
 mod mdbook;
 mod method_calls;
 mod normalization;
 mod place_ops;
 mod share;
 mod size_of;
+mod unwind;
 mod vector;
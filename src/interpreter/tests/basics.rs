@@ -57,9 +57,9 @@ fn give_and_return() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_p = new Point (22, 44) ;
-            Output: Trace:   _1_p = Point { x: 22, y: 44 }
-            Output: Trace:   _1_p . give ;
+            Output: Trace:   let p = new Point (22, 44) ;
+            Output: Trace:   p = Point { x: 22, y: 44 }
+            Output: Trace:   p . give ;
             Output: Trace: exit Main.main => Point { x: 22, y: 44 }
             Result: Ok: Point { x: 22, y: 44 }
             Alloc 0x06: [Int(22), Int(44)]"#]])
@@ -80,11 +80,11 @@ fn arithmetic() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_x = 10 ;
-            Output: Trace:   _1_x = 10
-            Output: Trace:   let _1_y = 20 ;
-            Output: Trace:   _1_y = 20
-            Output: Trace:   _1_x . give + _1_y . give ;
+            Output: Trace:   let x = 10 ;
+            Output: Trace:   x = 10
+            Output: Trace:   let y = 20 ;
+            Output: Trace:   y = 20
+            Output: Trace:   x . give + y . give ;
             Output: Trace: exit Main.main => 30
             Result: Ok: 30
             Alloc 0x08: [Int(30)]"#]])
@@ -113,11 +113,11 @@ fn method_call() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_adder = new Adder (3, 4) ;
-            Output: Trace:   _1_adder = Adder { a: 3, b: 4 }
-            Output: Trace:   _1_adder . give . sum () ;
+            Output: Trace:   let adder = new Adder (3, 4) ;
+            Output: Trace:   adder = Adder { a: 3, b: 4 }
+            Output: Trace:   adder . give . sum () ;
             Output: Trace:   enter Adder.sum
-            Output: Trace:     _2_self . a . give + _2_self . b . give ;
+            Output: Trace:     self . a . give + self . b . give ;
             Output: Trace:   exit Adder.sum => 7
             Output: Trace: exit Main.main => 7
             Result: Ok: 7
@@ -148,11 +148,11 @@ fn ref_creates_copy() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_p = new Pair (new Data (), new Data ()) ;
-            Output: Trace:   _1_p = Pair { a: Data {  }, b: Data {  } }
-            Output: Trace:   let _1_r = _1_p . ref ;
-            Output: Trace:   _1_r = ref [_1_p] Pair { a: Data {  }, b: Data {  } }
-            Output: Trace:   _1_p . a . give ;
+            Output: Trace:   let p = new Pair (new Data (), new Data ()) ;
+            Output: Trace:   p = Pair { a: Data {  }, b: Data {  } }
+            Output: Trace:   let r = p . ref ;
+            Output: Trace:   r = ref [p] Pair { a: Data {  }, b: Data {  } }
+            Output: Trace:   p . a . give ;
             Output: Trace: exit Main.main => Data {  }
             Result: Ok: Data {  }"#]])
     );
@@ -172,12 +172,12 @@ fn if_then_else() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_result = 0 ;
-            Output: Trace:   _1_result = 0
-            Output: Trace:   if true { _1_result = 42 ; } else { _1_result = 0 ; } ;
-            Output: Trace:   _1_result = 42 ;
-            Output: Trace:   _1_result = 42
-            Output: Trace:   _1_result . give ;
+            Output: Trace:   let result = 0 ;
+            Output: Trace:   result = 0
+            Output: Trace:   if true { result = 42 ; } else { result = 0 ; } ;
+            Output: Trace:   result = 42 ;
+            Output: Trace:   result = 42
+            Output: Trace:   result . give ;
             Output: Trace: exit Main.main => 42
             Result: Ok: 42
             Alloc 0x08: [Int(42)]"#]])
@@ -198,12 +198,12 @@ fn if_false_branch() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_result = 0 ;
-            Output: Trace:   _1_result = 0
-            Output: Trace:   if false { _1_result = 42 ; } else { _1_result = 99 ; } ;
-            Output: Trace:   _1_result = 99 ;
-            Output: Trace:   _1_result = 99
-            Output: Trace:   _1_result . give ;
+            Output: Trace:   let result = 0 ;
+            Output: Trace:   result = 0
+            Output: Trace:   if false { result = 42 ; } else { result = 99 ; } ;
+            Output: Trace:   result = 99 ;
+            Output: Trace:   result = 99
+            Output: Trace:   result . give ;
             Output: Trace: exit Main.main => 99
             Result: Ok: 99
             Alloc 0x08: [Int(99)]"#]])
@@ -253,10 +253,10 @@ fn print_object() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_p = new Point (10, 20) ;
-            Output: Trace:   _1_p = Point { x: 10, y: 20 }
-            Output: Trace:   print(_1_p . ref) ;
-            Output: ----->   ref [_1_p] Point { x: 10, y: 20 }
+            Output: Trace:   let p = new Point (10, 20) ;
+            Output: Trace:   p = Point { x: 10, y: 20 }
+            Output: Trace:   print(p . ref) ;
+            Output: ----->   ref [p] Point { x: 10, y: 20 }
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
             Result: Ok: 0
@@ -290,16 +290,16 @@ fn loop_body_value_is_freed() {
                 }
             }
         },
-        type: error(expect_test::expect![[r#"src/type_system/statements.rs:57:1: no applicable rules for type_statement { statement: loop { if stop . give >= 1 { break ; } else { stop = 1 ; } ; new Point (1, 2) ; }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, stop: Int}, assumptions: {}, fresh: 0 }, live_after: LivePlaces { accessed: {}, traversed: {} } }"#]]), interpret: ok(expect_test::expect![[r#"
+        type: error(expect_test::expect![[r#"src/type_system/statements.rs:58:1: no applicable rules for type_statement { statement: loop { if stop . give >= 1 { break ; } else { stop = 1 ; } ; new Point (1, 2) ; }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, stop: Int}, assumptions: {}, fresh: 0 }, live_after: LivePlaces { accessed: {}, traversed: {} } }"#]]), interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_stop = 0 ;
-            Output: Trace:   _1_stop = 0
-            Output: Trace:   loop { if _1_stop . give >= 1 { break ; } else { _1_stop = 1 ; } ; new Point (1, 2) ; }
-            Output: Trace:   if _1_stop . give >= 1 { break ; } else { _1_stop = 1 ; } ;
-            Output: Trace:   _1_stop = 1 ;
-            Output: Trace:   _1_stop = 1
+            Output: Trace:   let stop = 0 ;
+            Output: Trace:   stop = 0
+            Output: Trace:   loop { if stop . give >= 1 { break ; } else { stop = 1 ; } ; new Point (1, 2) ; }
+            Output: Trace:   if stop . give >= 1 { break ; } else { stop = 1 ; } ;
+            Output: Trace:   stop = 1 ;
+            Output: Trace:   stop = 1
             Output: Trace:   new Point (1, 2) ;
-            Output: Trace:   if _1_stop . give >= 1 { break ; } else { _1_stop = 1 ; } ;
+            Output: Trace:   if stop . give >= 1 { break ; } else { stop = 1 ; } ;
             Output: Trace:   break ;
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
@@ -28,9 +28,9 @@ fn block_scoped_drop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   { let _1_d : given Data = new Data (42) ; () ; } ;
-            Output: Trace:   let _1_d : given Data = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
+            Output: Trace:   { let d : given Data = new Data (42) ; () ; } ;
+            Output: Trace:   let d : given Data = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace:   drop Data
             Output: Trace:     print(self . x . give) ;
@@ -68,13 +68,13 @@ fn block_scoped_drop_order() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   { let _1_a : given Data = new Data (1) ; let _1_b : given Data = new Data (2) ; let _1_c : given Data = new Data (3) ; () ; } ;
-            Output: Trace:   let _1_a : given Data = new Data (1) ;
-            Output: Trace:   _1_a = Data { x: 1 }
-            Output: Trace:   let _1_b : given Data = new Data (2) ;
-            Output: Trace:   _1_b = Data { x: 2 }
-            Output: Trace:   let _1_c : given Data = new Data (3) ;
-            Output: Trace:   _1_c = Data { x: 3 }
+            Output: Trace:   { let a : given Data = new Data (1) ; let b : given Data = new Data (2) ; let c : given Data = new Data (3) ; () ; } ;
+            Output: Trace:   let a : given Data = new Data (1) ;
+            Output: Trace:   a = Data { x: 1 }
+            Output: Trace:   let b : given Data = new Data (2) ;
+            Output: Trace:   b = Data { x: 2 }
+            Output: Trace:   let c : given Data = new Data (3) ;
+            Output: Trace:   c = Data { x: 3 }
             Output: Trace:   () ;
             Output: Trace:   drop Data
             Output: Trace:     print(self . x . give) ;
@@ -117,11 +117,11 @@ fn nested_blocks_drop_innermost_first() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_outer : given Data = new Data (1) ;
-            Output: Trace:   _1_outer = Data { x: 1 }
-            Output: Trace:   { let _1_inner : given Data = new Data (2) ; () ; } ;
-            Output: Trace:   let _1_inner : given Data = new Data (2) ;
-            Output: Trace:   _1_inner = Data { x: 2 }
+            Output: Trace:   let outer : given Data = new Data (1) ;
+            Output: Trace:   outer = Data { x: 1 }
+            Output: Trace:   { let inner : given Data = new Data (2) ; () ; } ;
+            Output: Trace:   let inner : given Data = new Data (2) ;
+            Output: Trace:   inner = Data { x: 2 }
             Output: Trace:   () ;
             Output: Trace:   drop Data
             Output: Trace:     print(self . x . give) ;
@@ -158,11 +158,11 @@ fn block_early_break_drops_locals() {
                 }
             }
         },
-        type: error(expect_test::expect![[r#"src/type_system/statements.rs:57:1: no applicable rules for type_statement { statement: loop { let d : given Data = new Data (42) ; break ; }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main}, assumptions: {}, fresh: 0 }, live_after: LivePlaces { accessed: {}, traversed: {} } }"#]]), interpret: ok(expect_test::expect![[r#"
+        type: error(expect_test::expect![[r#"src/type_system/statements.rs:58:1: no applicable rules for type_statement { statement: loop { let d : given Data = new Data (42) ; break ; }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main}, assumptions: {}, fresh: 0 }, live_after: LivePlaces { accessed: {}, traversed: {} } }"#]]), interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   loop { let _1_d : given Data = new Data (42) ; break ; }
-            Output: Trace:   let _1_d : given Data = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
+            Output: Trace:   loop { let d : given Data = new Data (42) ; break ; }
+            Output: Trace:   let d : given Data = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
             Output: Trace:   break ;
             Output: Trace:   drop Data
             Output: Trace:     print(self . x . give) ;
@@ -203,11 +203,11 @@ fn partial_move_in_block_skips_drop_body() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   { let _1_p : given Pair = new Pair (array_new [Int](1), array_new [Int](1)) ; let _1_moved_a = _1_p . a . give ; () ; } ;
-            Output: Trace:   let _1_p : given Pair = new Pair (array_new [Int](1), array_new [Int](1)) ;
-            Output: Trace:   _1_p = Pair { a: Array { flag: Given, rc: 1, ⚡ }, b: Array { flag: Given, rc: 1, ⚡ } }
-            Output: Trace:   let _1_moved_a = _1_p . a . give ;
-            Output: Trace:   _1_moved_a = Array { flag: Given, rc: 1, ⚡ }
+            Output: Trace:   { let p : given Pair = new Pair (array_new [Int](1), array_new [Int](1)) ; let moved_a = p . a . give ; () ; } ;
+            Output: Trace:   let p : given Pair = new Pair (array_new [Int](1), array_new [Int](1)) ;
+            Output: Trace:   p = Pair { a: Array { flag: Given, rc: 1, ⚡ }, b: Array { flag: Given, rc: 1, ⚡ } }
+            Output: Trace:   let moved_a = p . a . give ;
+            Output: Trace:   moved_a = Array { flag: Given, rc: 1, ⚡ }
             Output: Trace:   () ;
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
@@ -239,22 +239,22 @@ fn loop_break_drops_locals() {
                 }
             }
         },
-        type: error(expect_test::expect![[r#"src/type_system/statements.rs:57:1: no applicable rules for type_statement { statement: loop { let d : given Data = new Data (stop . give) ; if stop . give >= 1 { break ; } else { stop = 1 ; } ; }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, stop: Int}, assumptions: {}, fresh: 0 }, live_after: LivePlaces { accessed: {}, traversed: {} } }"#]]), interpret: ok(expect_test::expect![[r#"
+        type: error(expect_test::expect![[r#"src/type_system/statements.rs:58:1: no applicable rules for type_statement { statement: loop { let d : given Data = new Data (stop . give) ; if stop . give >= 1 { break ; } else { stop = 1 ; } ; }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, stop: Int}, assumptions: {}, fresh: 0 }, live_after: LivePlaces { accessed: {}, traversed: {} } }"#]]), interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_stop = 0 ;
-            Output: Trace:   _1_stop = 0
-            Output: Trace:   loop { let _1_d : given Data = new Data (_1_stop . give) ; if _1_stop . give >= 1 { break ; } else { _1_stop = 1 ; } ; }
-            Output: Trace:   let _1_d : given Data = new Data (_1_stop . give) ;
-            Output: Trace:   _1_d = Data { x: 0 }
-            Output: Trace:   if _1_stop . give >= 1 { break ; } else { _1_stop = 1 ; } ;
-            Output: Trace:   _1_stop = 1 ;
-            Output: Trace:   _1_stop = 1
+            Output: Trace:   let stop = 0 ;
+            Output: Trace:   stop = 0
+            Output: Trace:   loop { let d : given Data = new Data (stop . give) ; if stop . give >= 1 { break ; } else { stop = 1 ; } ; }
+            Output: Trace:   let d : given Data = new Data (stop . give) ;
+            Output: Trace:   d = Data { x: 0 }
+            Output: Trace:   if stop . give >= 1 { break ; } else { stop = 1 ; } ;
+            Output: Trace:   stop = 1 ;
+            Output: Trace:   stop = 1
             Output: Trace:   drop Data
             Output: Trace:     print(self . x . give) ;
             Output: ----->     0
-            Output: Trace:   let _1_d : given Data = new Data (_1_stop . give) ;
-            Output: Trace:   _1_d = Data { x: 1 }
-            Output: Trace:   if _1_stop . give >= 1 { break ; } else { _1_stop = 1 ; } ;
+            Output: Trace:   let d : given Data = new Data (stop . give) ;
+            Output: Trace:   d = Data { x: 1 }
+            Output: Trace:   if stop . give >= 1 { break ; } else { stop = 1 ; } ;
             Output: Trace:   break ;
             Output: Trace:   drop Data
             Output: Trace:     print(self . x . give) ;
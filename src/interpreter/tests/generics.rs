@@ -17,11 +17,11 @@ fn generic_struct_copy_param() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_b : Box[Int] = new Box [Int] (42) ;
-            Output: Trace:   _1_b = Box { value: 42 }
-            Output: Trace:   let _1_a = _1_b . give ;
-            Output: Trace:   _1_a = Box { value: 42 }
-            Output: Trace:   _1_b . give ;
+            Output: Trace:   let b : Box[Int] = new Box [Int] (42) ;
+            Output: Trace:   b = Box { value: 42 }
+            Output: Trace:   let a = b . give ;
+            Output: Trace:   a = Box { value: 42 }
+            Output: Trace:   b . give ;
             Output: Trace: exit Main.main => Box { value: 42 }
             Result: Ok: Box { value: 42 }
             Alloc 0x07: [Int(42)]"#]])
@@ -49,9 +49,9 @@ fn generic_struct_move_param() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_b : Box[Data] = new Box [Data] (new Data (1)) ;
-            Output: Trace:   _1_b = Box { value: Data { x: 1 } }
-            Output: Trace:   _1_b . give ;
+            Output: Trace:   let b : Box[Data] = new Box [Data] (new Data (1)) ;
+            Output: Trace:   b = Box { value: Data { x: 1 } }
+            Output: Trace:   b . give ;
             Output: Trace: exit Main.main => Box { value: Data { x: 1 } }
             Result: Ok: Box { value: Data { x: 1 } }
             Alloc 0x06: [Int(1)]"#]])
@@ -80,11 +80,11 @@ fn generic_method_dispatch() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_b : Box[Int] = new Box [Int] (42) ;
-            Output: Trace:   _1_b = Box { value: 42 }
-            Output: Trace:   _1_b . give . get () ;
+            Output: Trace:   let b : Box[Int] = new Box [Int] (42) ;
+            Output: Trace:   b = Box { value: 42 }
+            Output: Trace:   b . give . get () ;
             Output: Trace:   enter Box.get
-            Output: Trace:     _2_self . value . give ;
+            Output: Trace:     self . value . give ;
             Output: Trace:   exit Box.get => 42
             Output: Trace: exit Main.main => 42
             Result: Ok: 42
@@ -112,11 +112,11 @@ fn struct_pair_of_ints_is_copy() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_p : Pair[Int] = new Pair [Int] (1, 2) ;
-            Output: Trace:   _1_p = Pair { a: 1, b: 2 }
-            Output: Trace:   let _1_c = _1_p . give ;
-            Output: Trace:   _1_c = Pair { a: 1, b: 2 }
-            Output: Trace:   _1_p . give ;
+            Output: Trace:   let p : Pair[Int] = new Pair [Int] (1, 2) ;
+            Output: Trace:   p = Pair { a: 1, b: 2 }
+            Output: Trace:   let c = p . give ;
+            Output: Trace:   c = Pair { a: 1, b: 2 }
+            Output: Trace:   p . give ;
             Output: Trace: exit Main.main => Pair { a: 1, b: 2 }
             Result: Ok: Pair { a: 1, b: 2 }
             Alloc 0x08: [Int(1), Int(2)]"#]])
@@ -145,9 +145,9 @@ fn nested_struct_move_poisons() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_p : Pair[Data] = new Pair [Data] (new Data (1), new Data (2)) ;
-            Output: Trace:   _1_p = Pair { a: Data { x: 1 }, b: Data { x: 2 } }
-            Output: Trace:   _1_p . give ;
+            Output: Trace:   let p : Pair[Data] = new Pair [Data] (new Data (1), new Data (2)) ;
+            Output: Trace:   p = Pair { a: Data { x: 1 }, b: Data { x: 2 } }
+            Output: Trace:   p . give ;
             Output: Trace: exit Main.main => Pair { a: Data { x: 1 }, b: Data { x: 2 } }
             Result: Ok: Pair { a: Data { x: 1 }, b: Data { x: 2 } }
             Alloc 0x08: [Int(1), Int(2)]"#]])
@@ -175,9 +175,9 @@ fn struct_move_param_give_consumes() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_b : Box[Data] = new Box [Data] (new Data (99)) ;
-            Output: Trace:   _1_b = Box { value: Data { x: 99 } }
-            Output: Trace:   _1_b . give ;
+            Output: Trace:   let b : Box[Data] = new Box [Data] (new Data (99)) ;
+            Output: Trace:   b = Box { value: Data { x: 99 } }
+            Output: Trace:   b . give ;
             Output: Trace: exit Main.main => Box { value: Data { x: 99 } }
             Result: Ok: Box { value: Data { x: 99 } }
             Alloc 0x06: [Int(99)]"#]])
@@ -204,18 +204,18 @@ fn struct_move_param_give_twice_faults() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, b: Box[Data]}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, b: Box[Data]}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
                 live_after = LivePlaces { accessed: {b}, traversed: {} }
                 place = b"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_b : Box[Data] = new Box [Data] (new Data (99)) ;
-            Output: Trace:   _1_b = Box { value: Data { x: 99 } }
-            Output: Trace:   let _1_c = _1_b . give ;
-            Output: Trace:   _1_c = Box { value: Data { x: 99 } }
-            Output: Trace:   _1_b . give ;
+            Output: Trace:   let b : Box[Data] = new Box [Data] (new Data (99)) ;
+            Output: Trace:   b = Box { value: Data { x: 99 } }
+            Output: Trace:   let c = b . give ;
+            Output: Trace:   c = Box { value: Data { x: 99 } }
+            Output: Trace:   b . give ;
             Result: Fault: access of uninitialized value
             Alloc 0x06: [Int(99)]"#]])
     );
@@ -243,11 +243,11 @@ fn struct_move_param_ref_borrows() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_b : Box[Data] = new Box [Data] (new Data (42)) ;
-            Output: Trace:   _1_b = Box { value: Data { x: 42 } }
-            Output: Trace:   print(_1_b . ref) ;
-            Output: ----->   ref [_1_b] Box { value: Data { x: 42 } }
-            Output: Trace:   _1_b . give ;
+            Output: Trace:   let b : Box[Data] = new Box [Data] (new Data (42)) ;
+            Output: Trace:   b = Box { value: Data { x: 42 } }
+            Output: Trace:   print(b . ref) ;
+            Output: ----->   ref [b] Box { value: Data { x: 42 } }
+            Output: Trace:   b . give ;
             Output: Trace: exit Main.main => Box { value: Data { x: 42 } }
             Result: Ok: Box { value: Data { x: 42 } }
             Alloc 0x08: [Int(42)]"#]])
@@ -0,0 +1,117 @@
+// Tests for the fuel and call-depth limits, which turn a program that never
+// terminates into a fault.
+
+use crate::interpreter::{
+    with_stack, Interpreter, LimitExceeded, ObjectValue, DEFAULT_MAX_CALL_DEPTH,
+};
+use crate::test_util::parse_program;
+
+fn limit_exceeded(result: anyhow::Result<ObjectValue>) -> LimitExceeded {
+    let error = result.expect_err("program should have faulted");
+    error
+        .downcast_ref::<LimitExceeded>()
+        .copied()
+        .unwrap_or_else(|| panic!("expected a limit to be exceeded, got `{error}`"))
+}
+
+/// A loop that never breaks runs out of fuel.
+#[test]
+fn infinite_loop_runs_out_of_fuel() {
+    let program = parse_program(&["
+        class Main {
+            fn main(given self) -> Int {
+                loop {
+                    0;
+                }
+                0;
+            }
+        }
+    "]);
+    let mut interpreter = Interpreter::new(program);
+    interpreter.set_fuel(100);
+    let result = interpreter.interpret();
+    assert_eq!(limit_exceeded(result), LimitExceeded::Fuel(100));
+}
+
+/// An empty loop evaluates no statements, but each iteration still uses fuel.
+#[test]
+fn empty_loop_runs_out_of_fuel() {
+    let program = parse_program(&["
+        class Main {
+            fn main(given self) -> Int {
+                loop { }
+                0;
+            }
+        }
+    "]);
+    let mut interpreter = Interpreter::new(program);
+    interpreter.set_fuel(100);
+    let result = interpreter.interpret();
+    assert_eq!(limit_exceeded(result), LimitExceeded::Fuel(100));
+}
+
+/// A method that calls itself forever overflows the call stack.
+#[test]
+fn unbounded_recursion_overflows_the_stack() {
+    let program = parse_program(&["
+        class Main {
+            fn main(given self) -> Int {
+                self.give.main();
+            }
+        }
+    "]);
+    let mut interpreter = Interpreter::new(program);
+    interpreter.set_max_call_depth(8);
+    let result = interpreter.interpret();
+    assert_eq!(limit_exceeded(result), LimitExceeded::CallDepth(8));
+}
+
+/// A program that finishes within its limits is unaffected by them.
+#[test]
+fn limits_allow_terminating_programs() {
+    let program = parse_program(&["
+        class Main {
+            fn main(given self) -> Int {
+                22 + 44;
+            }
+        }
+    "]);
+    let mut interpreter = Interpreter::new(program);
+    interpreter.set_fuel(100);
+    interpreter.set_max_call_depth(8);
+    interpreter.interpret().unwrap();
+}
+
+/// Run a recursion `depth` calls deep below `main`, with the default call depth.
+fn count_down(depth: usize) -> anyhow::Result<ObjectValue> {
+    let text = format!(
+        "
+        class Main {{
+            fn main(given self) -> Int {{
+                self.give.count({depth});
+            }}
+
+            fn count(given self, n: Int) -> Int {{
+                if n.give >= 1 {{ self.give.count(n.give - 1); }} else {{ 0; }};
+            }}
+        }}
+    "
+    );
+    let program = parse_program(&[text.as_str()]);
+    with_stack(|| {
+        let mut interpreter = Interpreter::new(program);
+        interpreter.set_max_call_depth(DEFAULT_MAX_CALL_DEPTH);
+        interpreter.interpret()
+    })
+}
+
+/// `main` and the `depth + 1` calls of `count` nest `depth + 2` calls, so the
+/// recursion fits in the default call depth for every `depth` up to two less.
+#[test]
+fn recursion_up_to_the_default_call_depth() {
+    count_down(DEFAULT_MAX_CALL_DEPTH - 2).unwrap();
+    assert_eq!(
+        limit_exceeded(count_down(DEFAULT_MAX_CALL_DEPTH - 1)),
+        LimitExceeded::CallDepth(DEFAULT_MAX_CALL_DEPTH)
+    );
+}
@@ -21,9 +21,9 @@ fn interp_point_example() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_p = new Point (22, 44) ;
-            Output: Trace:   _1_p = Point { x: 22, y: 44 }
-            Output: Trace:   _1_p . give ;
+            Output: Trace:   let p = new Point (22, 44) ;
+            Output: Trace:   p = Point { x: 22, y: 44 }
+            Output: Trace:   p . give ;
             Output: Trace: exit Main.main => Point { x: 22, y: 44 }
             Result: Ok: Point { x: 22, y: 44 }
             Alloc 0x06: [Int(22), Int(44)]"#]])
@@ -46,11 +46,11 @@ fn interp_arithmetic() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_x = 10 ;
-            Output: Trace:   _1_x = 10
-            Output: Trace:   let _1_y = 20 ;
-            Output: Trace:   _1_y = 20
-            Output: Trace:   _1_x . give + _1_y . give ;
+            Output: Trace:   let x = 10 ;
+            Output: Trace:   x = 10
+            Output: Trace:   let y = 20 ;
+            Output: Trace:   y = 20
+            Output: Trace:   x . give + y . give ;
             Output: Trace: exit Main.main => 30
             Result: Ok: 30
             Alloc 0x08: [Int(30)]"#]])
@@ -81,11 +81,11 @@ fn interp_method_calls() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_adder = new Adder (3, 4) ;
-            Output: Trace:   _1_adder = Adder { a: 3, b: 4 }
-            Output: Trace:   _1_adder . give . sum () ;
+            Output: Trace:   let adder = new Adder (3, 4) ;
+            Output: Trace:   adder = Adder { a: 3, b: 4 }
+            Output: Trace:   adder . give . sum () ;
             Output: Trace:   enter Adder.sum
-            Output: Trace:     _2_self . a . give + _2_self . b . give ;
+            Output: Trace:     self . a . give + self . b . give ;
             Output: Trace:   exit Adder.sum => 7
             Output: Trace: exit Main.main => 7
             Result: Ok: 7
@@ -109,9 +109,9 @@ fn interp_give_given() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 42 }
             Result: Ok: Data { x: 42 }
             Alloc 0x05: [Int(42)]"#]])
@@ -138,17 +138,17 @@ fn interp_give_shared() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   let _1_x1 = _1_s . give ;
-            Output: Trace:   _1_x1 = shared Data { x: 42 }
-            Output: Trace:   let _1_x2 = _1_s . give ;
-            Output: Trace:   _1_x2 = shared Data { x: 42 }
-            Output: Trace:   print(_1_x1 . give) ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   let x1 = s . give ;
+            Output: Trace:   x1 = shared Data { x: 42 }
+            Output: Trace:   let x2 = s . give ;
+            Output: Trace:   x2 = shared Data { x: 42 }
+            Output: Trace:   print(x1 . give) ;
             Output: ----->   shared Data { x: 42 }
-            Output: Trace:   _1_x2 . give ;
+            Output: Trace:   x2 . give ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x0d: [Int(42)]"#]])
@@ -172,11 +172,11 @@ fn interp_ref_given() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   print(_1_d . ref) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   print(d . ref) ;
+            Output: ----->   ref [d] Data { x: 42 }
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 42 }
             Result: Ok: Data { x: 42 }
             Alloc 0x07: [Int(42)]"#]])
@@ -200,11 +200,11 @@ fn interp_ref_shared() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   _1_s . ref ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   s . ref ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x07: [Int(42)]"#]])
@@ -228,9 +228,9 @@ fn interp_share_recursive() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_o . give . share ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   o . give . share ;
             Output: Trace: exit Main.main => shared Outer { inner: Inner { x: 1 } }
             Result: Ok: shared Outer { inner: Inner { x: 1 } }
             Alloc 0x06: [Int(1)]"#]])
@@ -256,13 +256,13 @@ fn interp_drop_borrowed_noop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_r = _1_d . ref ;
-            Output: Trace:   _1_r = ref [_1_d] Data { x: 42 }
-            Output: Trace:   _1_r . drop ;
-            Output: Trace:   print(_1_r . give) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let r = d . ref ;
+            Output: Trace:   r = ref [d] Data { x: 42 }
+            Output: Trace:   r . drop ;
+            Output: Trace:   print(r . give) ;
+            Output: ----->   ref [d] Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -285,12 +285,12 @@ fn interp_conditional_true() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_result = 0 ;
-            Output: Trace:   _1_result = 0
-            Output: Trace:   if true { _1_result = 42 ; } else { _1_result = 0 ; } ;
-            Output: Trace:   _1_result = 42 ;
-            Output: Trace:   _1_result = 42
-            Output: Trace:   _1_result . give ;
+            Output: Trace:   let result = 0 ;
+            Output: Trace:   result = 0
+            Output: Trace:   if true { result = 42 ; } else { result = 0 ; } ;
+            Output: Trace:   result = 42 ;
+            Output: Trace:   result = 42
+            Output: Trace:   result . give ;
             Output: Trace: exit Main.main => 42
             Result: Ok: 42
             Alloc 0x08: [Int(42)]"#]])
@@ -313,12 +313,12 @@ fn interp_conditional_false() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_result = 0 ;
-            Output: Trace:   _1_result = 0
-            Output: Trace:   if false { _1_result = 42 ; } else { _1_result = 99 ; } ;
-            Output: Trace:   _1_result = 99 ;
-            Output: Trace:   _1_result = 99
-            Output: Trace:   _1_result . give ;
+            Output: Trace:   let result = 0 ;
+            Output: Trace:   result = 0
+            Output: Trace:   if false { result = 42 ; } else { result = 99 ; } ;
+            Output: Trace:   result = 99 ;
+            Output: Trace:   result = 99
+            Output: Trace:   result . give ;
             Output: Trace: exit Main.main => 99
             Result: Ok: 99
             Alloc 0x08: [Int(99)]"#]])
@@ -349,16 +349,16 @@ fn interp_array_new_and_get() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Int](3) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, ⚡, ⚡, ⚡ }
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 0 , 10) ;
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 1 , 20) ;
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 2 , 30) ;
-            Output: Trace:   print(array_give [Int, given, ref [_1_a]](_1_a . ref , 0)) ;
+            Output: Trace:   let a = array_new [Int](3) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, ⚡, ⚡, ⚡ }
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 0 , 10) ;
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 1 , 20) ;
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 2 , 30) ;
+            Output: Trace:   print(array_give [Int, given, ref [a]](a . ref , 0)) ;
             Output: ----->   10
-            Output: Trace:   print(array_give [Int, given, ref [_1_a]](_1_a . ref , 1)) ;
+            Output: Trace:   print(array_give [Int, given, ref [a]](a . ref , 1)) ;
             Output: ----->   20
-            Output: Trace:   array_give [Int, given, given](_1_a . give , 2) ;
+            Output: Trace:   array_give [Int, given, given](a . give , 2) ;
             Output: Trace: exit Main.main => 30
             Result: Ok: 30
             Alloc 0x1c: [Int(30)]"#]])
@@ -384,13 +384,13 @@ fn interp_array_class_elements() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Data](2) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, Data { x: ⚡ }, Data { x: ⚡ } }
-            Output: Trace:   array_write [Data, mut [_1_a]](_1_a . mut , 0 , new Data (42)) ;
-            Output: Trace:   array_write [Data, mut [_1_a]](_1_a . mut , 1 , new Data (99)) ;
-            Output: Trace:   print(array_give [Data, given, ref [_1_a]](_1_a . ref , 0)) ;
+            Output: Trace:   let a = array_new [Data](2) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, Data { x: ⚡ }, Data { x: ⚡ } }
+            Output: Trace:   array_write [Data, mut [a]](a . mut , 0 , new Data (42)) ;
+            Output: Trace:   array_write [Data, mut [a]](a . mut , 1 , new Data (99)) ;
+            Output: Trace:   print(array_give [Data, given, ref [a]](a . ref , 0)) ;
             Output: ----->   Data { x: 42 }
-            Output: Trace:   array_give [Data, given, given](_1_a . give , 1) ;
+            Output: Trace:   array_give [Data, given, given](a . give , 1) ;
             Output: Trace: exit Main.main => Data { x: 99 }
             Result: Ok: Data { x: 99 }
             Alloc 0x16: [Int(99)]"#]])
@@ -416,16 +416,16 @@ fn interp_array_int_is_copy() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Int](1) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, ⚡ }
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 0 , 42) ;
-            Output: Trace:   let _1_x = array_give [Int, given, ref [_1_a]](_1_a . ref , 0) ;
-            Output: Trace:   _1_x = 42
-            Output: Trace:   let _1_y = array_give [Int, given, ref [_1_a]](_1_a . ref , 0) ;
-            Output: Trace:   _1_y = 42
-            Output: Trace:   print(_1_x . give) ;
+            Output: Trace:   let a = array_new [Int](1) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, ⚡ }
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 0 , 42) ;
+            Output: Trace:   let x = array_give [Int, given, ref [a]](a . ref , 0) ;
+            Output: Trace:   x = 42
+            Output: Trace:   let y = array_give [Int, given, ref [a]](a . ref , 0) ;
+            Output: Trace:   y = 42
+            Output: Trace:   print(x . give) ;
             Output: ----->   42
-            Output: Trace:   _1_y . give ;
+            Output: Trace:   y . give ;
             Output: Trace: exit Main.main => 42
             Result: Ok: 42
             Alloc 0x14: [Int(42)]"#]])
@@ -455,16 +455,16 @@ fn interp_array_class_shared_no_move() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Data](1) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, Data { x: ⚡ } }
-            Output: Trace:   array_write [Data, mut [_1_a]](_1_a . mut , 0 , new Data (42)) ;
-            Output: Trace:   let _1_s = _1_a . give . share ;
-            Output: Trace:   _1_s = shared Array { flag: Shared, rc: 1, Data { x: 42 } }
-            Output: Trace:   let _1_x = array_give [Data, shared, ref [_1_s]](_1_s . ref , 0) ;
-            Output: Trace:   _1_x = shared Data { x: 42 }
-            Output: Trace:   print(_1_x . give) ;
+            Output: Trace:   let a = array_new [Data](1) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, Data { x: ⚡ } }
+            Output: Trace:   array_write [Data, mut [a]](a . mut , 0 , new Data (42)) ;
+            Output: Trace:   let s = a . give . share ;
+            Output: Trace:   s = shared Array { flag: Shared, rc: 1, Data { x: 42 } }
+            Output: Trace:   let x = array_give [Data, shared, ref [s]](s . ref , 0) ;
+            Output: Trace:   x = shared Data { x: 42 }
+            Output: Trace:   print(x . give) ;
             Output: ----->   shared Data { x: 42 }
-            Output: Trace:   array_give [Data, shared, shared](_1_s . give , 0) ;
+            Output: Trace:   array_give [Data, shared, shared](s . give , 0) ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x15: [Int(42)]"#]])
@@ -492,18 +492,18 @@ fn interp_array_shared_refcount() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Int](2) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, ⚡, ⚡ }
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 0 , 10) ;
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 1 , 20) ;
-            Output: Trace:   let _1_s = _1_a . give . share ;
-            Output: Trace:   _1_s = shared Array { flag: Shared, rc: 1, 10, 20 }
-            Output: Trace:   let _1_b = _1_s . give ;
-            Output: Trace:   _1_b = shared Array { flag: Shared, rc: 2, 10, 20 }
-            Output: Trace:   _1_s . drop ;
-            Output: Trace:   print(array_give [Int, given, ref [_1_b]](_1_b . ref , 0)) ;
+            Output: Trace:   let a = array_new [Int](2) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, ⚡, ⚡ }
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 0 , 10) ;
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 1 , 20) ;
+            Output: Trace:   let s = a . give . share ;
+            Output: Trace:   s = shared Array { flag: Shared, rc: 1, 10, 20 }
+            Output: Trace:   let b = s . give ;
+            Output: Trace:   b = shared Array { flag: Shared, rc: 2, 10, 20 }
+            Output: Trace:   s . drop ;
+            Output: Trace:   print(array_give [Int, given, ref [b]](b . ref , 0)) ;
             Output: ----->   10
-            Output: Trace:   array_give [Int, given, shared](_1_b . give , 1) ;
+            Output: Trace:   array_give [Int, given, shared](b . give , 1) ;
             Output: Trace: exit Main.main => 20
             Result: Ok: 20
             Alloc 0x19: [Int(20)]"#]])
@@ -528,13 +528,13 @@ fn interp_array_given_move() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Int](2) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, ⚡, ⚡ }
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 0 , 10) ;
-            Output: Trace:   array_write [Int, mut [_1_a]](_1_a . mut , 1 , 20) ;
-            Output: Trace:   let _1_b = _1_a . give ;
-            Output: Trace:   _1_b = Array { flag: Given, rc: 1, 10, 20 }
-            Output: Trace:   array_give [Int, given, given](_1_b . give , 0) ;
+            Output: Trace:   let a = array_new [Int](2) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, ⚡, ⚡ }
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 0 , 10) ;
+            Output: Trace:   array_write [Int, mut [a]](a . mut , 1 , 20) ;
+            Output: Trace:   let b = a . give ;
+            Output: Trace:   b = Array { flag: Given, rc: 1, 10, 20 }
+            Output: Trace:   array_give [Int, given, given](b . give , 0) ;
             Output: Trace: exit Main.main => 10
             Result: Ok: 10
             Alloc 0x12: [Int(10)]"#]])
@@ -560,11 +560,11 @@ fn interp_array_drop_frees() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Data](2) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, Data { x: ⚡ }, Data { x: ⚡ } }
-            Output: Trace:   array_write [Data, mut [_1_a]](_1_a . mut , 0 , new Data (1)) ;
-            Output: Trace:   array_write [Data, mut [_1_a]](_1_a . mut , 1 , new Data (2)) ;
-            Output: Trace:   _1_a . drop ;
+            Output: Trace:   let a = array_new [Data](2) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, Data { x: ⚡ }, Data { x: ⚡ } }
+            Output: Trace:   array_write [Data, mut [a]](a . mut , 0 , new Data (1)) ;
+            Output: Trace:   array_write [Data, mut [a]](a . mut , 1 , new Data (2)) ;
+            Output: Trace:   a . drop ;
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
             Result: Ok: 0
@@ -17,8 +17,8 @@
 // - `normalize_ty_for_pop` is called on result types in the interpreter
 // - All #[ignore]'d tests are un-ignored and pass
 // - Trace output for result types will show normalized permissions
-//   (e.g., `ref[_1_d] Data` instead of `ref[_2_x] ref[_1_d] Data`,
-//    `or(mut[d1], mut[d2]) Data` instead of `mut[_2_x] mut[d1] Data`)
+//   (e.g., `ref[d] Data` instead of `ref[x] ref[d] Data`,
+//    `or(mut[d1], mut[d2]) Data` instead of `mut[x] mut[d1] Data`)
 
 // ---------------------------------------------------------------------------
 // given[self] resolution: basic ownership transfer
@@ -46,9 +46,9 @@ fn interp_given_self_basic() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_c = new Container () ;
-            Output: Trace:   _1_c = Container {  }
-            Output: Trace:   _1_c . give . get () ;
+            Output: Trace:   let c = new Container () ;
+            Output: Trace:   c = Container {  }
+            Output: Trace:   c . give . get () ;
             Output: Trace:   enter Container.get
             Output: Trace:     new Data (42) ;
             Output: Trace:   exit Container.get => Data { x: 42 }
@@ -92,18 +92,18 @@ fn interp_given_self_give_to_consumer() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_c = new Container () ;
-            Output: Trace:   _1_c = Container {  }
-            Output: Trace:   let _1_result = _1_c . give . get () ;
+            Output: Trace:   let c = new Container () ;
+            Output: Trace:   c = Container {  }
+            Output: Trace:   let result = c . give . get () ;
             Output: Trace:   enter Container.get
             Output: Trace:     new Data (99) ;
             Output: Trace:   exit Container.get => Data { x: 99 }
-            Output: Trace:   _1_result = Data { x: 99 }
-            Output: Trace:   let _1_sink = new Sink () ;
-            Output: Trace:   _1_sink = Sink {  }
-            Output: Trace:   _1_sink . give . consume (_1_result . give) ;
+            Output: Trace:   result = Data { x: 99 }
+            Output: Trace:   let sink = new Sink () ;
+            Output: Trace:   sink = Sink {  }
+            Output: Trace:   sink . give . consume (result . give) ;
             Output: Trace:   enter Sink.consume
-            Output: Trace:     _3_d . x . give ;
+            Output: Trace:     d . x . give ;
             Output: Trace:   exit Sink.consume => 99
             Output: Trace: exit Main.main => 99
             Result: Ok: 99
@@ -138,14 +138,14 @@ fn interp_ref_self_field_preservation() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_c = new Container (new Data (77)) ;
-            Output: Trace:   _1_c = Container { d: Data { x: 77 } }
-            Output: Trace:   let _1_result = _1_c . ref . get_ref [ref [_1_c]] () ;
+            Output: Trace:   let c = new Container (new Data (77)) ;
+            Output: Trace:   c = Container { d: Data { x: 77 } }
+            Output: Trace:   let result = c . ref . get_ref [ref [c]] () ;
             Output: Trace:   enter Container.get_ref
-            Output: Trace:     _2_self . d . ref ;
-            Output: Trace:   exit Container.get_ref => ref [_1_c] Data { x: 77 }
-            Output: Trace:   _1_result = ref [_1_c] Data { x: 77 }
-            Output: Trace:   _1_result . x . give ;
+            Output: Trace:     self . d . ref ;
+            Output: Trace:   exit Container.get_ref => ref [c] Data { x: 77 }
+            Output: Trace:   result = ref [c] Data { x: 77 }
+            Output: Trace:   result . x . give ;
             Output: Trace: exit Main.main => 77
             Result: Ok: 77
             Alloc 0x0a: [Int(77)]"#]])
@@ -178,13 +178,13 @@ fn interp_given_named_param() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (7) ;
-            Output: Trace:   _1_d = Data { x: 7 }
-            Output: Trace:   let _1_f = new Funcs () ;
-            Output: Trace:   _1_f = Funcs {  }
-            Output: Trace:   _1_f . give . take (_1_d . give) ;
+            Output: Trace:   let d = new Data (7) ;
+            Output: Trace:   d = Data { x: 7 }
+            Output: Trace:   let f = new Funcs () ;
+            Output: Trace:   f = Funcs {  }
+            Output: Trace:   f . give . take (d . give) ;
             Output: Trace:   enter Funcs.take
-            Output: Trace:     _2_x . give ;
+            Output: Trace:     x . give ;
             Output: Trace:   exit Funcs.take => Data { x: 7 }
             Output: Trace: exit Main.main => Data { x: 7 }
             Result: Ok: Data { x: 7 }
@@ -220,20 +220,20 @@ fn interp_given_named_param_give_result() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (55) ;
-            Output: Trace:   _1_d = Data { x: 55 }
-            Output: Trace:   let _1_f = new Funcs () ;
-            Output: Trace:   _1_f = Funcs {  }
-            Output: Trace:   let _1_result = _1_f . give . take (_1_d . give) ;
+            Output: Trace:   let d = new Data (55) ;
+            Output: Trace:   d = Data { x: 55 }
+            Output: Trace:   let f = new Funcs () ;
+            Output: Trace:   f = Funcs {  }
+            Output: Trace:   let result = f . give . take (d . give) ;
             Output: Trace:   enter Funcs.take
-            Output: Trace:     _2_x . give ;
+            Output: Trace:     x . give ;
             Output: Trace:   exit Funcs.take => Data { x: 55 }
-            Output: Trace:   _1_result = Data { x: 55 }
-            Output: Trace:   let _1_sink = new Sink () ;
-            Output: Trace:   _1_sink = Sink {  }
-            Output: Trace:   _1_sink . give . consume (_1_result . give) ;
+            Output: Trace:   result = Data { x: 55 }
+            Output: Trace:   let sink = new Sink () ;
+            Output: Trace:   sink = Sink {  }
+            Output: Trace:   sink . give . consume (result . give) ;
             Output: Trace:   enter Sink.consume
-            Output: Trace:     _3_d . x . give ;
+            Output: Trace:     d . x . give ;
             Output: Trace:   exit Sink.consume => 55
             Output: Trace: exit Main.main => 55
             Result: Ok: 55
@@ -248,9 +248,9 @@ fn interp_given_named_param_give_result() {
 /// Method returns ref[x] where x is a ref parameter → borrow chains through.
 /// The result should be readable in the caller's scope.
 ///
-/// Currently hits preservation violation: result type `ref[_2_x] ref[_1_d] Data`
-/// references method-scoped `_2_x`. After Phase 3c normalization, this resolves
-/// to `ref[_1_d] Data` (copy tail drops the dead link).
+/// Currently hits preservation violation: result type `ref[x] ref[d] Data`
+/// references method-scoped `x`. After Phase 3c normalization, this resolves
+/// to `ref[d] Data` (copy tail drops the dead link).
 #[test]
 fn interp_borrow_chain_ref_through_ref() {
     crate::assert_interpret!(
@@ -274,16 +274,16 @@ fn interp_borrow_chain_ref_through_ref() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (33) ;
-            Output: Trace:   _1_d = Data { x: 33 }
-            Output: Trace:   let _1_f = new Funcs () ;
-            Output: Trace:   _1_f = Funcs {  }
-            Output: Trace:   let _1_result = _1_f . give . borrow [ref [_1_d]] (_1_d . ref) ;
+            Output: Trace:   let d = new Data (33) ;
+            Output: Trace:   d = Data { x: 33 }
+            Output: Trace:   let f = new Funcs () ;
+            Output: Trace:   f = Funcs {  }
+            Output: Trace:   let result = f . give . borrow [ref [d]] (d . ref) ;
             Output: Trace:   enter Funcs.borrow
-            Output: Trace:     _2_x . ref ;
-            Output: Trace:   exit Funcs.borrow => ref [_1_d] Data { x: 33 }
-            Output: Trace:   _1_result = ref [_1_d] Data { x: 33 }
-            Output: Trace:   _1_result . x . give ;
+            Output: Trace:     x . ref ;
+            Output: Trace:   exit Funcs.borrow => ref [d] Data { x: 33 }
+            Output: Trace:   result = ref [d] Data { x: 33 }
+            Output: Trace:   result . x . give ;
             Output: Trace: exit Main.main => 33
             Result: Ok: 33
             Alloc 0x0c: [Int(33)]"#]])
@@ -293,7 +293,7 @@ fn interp_borrow_chain_ref_through_ref() {
 /// ref[self] where self is ref → borrow chains through, field readable.
 ///
 /// Currently hits preservation violation: result type references method-scoped
-/// `_2_self`. After Phase 3c normalization, resolves to `ref[_1_c] Data`.
+/// `self`. After Phase 3c normalization, resolves to `ref[c] Data`.
 #[test]
 fn interp_borrow_chain_ref_through_ref_self() {
     crate::assert_interpret!(
@@ -317,14 +317,14 @@ fn interp_borrow_chain_ref_through_ref_self() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_c = new Container (new Data (44)) ;
-            Output: Trace:   _1_c = Container { d: Data { x: 44 } }
-            Output: Trace:   let _1_result = _1_c . ref . get [ref [_1_c]] () ;
+            Output: Trace:   let c = new Container (new Data (44)) ;
+            Output: Trace:   c = Container { d: Data { x: 44 } }
+            Output: Trace:   let result = c . ref . get [ref [c]] () ;
             Output: Trace:   enter Container.get
-            Output: Trace:     _2_self . d . ref ;
-            Output: Trace:   exit Container.get => ref [_1_c] Data { x: 44 }
-            Output: Trace:   _1_result = ref [_1_c] Data { x: 44 }
-            Output: Trace:   _1_result . x . give ;
+            Output: Trace:     self . d . ref ;
+            Output: Trace:   exit Container.get => ref [c] Data { x: 44 }
+            Output: Trace:   result = ref [c] Data { x: 44 }
+            Output: Trace:   result . x . give ;
             Output: Trace: exit Main.main => 44
             Result: Ok: 44
             Alloc 0x0a: [Int(44)]"#]])
@@ -339,7 +339,7 @@ fn interp_borrow_chain_ref_through_ref_self() {
 /// Reading a field from the result should work.
 ///
 /// Currently hits preservation violation: result type references method-scoped
-/// `_2_x`. After Phase 3c normalization, resolves to `or(ref[_1_d1], ref[_1_d2]) Data`.
+/// `x`. After Phase 3c normalization, resolves to `or(ref[d1], ref[d2]) Data`.
 #[test]
 fn interp_multi_place_ref_produces_or() {
     crate::assert_interpret!(
@@ -364,18 +364,18 @@ fn interp_multi_place_ref_produces_or() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d1 = new Data (10) ;
-            Output: Trace:   _1_d1 = Data { x: 10 }
-            Output: Trace:   let _1_d2 = new Data (20) ;
-            Output: Trace:   _1_d2 = Data { x: 20 }
-            Output: Trace:   let _1_f = new Funcs () ;
-            Output: Trace:   _1_f = Funcs {  }
-            Output: Trace:   let _1_result = _1_f . give . either [ref [_1_d1], ref [_1_d2]] (_1_d1 . ref, _1_d2 . ref) ;
+            Output: Trace:   let d1 = new Data (10) ;
+            Output: Trace:   d1 = Data { x: 10 }
+            Output: Trace:   let d2 = new Data (20) ;
+            Output: Trace:   d2 = Data { x: 20 }
+            Output: Trace:   let f = new Funcs () ;
+            Output: Trace:   f = Funcs {  }
+            Output: Trace:   let result = f . give . either [ref [d1], ref [d2]] (d1 . ref, d2 . ref) ;
             Output: Trace:   enter Funcs.either
-            Output: Trace:     _2_x . ref ;
-            Output: Trace:   exit Funcs.either => ref [_1_d1] Data { x: 10 }
-            Output: Trace:   _1_result = ref [_1_d1] Data { x: 10 }
-            Output: Trace:   _1_result . x . give ;
+            Output: Trace:     x . ref ;
+            Output: Trace:   exit Funcs.either => ref [d1] Data { x: 10 }
+            Output: Trace:   result = ref [d1] Data { x: 10 }
+            Output: Trace:   result . x . give ;
             Output: Trace: exit Main.main => 10
             Result: Ok: 10
             Alloc 0x10: [Int(10)]"#]])
@@ -412,22 +412,22 @@ fn interp_multi_place_given_both_given() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d1 = new Data (100) ;
-            Output: Trace:   _1_d1 = Data { x: 100 }
-            Output: Trace:   let _1_d2 = new Data (200) ;
-            Output: Trace:   _1_d2 = Data { x: 200 }
-            Output: Trace:   let _1_f = new Funcs () ;
-            Output: Trace:   _1_f = Funcs {  }
-            Output: Trace:   let _1_result = _1_f . give . pick (_1_d1 . give, _1_d2 . give) ;
+            Output: Trace:   let d1 = new Data (100) ;
+            Output: Trace:   d1 = Data { x: 100 }
+            Output: Trace:   let d2 = new Data (200) ;
+            Output: Trace:   d2 = Data { x: 200 }
+            Output: Trace:   let f = new Funcs () ;
+            Output: Trace:   f = Funcs {  }
+            Output: Trace:   let result = f . give . pick (d1 . give, d2 . give) ;
             Output: Trace:   enter Funcs.pick
-            Output: Trace:     _2_x . give ;
+            Output: Trace:     x . give ;
             Output: Trace:   exit Funcs.pick => Data { x: 100 }
-            Output: Trace:   _1_result = Data { x: 100 }
-            Output: Trace:   let _1_sink = new Sink () ;
-            Output: Trace:   _1_sink = Sink {  }
-            Output: Trace:   _1_sink . give . consume (_1_result . give) ;
+            Output: Trace:   result = Data { x: 100 }
+            Output: Trace:   let sink = new Sink () ;
+            Output: Trace:   sink = Sink {  }
+            Output: Trace:   sink . give . consume (result . give) ;
             Output: Trace:   enter Sink.consume
-            Output: Trace:     _3_d . x . give ;
+            Output: Trace:     d . x . give ;
             Output: Trace:   exit Sink.consume => 100
             Output: Trace: exit Main.main => 100
             Result: Ok: 100
@@ -438,9 +438,9 @@ fn interp_multi_place_given_both_given() {
 /// mut[x, y] through mut → result has or(mut[d1], mut[d2]).
 /// Mutating through the result should work.
 ///
-/// Currently hits preservation violation: result type `mut[_2_x] mut[_1_d1] Data`
-/// references method-scoped `_2_x`. After Phase 3c normalization, resolves to
-/// `or(mut[_1_d1], mut[_1_d2]) Data`.
+/// Currently hits preservation violation: result type `mut[x] mut[d1] Data`
+/// references method-scoped `x`. After Phase 3c normalization, resolves to
+/// `or(mut[d1], mut[d2]) Data`.
 #[test]
 fn interp_multi_place_mut_through_mut() {
     crate::assert_interpret!(
@@ -465,18 +465,18 @@ fn interp_multi_place_mut_through_mut() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d1 = new Data (10) ;
-            Output: Trace:   _1_d1 = Data { x: 10 }
-            Output: Trace:   let _1_d2 = new Data (20) ;
-            Output: Trace:   _1_d2 = Data { x: 20 }
-            Output: Trace:   let _1_f = new Funcs () ;
-            Output: Trace:   _1_f = Funcs {  }
-            Output: Trace:   let _1_result = _1_f . give . either [mut [_1_d1], mut [_1_d2]] (_1_d1 . mut, _1_d2 . mut) ;
+            Output: Trace:   let d1 = new Data (10) ;
+            Output: Trace:   d1 = Data { x: 10 }
+            Output: Trace:   let d2 = new Data (20) ;
+            Output: Trace:   d2 = Data { x: 20 }
+            Output: Trace:   let f = new Funcs () ;
+            Output: Trace:   f = Funcs {  }
+            Output: Trace:   let result = f . give . either [mut [d1], mut [d2]] (d1 . mut, d2 . mut) ;
             Output: Trace:   enter Funcs.either
-            Output: Trace:     _2_x . mut ;
-            Output: Trace:   exit Funcs.either => mut [_1_d1] mut [_1_d1] Data { x: 10 }
-            Output: Trace:   _1_result = mut [_1_d1] mut [_1_d1] Data { x: 10 }
-            Output: Trace:   _1_result . x . give ;
+            Output: Trace:     x . mut ;
+            Output: Trace:   exit Funcs.either => mut [d1] mut [d1] Data { x: 10 }
+            Output: Trace:   result = mut [d1] mut [d1] Data { x: 10 }
+            Output: Trace:   result . x . give ;
             Output: Trace: exit Main.main => 10
             Result: Ok: 10
             Alloc 0x10: [Int(10)]"#]])
@@ -514,25 +514,25 @@ fn interp_no_leaked_method_bindings() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_f1 = new Funcs () ;
-            Output: Trace:   _1_f1 = Funcs {  }
-            Output: Trace:   let _1_d1 = new Data (1) ;
-            Output: Trace:   _1_d1 = Data { x: 1 }
-            Output: Trace:   let _1_r1 = _1_f1 . give . take (_1_d1 . give) ;
+            Output: Trace:   let f1 = new Funcs () ;
+            Output: Trace:   f1 = Funcs {  }
+            Output: Trace:   let d1 = new Data (1) ;
+            Output: Trace:   d1 = Data { x: 1 }
+            Output: Trace:   let r1 = f1 . give . take (d1 . give) ;
             Output: Trace:   enter Funcs.take
-            Output: Trace:     _2_x . give ;
+            Output: Trace:     x . give ;
             Output: Trace:   exit Funcs.take => Data { x: 1 }
-            Output: Trace:   _1_r1 = Data { x: 1 }
-            Output: Trace:   let _1_f2 = new Funcs () ;
-            Output: Trace:   _1_f2 = Funcs {  }
-            Output: Trace:   let _1_d2 = new Data (2) ;
-            Output: Trace:   _1_d2 = Data { x: 2 }
-            Output: Trace:   let _1_r2 = _1_f2 . give . take (_1_d2 . give) ;
+            Output: Trace:   r1 = Data { x: 1 }
+            Output: Trace:   let f2 = new Funcs () ;
+            Output: Trace:   f2 = Funcs {  }
+            Output: Trace:   let d2 = new Data (2) ;
+            Output: Trace:   d2 = Data { x: 2 }
+            Output: Trace:   let r2 = f2 . give . take (d2 . give) ;
             Output: Trace:   enter Funcs.take
-            Output: Trace:     _3_x . give ;
+            Output: Trace:     x . give ;
             Output: Trace:   exit Funcs.take => Data { x: 2 }
-            Output: Trace:   _1_r2 = Data { x: 2 }
-            Output: Trace:   _1_r1 . x . give + _1_r2 . x . give ;
+            Output: Trace:   r2 = Data { x: 2 }
+            Output: Trace:   r1 . x . give + r2 . x . give ;
             Output: Trace: exit Main.main => 3
             Result: Ok: 3
             Alloc 0x18: [Int(3)]"#]])
@@ -26,9 +26,9 @@ fn give_from_given() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 42 }
             Result: Ok: Data { x: 42 }
             Alloc 0x05: [Int(42)]"#]])
@@ -50,18 +50,18 @@ fn give_from_given_uninitializes_source() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
                 live_after = LivePlaces { accessed: {d}, traversed: {} }
                 place = d"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_a = _1_d . give ;
-            Output: Trace:   _1_a = Data { x: 42 }
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let a = d . give ;
+            Output: Trace:   a = Data { x: 42 }
+            Output: Trace:   d . give ;
             Result: Fault: access of uninitialized value
             Alloc 0x05: [Int(42)]"#]])
     );
@@ -86,13 +86,13 @@ fn give_from_shared() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   print(_1_s . give) ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   print(s . give) ;
             Output: ----->   shared Data { x: 42 }
-            Output: Trace:   _1_s . give ;
+            Output: Trace:   s . give ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x09: [Int(42)]"#]])
@@ -118,11 +118,11 @@ fn give_from_shared_nested() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_s . give ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 1 } }
+            Output: Trace:   s . give ;
             Output: Trace: exit Main.main => shared Outer { inner: Inner { x: 1 } }
             Result: Ok: shared Outer { inner: Inner { x: 1 } }
             Alloc 0x08: [Int(1)]"#]])
@@ -146,12 +146,12 @@ fn give_from_borrowed() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_r = _1_d . ref ;
-            Output: Trace:   _1_r = ref [_1_d] Data { x: 42 }
-            Output: Trace:   print(_1_r . give) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let r = d . ref ;
+            Output: Trace:   r = ref [d] Data { x: 42 }
+            Output: Trace:   print(r . give) ;
+            Output: ----->   ref [d] Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -179,19 +179,19 @@ fn give_shared_multiple_times() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   let _1_x1 = _1_s . give ;
-            Output: Trace:   _1_x1 = shared Data { x: 42 }
-            Output: Trace:   let _1_x2 = _1_s . give ;
-            Output: Trace:   _1_x2 = shared Data { x: 42 }
-            Output: Trace:   print(_1_x1 . give) ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   let x1 = s . give ;
+            Output: Trace:   x1 = shared Data { x: 42 }
+            Output: Trace:   let x2 = s . give ;
+            Output: Trace:   x2 = shared Data { x: 42 }
+            Output: Trace:   print(x1 . give) ;
             Output: ----->   shared Data { x: 42 }
-            Output: Trace:   print(_1_x2 . give) ;
+            Output: Trace:   print(x2 . give) ;
             Output: ----->   shared Data { x: 42 }
-            Output: Trace:   _1_s . give ;
+            Output: Trace:   s . give ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x0f: [Int(42)]"#]])
@@ -220,17 +220,17 @@ fn give_shared_nested_subfield() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (99)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 99 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 99 } }
-            Output: Trace:   let _1_i1 = _1_s . inner . give ;
-            Output: Trace:   _1_i1 = shared Inner { x: 99 }
-            Output: Trace:   let _1_i2 = _1_s . inner . give ;
-            Output: Trace:   _1_i2 = shared Inner { x: 99 }
-            Output: Trace:   print(_1_i1 . give) ;
+            Output: Trace:   let o = new Outer (new Inner (99)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 99 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 99 } }
+            Output: Trace:   let i1 = s . inner . give ;
+            Output: Trace:   i1 = shared Inner { x: 99 }
+            Output: Trace:   let i2 = s . inner . give ;
+            Output: Trace:   i2 = shared Inner { x: 99 }
+            Output: Trace:   print(i1 . give) ;
             Output: ----->   shared Inner { x: 99 }
-            Output: Trace:   _1_i2 . give ;
+            Output: Trace:   i2 . give ;
             Output: Trace: exit Main.main => shared Inner { x: 99 }
             Result: Ok: shared Inner { x: 99 }
             Alloc 0x0e: [Int(99)]"#]])
@@ -258,11 +258,11 @@ fn ref_from_given() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   print(_1_d . ref) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   print(d . ref) ;
+            Output: ----->   ref [d] Data { x: 42 }
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 42 }
             Result: Ok: Data { x: 42 }
             Alloc 0x07: [Int(42)]"#]])
@@ -286,11 +286,11 @@ fn ref_from_shared() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   _1_s . ref ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   s . ref ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x07: [Int(42)]"#]])
@@ -315,11 +315,11 @@ fn ref_from_shared_nested() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_s . ref ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 1 } }
+            Output: Trace:   s . ref ;
             Output: Trace: exit Main.main => shared Outer { inner: Inner { x: 1 } }
             Result: Ok: shared Outer { inner: Inner { x: 1 } }
             Alloc 0x08: [Int(1)]"#]])
@@ -349,19 +349,19 @@ fn ref_from_shared_nested_subfield() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (7)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 7 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 7 } }
-            Output: Trace:   let _1_r = _1_s . ref ;
-            Output: Trace:   _1_r = shared Outer { inner: Inner { x: 7 } }
-            Output: Trace:   let _1_i1 = _1_r . inner . give ;
-            Output: Trace:   _1_i1 = shared Inner { x: 7 }
-            Output: Trace:   let _1_i2 = _1_r . inner . give ;
-            Output: Trace:   _1_i2 = shared Inner { x: 7 }
-            Output: Trace:   print(_1_i1 . give) ;
+            Output: Trace:   let o = new Outer (new Inner (7)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 7 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 7 } }
+            Output: Trace:   let r = s . ref ;
+            Output: Trace:   r = shared Outer { inner: Inner { x: 7 } }
+            Output: Trace:   let i1 = r . inner . give ;
+            Output: Trace:   i1 = shared Inner { x: 7 }
+            Output: Trace:   let i2 = r . inner . give ;
+            Output: Trace:   i2 = shared Inner { x: 7 }
+            Output: Trace:   print(i1 . give) ;
             Output: ----->   shared Inner { x: 7 }
-            Output: Trace:   _1_i2 . give ;
+            Output: Trace:   i2 . give ;
             Output: Trace: exit Main.main => shared Inner { x: 7 }
             Result: Ok: shared Inner { x: 7 }
             Alloc 0x10: [Int(7)]"#]])
@@ -385,12 +385,12 @@ fn ref_from_borrowed() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_r = _1_d . ref ;
-            Output: Trace:   _1_r = ref [_1_d] Data { x: 42 }
-            Output: Trace:   print(_1_r . ref) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let r = d . ref ;
+            Output: Trace:   r = ref [d] Data { x: 42 }
+            Output: Trace:   print(r . ref) ;
+            Output: ----->   ref [d] Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -419,11 +419,11 @@ fn drop_given() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   print(_1_d . ref) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
-            Output: Trace:   _1_d . drop ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   print(d . ref) ;
+            Output: ----->   ref [d] Data { x: 42 }
+            Output: Trace:   d . drop ;
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
             Result: Ok: 0
@@ -450,11 +450,11 @@ fn drop_given_nested() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   print(_1_o . ref) ;
-            Output: ----->   ref [_1_o] Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_o . drop ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   print(o . ref) ;
+            Output: ----->   ref [o] Outer { inner: Inner { x: 1 } }
+            Output: Trace:   o . drop ;
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
             Result: Ok: 0
@@ -478,17 +478,17 @@ fn drop_given_nested_uninitializes() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Outer, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Outer, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
                 live_after = LivePlaces { accessed: {o}, traversed: {} }
                 place = o"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_o . drop ;
-            Output: Trace:   _1_o . give ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   o . drop ;
+            Output: Trace:   o . give ;
             Result: Fault: access of uninitialized value"#]])
     );
 }
@@ -511,13 +511,13 @@ fn drop_borrowed_is_noop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_r = _1_d . ref ;
-            Output: Trace:   _1_r = ref [_1_d] Data { x: 42 }
-            Output: Trace:   _1_r . drop ;
-            Output: Trace:   print(_1_r . give) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let r = d . ref ;
+            Output: Trace:   r = ref [d] Data { x: 42 }
+            Output: Trace:   r . drop ;
+            Output: Trace:   print(r . give) ;
+            Output: ----->   ref [d] Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -542,13 +542,13 @@ fn drop_shared() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   print(_1_s . ref) ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   print(s . ref) ;
             Output: ----->   shared Data { x: 42 }
-            Output: Trace:   _1_s . drop ;
+            Output: Trace:   s . drop ;
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
             Result: Ok: 0
@@ -575,13 +575,13 @@ fn drop_shared_nested() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 1 } }
-            Output: Trace:   print(_1_s . ref) ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 1 } }
+            Output: Trace:   print(s . ref) ;
             Output: ----->   shared Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_s . drop ;
+            Output: Trace:   s . drop ;
             Output: Trace:   0 ;
             Output: Trace: exit Main.main => 0
             Result: Ok: 0
@@ -610,9 +610,9 @@ fn share_nested_objects() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_o . give . share ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   o . give . share ;
             Output: Trace: exit Main.main => shared Outer { inner: Inner { x: 1 } }
             Result: Ok: shared Outer { inner: Inner { x: 1 } }
             Alloc 0x06: [Int(1)]"#]])
@@ -635,11 +635,11 @@ fn share_already_shared_is_noop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   _1_s . give . share ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   s . give . share ;
             Output: Trace: exit Main.main => shared Data { x: 42 }
             Result: Ok: shared Data { x: 42 }
             Alloc 0x07: [Int(42)]"#]])
@@ -663,12 +663,12 @@ fn share_borrowed_is_noop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_r = _1_d . ref ;
-            Output: Trace:   _1_r = ref [_1_d] Data { x: 42 }
-            Output: Trace:   print(_1_r . give . share) ;
-            Output: ----->   ref [_1_d] Data { x: 42 }
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let r = d . ref ;
+            Output: Trace:   r = ref [d] Data { x: 42 }
+            Output: Trace:   print(r . give . share) ;
+            Output: ----->   ref [d] Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -704,15 +704,15 @@ fn give_field_through_borrowed_path() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (42)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 42 } }
-            Output: Trace:   let _1_r = _1_o . ref ;
-            Output: Trace:   _1_r = ref [_1_o] Outer { inner: Inner { x: 42 } }
-            Output: Trace:   let _1_stolen = _1_r . inner . give ;
-            Output: Trace:   _1_stolen = ref [_1_o] Inner { x: 42 }
-            Output: Trace:   print(_1_stolen . give) ;
-            Output: ----->   ref [_1_o] Inner { x: 42 }
-            Output: Trace:   _1_o . inner . give ;
+            Output: Trace:   let o = new Outer (new Inner (42)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 42 } }
+            Output: Trace:   let r = o . ref ;
+            Output: Trace:   r = ref [o] Outer { inner: Inner { x: 42 } }
+            Output: Trace:   let stolen = r . inner . give ;
+            Output: Trace:   stolen = ref [o] Inner { x: 42 }
+            Output: Trace:   print(stolen . give) ;
+            Output: ----->   ref [o] Inner { x: 42 }
+            Output: Trace:   o . inner . give ;
             Output: Trace: exit Main.main => Inner { x: 42 }
             Result: Ok: Inner { x: 42 }
             Alloc 0x0c: [Int(42)]"#]])
@@ -738,12 +738,12 @@ fn ref_field_through_borrowed_path() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (42)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 42 } }
-            Output: Trace:   let _1_r = _1_o . ref ;
-            Output: Trace:   _1_r = ref [_1_o] Outer { inner: Inner { x: 42 } }
-            Output: Trace:   print(_1_r . inner . ref) ;
-            Output: ----->   ref [_1_o] Inner { x: 42 }
+            Output: Trace:   let o = new Outer (new Inner (42)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 42 } }
+            Output: Trace:   let r = o . ref ;
+            Output: Trace:   r = ref [o] Outer { inner: Inner { x: 42 } }
+            Output: Trace:   print(r . inner . ref) ;
+            Output: ----->   ref [o] Inner { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -772,17 +772,17 @@ fn give_field_through_shared_path() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (42)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 42 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 42 } }
-            Output: Trace:   let _1_i1 = _1_s . inner . give ;
-            Output: Trace:   _1_i1 = shared Inner { x: 42 }
-            Output: Trace:   let _1_i2 = _1_s . inner . give ;
-            Output: Trace:   _1_i2 = shared Inner { x: 42 }
-            Output: Trace:   print(_1_i1 . give) ;
+            Output: Trace:   let o = new Outer (new Inner (42)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 42 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 42 } }
+            Output: Trace:   let i1 = s . inner . give ;
+            Output: Trace:   i1 = shared Inner { x: 42 }
+            Output: Trace:   let i2 = s . inner . give ;
+            Output: Trace:   i2 = shared Inner { x: 42 }
+            Output: Trace:   print(i1 . give) ;
             Output: ----->   shared Inner { x: 42 }
-            Output: Trace:   _1_i2 . give ;
+            Output: Trace:   i2 . give ;
             Output: Trace: exit Main.main => shared Inner { x: 42 }
             Result: Ok: shared Inner { x: 42 }
             Alloc 0x0e: [Int(42)]"#]])
@@ -823,25 +823,25 @@ fn shared_ref_subtype() {
         },
          type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Link0 (new Link1 (new Link2 ())) ;
-            Output: Trace:   _1_o = Link0 { inner: Link1 { inner: Link2 {  } } }
-            Output: Trace:   let _1_a = _1_self . ref . sub [ref [_1_self], ref [_1_o]] (_1_o . inner . ref) ;
+            Output: Trace:   let o = new Link0 (new Link1 (new Link2 ())) ;
+            Output: Trace:   o = Link0 { inner: Link1 { inner: Link2 {  } } }
+            Output: Trace:   let a = self . ref . sub [ref [self], ref [o]] (o . inner . ref) ;
             Output: Trace:   enter Main.sub
-            Output: Trace:     _2_link1 . inner . give ;
-            Output: Trace:   exit Main.sub => ref [_1_o . inner] Link2 {  }
-            Output: Trace:   _1_a = ref [_1_o . inner] Link2 {  }
-            Output: Trace:   print(_1_a . give) ;
-            Output: ----->   ref [_1_o . inner] Link2 {  }
-            Output: Trace:   let _1_x = new Link1 (new Link2 ()) . share ;
-            Output: Trace:   _1_x = shared Link1 { inner: Link2 {  } }
-            Output: Trace:   let _1_y = _1_self . ref . sub [ref [_1_self], ref [_1_o]] (_1_x . give) ;
+            Output: Trace:     link1 . inner . give ;
+            Output: Trace:   exit Main.sub => ref [o . inner] Link2 {  }
+            Output: Trace:   a = ref [o . inner] Link2 {  }
+            Output: Trace:   print(a . give) ;
+            Output: ----->   ref [o . inner] Link2 {  }
+            Output: Trace:   let x = new Link1 (new Link2 ()) . share ;
+            Output: Trace:   x = shared Link1 { inner: Link2 {  } }
+            Output: Trace:   let y = self . ref . sub [ref [self], ref [o]] (x . give) ;
             Output: Trace:   enter Main.sub
-            Output: Trace:     _3_link1 . inner . give ;
+            Output: Trace:     link1 . inner . give ;
             Output: Trace:   exit Main.sub => shared Link2 {  }
-            Output: Trace:   _1_y = shared Link2 {  }
-            Output: Trace:   print(_1_x . give) ;
+            Output: Trace:   y = shared Link2 {  }
+            Output: Trace:   print(x . give) ;
             Output: ----->   shared Link1 { inner: Link2 {  } }
-            Output: Trace:   print(_1_y . give) ;
+            Output: Trace:   print(y . give) ;
             Output: ----->   shared Link2 {  }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
@@ -871,13 +871,13 @@ fn mut_from_given() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 42 }
-            Output: Trace:   print(_1_m . give) ;
-            Output: ----->   mut [_1_d] Data { x: 42 }
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 42 }
+            Output: Trace:   print(m . give) ;
+            Output: ----->   mut [d] Data { x: 42 }
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 42 }
             Result: Ok: Data { x: 42 }
             Alloc 0x09: [Int(42)]"#]])
@@ -901,11 +901,11 @@ fn mut_field_read() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (10, 20) ;
-            Output: Trace:   _1_d = Data { x: 10, y: 20 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 10, y: 20 }
-            Output: Trace:   _1_m . y . give ;
+            Output: Trace:   let d = new Data (10, 20) ;
+            Output: Trace:   d = Data { x: 10, y: 20 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 10, y: 20 }
+            Output: Trace:   m . y . give ;
             Output: Trace: exit Main.main => 20
             Result: Ok: 20
             Alloc 0x08: [Int(20)]"#]])
@@ -930,13 +930,13 @@ fn mut_field_reassign() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 42 }
-            Output: Trace:   _1_m . x = 99 ;
-            Output: Trace:   _1_m . x = 99
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 42 }
+            Output: Trace:   m . x = 99 ;
+            Output: Trace:   m . x = 99
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 99 }
             Result: Ok: Data { x: 99 }
             Alloc 0x09: [Int(99)]"#]])
@@ -962,15 +962,15 @@ fn mut_give_copies_mutref() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 42 }
-            Output: Trace:   let _1_m2 = _1_m . give ;
-            Output: Trace:   _1_m2 = mut [_1_d] Data { x: 42 }
-            Output: Trace:   _1_m2 . x = 99 ;
-            Output: Trace:   _1_m2 . x = 99
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 42 }
+            Output: Trace:   let m2 = m . give ;
+            Output: Trace:   m2 = mut [d] Data { x: 42 }
+            Output: Trace:   m2 . x = 99 ;
+            Output: Trace:   m2 . x = 99
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 99 }
             Result: Ok: Data { x: 99 }
             Alloc 0x0b: [Int(99)]"#]])
@@ -995,12 +995,12 @@ fn mut_ref_through_mutref() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 42 }
-            Output: Trace:   print(_1_m . ref) ;
-            Output: ----->   ref [_1_m] mut [_1_d] Data { x: 42 }
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 42 }
+            Output: Trace:   print(m . ref) ;
+            Output: ----->   ref [m] mut [d] Data { x: 42 }
             Output: Trace:   () ;
             Output: Trace: exit Main.main => ()
             Result: Ok: ()"#]])
@@ -1025,12 +1025,12 @@ fn mut_drop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 42 }
-            Output: Trace:   _1_m . drop ;
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 42 }
+            Output: Trace:   m . drop ;
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 42 }
             Result: Ok: Data { x: 42 }
             Alloc 0x08: [Int(42)]"#]])
@@ -1055,15 +1055,15 @@ fn mut_of_mut() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m1 = _1_d . mut ;
-            Output: Trace:   _1_m1 = mut [_1_d] Data { x: 42 }
-            Output: Trace:   let _1_m2 = _1_m1 . mut ;
-            Output: Trace:   _1_m2 = mut [_1_m1] mut [_1_d] Data { x: 42 }
-            Output: Trace:   _1_m2 . x = 77 ;
-            Output: Trace:   _1_m2 . x = 77
-            Output: Trace:   _1_d . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m1 = d . mut ;
+            Output: Trace:   m1 = mut [d] Data { x: 42 }
+            Output: Trace:   let m2 = m1 . mut ;
+            Output: Trace:   m2 = mut [m1] mut [d] Data { x: 42 }
+            Output: Trace:   m2 . x = 77 ;
+            Output: Trace:   m2 . x = 77
+            Output: Trace:   d . give ;
             Output: Trace: exit Main.main => Data { x: 77 }
             Result: Ok: Data { x: 77 }
             Alloc 0x0b: [Int(77)]"#]])
@@ -1088,13 +1088,13 @@ fn mut_nested_field_reassign() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_m = _1_o . mut ;
-            Output: Trace:   _1_m = mut [_1_o] Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_m . inner . x = 42 ;
-            Output: Trace:   _1_m . inner . x = 42
-            Output: Trace:   _1_o . give ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let m = o . mut ;
+            Output: Trace:   m = mut [o] Outer { inner: Inner { x: 1 } }
+            Output: Trace:   m . inner . x = 42 ;
+            Output: Trace:   m . inner . x = 42
+            Output: Trace:   o . give ;
             Output: Trace: exit Main.main => Outer { inner: Inner { x: 42 } }
             Result: Ok: Outer { inner: Inner { x: 42 } }
             Alloc 0x0a: [Int(42)]"#]])
@@ -1125,13 +1125,13 @@ fn mut_field_of_given() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_m = _1_o . inner . mut ;
-            Output: Trace:   _1_m = mut [_1_o . inner] Inner { x: 1 }
-            Output: Trace:   _1_m . x = 99 ;
-            Output: Trace:   _1_m . x = 99
-            Output: Trace:   _1_o . give ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let m = o . inner . mut ;
+            Output: Trace:   m = mut [o . inner] Inner { x: 1 }
+            Output: Trace:   m . x = 99 ;
+            Output: Trace:   m . x = 99
+            Output: Trace:   o . give ;
             Output: Trace: exit Main.main => Outer { inner: Inner { x: 99 } }
             Result: Ok: Outer { inner: Inner { x: 99 } }
             Alloc 0x0a: [Int(99)]"#]])
@@ -1155,11 +1155,11 @@ fn mut_field_of_given_read() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (10, 20)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 10, y: 20 } }
-            Output: Trace:   let _1_m = _1_o . inner . mut ;
-            Output: Trace:   _1_m = mut [_1_o . inner] Inner { x: 10, y: 20 }
-            Output: Trace:   _1_m . y . give ;
+            Output: Trace:   let o = new Outer (new Inner (10, 20)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 10, y: 20 } }
+            Output: Trace:   let m = o . inner . mut ;
+            Output: Trace:   m = mut [o . inner] Inner { x: 10, y: 20 }
+            Output: Trace:   m . y . give ;
             Output: Trace: exit Main.main => 20
             Result: Ok: 20
             Alloc 0x09: [Int(20)]"#]])
@@ -1185,12 +1185,12 @@ fn mut_field_of_given_drop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (42)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 42 } }
-            Output: Trace:   let _1_m = _1_o . inner . mut ;
-            Output: Trace:   _1_m = mut [_1_o . inner] Inner { x: 42 }
-            Output: Trace:   _1_m . drop ;
-            Output: Trace:   _1_o . give ;
+            Output: Trace:   let o = new Outer (new Inner (42)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 42 } }
+            Output: Trace:   let m = o . inner . mut ;
+            Output: Trace:   m = mut [o . inner] Inner { x: 42 }
+            Output: Trace:   m . drop ;
+            Output: Trace:   o . give ;
             Output: Trace: exit Main.main => Outer { inner: Inner { x: 42 } }
             Result: Ok: Outer { inner: Inner { x: 42 } }
             Alloc 0x09: [Int(42)]"#]])
@@ -1218,15 +1218,15 @@ fn mut_field_through_mut() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_m = _1_o . mut ;
-            Output: Trace:   _1_m = mut [_1_o] Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_m2 = _1_m . inner . mut ;
-            Output: Trace:   _1_m2 = mut [_1_m . inner] mut [_1_o] Inner { x: 1 }
-            Output: Trace:   _1_m2 . x = 55 ;
-            Output: Trace:   _1_m2 . x = 55
-            Output: Trace:   _1_o . give ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let m = o . mut ;
+            Output: Trace:   m = mut [o] Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let m2 = m . inner . mut ;
+            Output: Trace:   m2 = mut [m . inner] mut [o] Inner { x: 1 }
+            Output: Trace:   m2 . x = 55 ;
+            Output: Trace:   m2 . x = 55
+            Output: Trace:   o . give ;
             Output: Trace: exit Main.main => Outer { inner: Inner { x: 55 } }
             Result: Ok: Outer { inner: Inner { x: 55 } }
             Alloc 0x0c: [Int(55)]"#]])
@@ -1251,17 +1251,17 @@ fn mut_field_through_shared() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, s: shared Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, s: shared Outer}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, s: shared Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, s: shared Outer}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, s: shared Outer}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, s: shared Outer}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_s = _1_o . give . share ;
-            Output: Trace:   _1_s = shared Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_s . inner . mut ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let s = o . give . share ;
+            Output: Trace:   s = shared Outer { inner: Inner { x: 1 } }
+            Output: Trace:   s . inner . mut ;
             Result: Fault: cannot take mutable reference to shared value
             Alloc 0x06: [Int(1)]"#]])
     );
@@ -1286,21 +1286,21 @@ fn mut_field_through_ref() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [o], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [o], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Outer, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Outer, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [o], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [o], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [o], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [o], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Outer, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Outer, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer, r: ref [o] Outer}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_r = _1_o . ref ;
-            Output: Trace:   _1_r = ref [_1_o] Outer { inner: Inner { x: 1 } }
-            Output: Trace:   _1_r . inner . mut ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let r = o . ref ;
+            Output: Trace:   r = ref [o] Outer { inner: Inner { x: 1 } }
+            Output: Trace:   r . inner . mut ;
             Result: Fault: cannot take mutable reference to borrowed value
             Alloc 0x04: [Int(1)]
             Alloc 0x06: [Int(1)]"#]])
@@ -1323,18 +1323,18 @@ fn mut_field_uninitialized() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Inner, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Inner, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, o: Outer}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
                 live_after = LivePlaces { accessed: {o . inner}, traversed: {} }
                 place = o . inner"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_o = new Outer (new Inner (1)) ;
-            Output: Trace:   _1_o = Outer { inner: Inner { x: 1 } }
-            Output: Trace:   let _1_stolen = _1_o . inner . give ;
-            Output: Trace:   _1_stolen = Inner { x: 1 }
-            Output: Trace:   _1_o . inner . mut ;
+            Output: Trace:   let o = new Outer (new Inner (1)) ;
+            Output: Trace:   o = Outer { inner: Inner { x: 1 } }
+            Output: Trace:   let stolen = o . inner . give ;
+            Output: Trace:   stolen = Inner { x: 1 }
+            Output: Trace:   o . inner . mut ;
             Result: Fault: access of uninitialized value
             Alloc 0x06: [Int(1)]"#]])
     );
@@ -1359,17 +1359,17 @@ fn mut_of_shared_faults() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, s: shared Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, s: shared Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, s: shared Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, s: shared Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, s: shared Data}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, s: shared Data}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_s = _1_d . give . share ;
-            Output: Trace:   _1_s = shared Data { x: 42 }
-            Output: Trace:   _1_s . mut ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let s = d . give . share ;
+            Output: Trace:   s = shared Data { x: 42 }
+            Output: Trace:   s . mut ;
             Result: Fault: cannot take mutable reference to shared value
             Alloc 0x05: [Int(42)]"#]])
     );
@@ -1390,17 +1390,17 @@ fn mut_of_uninitialized_faults() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
                 live_after = LivePlaces { accessed: {d}, traversed: {} }
                 place = d"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   _1_d . drop ;
-            Output: Trace:   _1_d . mut ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   d . drop ;
+            Output: Trace:   d . mut ;
             Result: Fault: access of uninitialized value"#]])
     );
 }
@@ -1421,14 +1421,14 @@ fn mut_of_copy_type_faults() {
             the rule "class move" at (predicates.rs) failed because
               pattern `false` did not match value `true`
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: Int, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, x: Int}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: Int, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, x: Int}, assumptions: {}, fresh: 0 } }
 
             the rule "shared-class move" at (predicates.rs) failed because
               expression evaluated to an empty collection: `parameters`"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_x = 42 ;
-            Output: Trace:   _1_x = 42
-            Output: Trace:   _1_x . mut ;
+            Output: Trace:   let x = 42 ;
+            Output: Trace:   x = 42
+            Output: Trace:   x . mut ;
             Result: Fault: cannot take mutable reference to shared value
             Alloc 0x02: [Int(42)]"#]])
     );
@@ -1460,12 +1460,12 @@ fn mut_of_array_create_and_drop() {
         },
         type: ok, interpret: ok(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_a = array_new [Data](2) ;
-            Output: Trace:   _1_a = Array { flag: Given, rc: 1, Data { x: ⚡ }, Data { x: ⚡ } }
-            Output: Trace:   let _1_m = _1_a . mut ;
-            Output: Trace:   _1_m = mut [_1_a] <unexpected: RefCount(1)>
-            Output: Trace:   _1_m . drop ;
-            Output: Trace:   array_capacity [Data, given](_1_a . give) ;
+            Output: Trace:   let a = array_new [Data](2) ;
+            Output: Trace:   a = Array { flag: Given, rc: 1, Data { x: ⚡ }, Data { x: ⚡ } }
+            Output: Trace:   let m = a . mut ;
+            Output: Trace:   m = mut [a] <unexpected: RefCount(1)>
+            Output: Trace:   m . drop ;
+            Output: Trace:   array_capacity [Data, given](a . give) ;
             Output: Trace: exit Main.main => 2
             Result: Ok: 2
             Alloc 0x0a: [Int(2)]"#]])
@@ -1496,22 +1496,22 @@ fn mut_dangling_after_give() {
             }
         },
         type: error(expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, m: mut [stolen] Data, stolen: Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, m: mut [stolen] Data, stolen: Data}, assumptions: {}, fresh: 0 } }
 
             the rule "keep non-popped link" at (pop_normalize.rs) failed because
               condition evaluated to false: `!link_references_popped(&link, &popped_vars)`
                 &link = Mtd(stolen)
                 &popped_vars = [d, m, stolen]
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, m: mut [stolen] Data, stolen: Data}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, m: mut [stolen] Data, stolen: Data}, assumptions: {}, fresh: 0 } }"#]]), interpret: fault(expect_test::expect![[r#"
             Output: Trace: enter Main.main
-            Output: Trace:   let _1_d = new Data (42) ;
-            Output: Trace:   _1_d = Data { x: 42 }
-            Output: Trace:   let _1_m = _1_d . mut ;
-            Output: Trace:   _1_m = mut [_1_d] Data { x: 42 }
-            Output: Trace:   let _1_stolen = _1_d . give ;
-            Output: Trace:   _1_stolen = Data { x: 42 }
-            Output: Trace:   _1_m . give ;
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let m = d . mut ;
+            Output: Trace:   m = mut [d] Data { x: 42 }
+            Output: Trace:   let stolen = d . give ;
+            Output: Trace:   stolen = Data { x: 42 }
+            Output: Trace:   m . give ;
             Result: Fault: access of uninitialized value
             Alloc 0x05: [MutRef(0x03)]
             Alloc 0x07: [Int(42)]"#]])
@@ -0,0 +1,136 @@
+// Tests for unwinding: a `!` panic drops the live values of every block and
+// method call it passes through, running their drop bodies, before it is
+// reported as a fault.
+
+#[test]
+fn panic_runs_drop_body_of_local() {
+    crate::assert_interpret!(
+        {
+            class Data {
+                x: Int;
+
+                drop {
+                    print(self.x.give);
+                }
+            }
+
+            class Main {
+                fn main(given self) -> Int {
+                    let d = new Data(42);
+                    let p: Int = !;
+                    0;
+                }
+            }
+        },
+         type: ok, interpret: fault(expect_test::expect![[r#"
+            Output: Trace: enter Main.main
+            Output: Trace:   let d = new Data (42) ;
+            Output: Trace:   d = Data { x: 42 }
+            Output: Trace:   let p : Int = ! ;
+            Output: Trace:   drop Data
+            Output: Trace:     print(self . x . give) ;
+            Output: ----->     42
+            Output: Trace: unwind Main.main
+            Result: Fault: panic! (unwound Main.main)"#]])
+    );
+}
+
+#[test]
+fn panic_unwinds_callee_then_caller() {
+    // The callee's parameters are dropped first, then the caller's locals.
+    crate::assert_interpret!(
+        {
+            class Data {
+                x: Int;
+
+                drop {
+                    print(self.x.give);
+                }
+            }
+
+            class Helper {
+                fn fail(given self, d: given Data) -> Int {
+                    let p: Int = !;
+                    0;
+                }
+            }
+
+            class Main {
+                fn main(given self) -> Int {
+                    let outer = new Data(1);
+                    let h = new Helper();
+                    h.give.fail(new Data(2));
+                }
+            }
+        },
+         type: ok, interpret: fault(expect_test::expect![[r#"
+            Output: Trace: enter Main.main
+            Output: Trace:   let outer = new Data (1) ;
+            Output: Trace:   outer = Data { x: 1 }
+            Output: Trace:   let h = new Helper () ;
+            Output: Trace:   h = Helper {  }
+            Output: Trace:   h . give . fail (new Data (2)) ;
+            Output: Trace:   enter Helper.fail
+            Output: Trace:     let p : Int = ! ;
+            Output: Trace:     drop Data
+            Output: Trace:       print(self . x . give) ;
+            Output: ----->       2
+            Output: Trace:   unwind Helper.fail
+            Output: Trace:   drop Data
+            Output: Trace:     print(self . x . give) ;
+            Output: ----->     1
+            Output: Trace: unwind Main.main
+            Result: Fault: panic! (unwound Helper.fail, Main.main)"#]])
+    );
+}
+
+#[test]
+fn panic_in_drop_body_while_unwinding_stops_unwinding() {
+    crate::assert_interpret!(
+        {
+            class Bomb {
+                drop {
+                    let p: Int = !;
+                }
+            }
+
+            class Main {
+                fn main(given self) -> Int {
+                    let b = new Bomb();
+                    let p: Int = !;
+                    0;
+                }
+            }
+        },
+         type: ok, interpret: fault(expect_test::expect![[r#"
+            Output: Trace: enter Main.main
+            Output: Trace:   let b = new Bomb () ;
+            Output: Trace:   b = Bomb {  }
+            Output: Trace:   let p : Int = ! ;
+            Output: Trace:   drop Bomb
+            Output: Trace:     let p : Int = ! ;
+            Result: Fault: fault while unwinding from `panic!`: panic!"#]])
+    );
+}
+
+#[test]
+fn unwinding_leaves_a_clean_heap() {
+    use crate::interpreter::{Interpreter, Panic};
+
+    let program = crate::test_util::parse_program(&["
+        class Data { x: Int; }
+        class Main {
+            fn main(given self) -> Int {
+                let d = new Data(42);
+                let p: Int = !;
+                0;
+            }
+        }
+    "]);
+    let mut interpreter = Interpreter::new(program);
+    let error = interpreter.interpret().unwrap_err();
+    let panic = error.downcast_ref::<Panic>().expect("should panic");
+    assert_eq!(panic.unwound, ["Main.main"]);
+    let audit = interpreter.audit(None);
+    assert!(audit.is_clean(), "{audit}");
+}
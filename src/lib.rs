@@ -1,15 +1,27 @@
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use dada_lang::FormalityLang;
 use fn_error_context::context;
 use formality_core::Fallible;
 use elaborator::ElaboratedProgram;
 use grammar::Program;
 
+pub mod coverage;
 pub mod elaborator;
+pub mod explain;
+pub mod fuzz;
 pub mod grammar;
 pub mod interpreter;
+#[cfg(feature = "lsp")]
+pub mod lsp;
+pub mod mir;
+#[cfg_attr(not(feature = "lsp"), allow(dead_code))]
+mod outline;
+pub mod profile;
+pub mod reduce;
 pub mod test_util;
 pub mod type_system;
 
@@ -63,26 +75,335 @@ formality_core::declare_language! {
 }
 
 #[derive(Parser, Debug)] // requires `derive` feature
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Files to type check (when no subcommand is given).
     paths: Vec<String>,
+
+    /// Profile the judgments run while type checking, print a summary to stderr,
+    /// and write a flamegraph-compatible folded-stacks file to this path.
+    #[arg(long)]
+    profile: Option<PathBuf>,
+
+    /// Print the hit rates of the judgment cache to stderr after checking each file.
+    #[arg(long)]
+    memo_stats: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Type check a file and interactively explore the resulting derivation.
+    Explain { path: String },
+
+    /// Print the control-flow-graph IR of every method in a file.
+    Mir { path: String },
+
+    /// Run a file in the interpreter, pausing before each statement.
+    Debug {
+        path: String,
+
+        #[command(flatten)]
+        limits: Limits,
+    },
+
+    /// Run a file in the interpreter and check that everything it allocated,
+    /// except its result, was freed exactly once.
+    Audit {
+        path: String,
+
+        #[command(flatten)]
+        limits: Limits,
+    },
+
+    /// Run a file in the interpreter and draw the heap it leaves behind,
+    /// starting from the program's result, as a Mermaid graph.
+    Graph {
+        path: String,
+
+        /// Draw a Graphviz graph instead.
+        #[arg(long)]
+        dot: bool,
+
+        #[command(flatten)]
+        limits: Limits,
+    },
+
+    /// Run a file in the interpreter and write what it does as JSON Lines,
+    /// one event per line, for comparing runs with other tools.
+    Events {
+        path: String,
+
+        /// Write the events to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        #[command(flatten)]
+        limits: Limits,
+    },
+
+    /// Run a language server for `.dada` files, speaking LSP over stdin and stdout.
+    #[cfg(feature = "lsp")]
+    Lsp,
+
+    /// Generate random programs and report any that type check and then fault
+    /// in the interpreter, together with a minimal reproducer.
+    Fuzz {
+        /// First seed to use.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of programs to generate.
+        #[arg(long, default_value_t = 1000)]
+        count: u64,
+    },
+
+    /// Shrink a program while preserving a property (given by exactly one of the
+    /// options) and print the result.
+    Reduce {
+        path: String,
+
+        /// The type error must contain this text (e.g. a judgment name).
+        #[arg(long)]
+        error_contains: Option<String>,
+
+        /// The interpreter must fault with a message containing this text.
+        #[arg(long)]
+        fault_contains: Option<String>,
+
+        /// The program must type check and then fault in the interpreter.
+        #[arg(long)]
+        unsound: bool,
+    },
+}
+
+/// Limits on a run of the interpreter, so that a program that never
+/// terminates faults instead of hanging.
+#[derive(clap::Args, Debug)]
+struct Limits {
+    /// Fault after evaluating this many statements.
+    #[arg(long, default_value_t = interpreter::DEFAULT_FUEL)]
+    fuel: usize,
+
+    /// Fault when method calls are nested more deeply than this.
+    #[arg(long, default_value_t = interpreter::DEFAULT_MAX_CALL_DEPTH)]
+    max_call_depth: usize,
+}
+
+impl Limits {
+    fn interpreter(&self, program: ElaboratedProgram) -> interpreter::Interpreter {
+        let mut interpreter = interpreter::Interpreter::new(program);
+        interpreter.set_fuel(self.fuel);
+        interpreter.set_max_call_depth(self.max_call_depth);
+        interpreter
+    }
 }
 
 pub fn main() -> Fallible<()> {
     let args = Args::try_parse()?;
+    // The interpreter recurses on the Rust stack (see `interpreter::with_stack`).
+    interpreter::with_stack(|| run(&args))
+}
 
-    for path in &args.paths {
-        check_file(path)?;
+fn run(args: &Args) -> Fallible<()> {
+    match &args.command {
+        Some(Command::Explain { path }) => explain_file(path)?,
+        Some(Command::Mir { path }) => mir_file(path)?,
+        Some(Command::Debug { path, limits }) => debug_file(path, limits)?,
+        Some(Command::Audit { path, limits }) => audit_file(path, limits)?,
+        Some(Command::Graph { path, dot, limits }) => graph_file(path, *dot, limits)?,
+        Some(Command::Events {
+            path,
+            output,
+            limits,
+        }) => events_file(path, output.as_deref(), limits)?,
+        #[cfg(feature = "lsp")]
+        Some(Command::Lsp) => lsp::run()?,
+        Some(Command::Fuzz { seed, count }) => run_fuzzer(*seed, *count)?,
+        Some(Command::Reduce {
+            path,
+            error_contains,
+            fault_contains,
+            unsound,
+        }) => {
+            let predicate = match (error_contains, fault_contains, unsound) {
+                (Some(text), None, false) => reduce::Predicate::TypeErrorContains(text.clone()),
+                (None, Some(text), false) => reduce::Predicate::FaultContains(text.clone()),
+                (None, None, true) => reduce::Predicate::Unsound,
+                _ => anyhow::bail!(
+                    "give exactly one of `--error-contains`, `--fault-contains` and `--unsound`"
+                ),
+            };
+            reduce_file(path, &predicate)?
+        }
+        None => match &args.profile {
+            Some(profile_path) => {
+                // Profile every file, and write out the profile before reporting
+                // any type errors: a failing program is often the one worth profiling.
+                let profiler = profile::Profiler::new();
+                let results: Vec<Fallible<()>> = args
+                    .paths
+                    .iter()
+                    .map(|path| profile_file(&profiler, path))
+                    .collect();
+                eprint!("{}", profiler.report());
+                std::fs::write(profile_path, profiler.folded_stacks())?;
+                results.into_iter().collect::<Fallible<()>>()?;
+            }
+            None => {
+                for path in &args.paths {
+                    check_file(path, args.memo_stats)?;
+                }
+            }
+        },
     }
 
     Ok(())
 }
 
+/// Parse and elaborate the program in `path`.
+fn read_program(path: &str) -> Fallible<ElaboratedProgram> {
+    let text: String = std::fs::read_to_string(path)?;
+    let program: Arc<Program> = dada_lang::try_term(&text)?;
+    Ok(ElaboratedProgram::elaborate(&program))
+}
+
 #[context("check input file `{path:?}`")]
-fn check_file(path: &str) -> Fallible<()> {
+fn check_file(path: &str, memo_stats: bool) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    let report = type_system::check_program_all(&elaborated);
+    if memo_stats {
+        eprint!("{}", report.memo);
+    }
+    report.into_result()
+}
+
+#[context("profile input file `{path:?}`")]
+fn profile_file(profiler: &profile::Profiler, path: &str) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    profiler.check_program(&elaborated)
+}
+
+#[context("explain input file `{path:?}`")]
+fn explain_file(path: &str) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    let root = match type_system::check_program(&elaborated).into_map() {
+        Ok(proofs) => match proofs.into_values().next() {
+            Some(proof_tree) => explain::ExplainNode::from_proof_tree(&proof_tree),
+            None => anyhow::bail!("type checker produced no derivation"),
+        },
+        Err(failed) => explain::ExplainNode::from_failed_judgment(&failed),
+    };
+    if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
+        explain::tui::run(root)
+    } else {
+        explain::Explorer::new(root).run(std::io::stdin().lock(), std::io::stdout())
+    }
+}
+
+#[context("lower input file `{path:?}`")]
+fn mir_file(path: &str) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    for body in mir::lower_program(&elaborated) {
+        println!("{body}");
+    }
+    Ok(())
+}
+
+#[context("debug input file `{path:?}`")]
+fn debug_file(path: &str, limits: &Limits) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    let mut interpreter = limits.interpreter(elaborated.clone());
+    interpreter.set_debugger(interpreter::debugger::Debugger::new(
+        std::io::stdin().lock(),
+        std::io::stdout(),
+    ));
+    let result = interpreter.interpret()?;
+    let env = type_system::env::Env::new(elaborated);
+    println!("{}", interpreter.display_value(&env, &result)?);
+    Ok(())
+}
+
+#[context("audit input file `{path:?}`")]
+fn audit_file(path: &str, limits: &Limits) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    let mut interpreter = limits.interpreter(elaborated.clone());
+    let result = interpreter.interpret();
+    match &result {
+        Ok(value) => {
+            let env = type_system::env::Env::new(elaborated);
+            println!("{}", interpreter.display_value(&env, value)?);
+        }
+        Err(e) => println!("fault: {e:#}"),
+    }
+    let audit = interpreter.audit(result.as_ref().ok());
+    print!("{audit}");
+    if !audit.is_clean() {
+        anyhow::bail!("the heap audit found {} problem(s)", audit.problems.len());
+    }
+    Ok(())
+}
+
+#[context("graph input file `{path:?}`")]
+fn graph_file(path: &str, dot: bool, limits: &Limits) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    let mut interpreter = limits.interpreter(elaborated.clone());
+    let result = interpreter.interpret()?;
+    let env = type_system::env::Env::new(elaborated);
+    let graph = interpreter::heap_graph::HeapGraph::new(
+        &interpreter,
+        &env,
+        &[("result".to_string(), result)],
+    )?;
+    let format = if dot {
+        interpreter::heap_graph::GraphFormat::Dot
+    } else {
+        interpreter::heap_graph::GraphFormat::Mermaid
+    };
+    print!("{}", graph.render(format));
+    Ok(())
+}
+
+#[context("write events for input file `{path:?}`")]
+fn events_file(path: &str, output: Option<&Path>, limits: &Limits) -> Fallible<()> {
+    let elaborated = read_program(path)?;
+    let mut interpreter = limits.interpreter(elaborated.clone());
+    match output {
+        Some(output) => {
+            let file = std::fs::File::create(output)?;
+            interpreter.set_event_sink(std::io::BufWriter::new(file));
+        }
+        None => interpreter.set_event_sink(std::io::stdout()),
+    }
+    // The events may be going to stdout, so report the result on stderr.
+    let result = interpreter.interpret()?;
+    let env = type_system::env::Env::new(elaborated);
+    eprintln!("{}", interpreter.display_value(&env, &result)?);
+    Ok(())
+}
+
+#[context("reduce input file `{path:?}`")]
+fn reduce_file(path: &str, predicate: &reduce::Predicate) -> Fallible<()> {
     let text: String = std::fs::read_to_string(path)?;
     let program: Arc<Program> = dada_lang::try_term(&text)?;
-    let elaborated = ElaboratedProgram::elaborate(&program);
-    let ((), _proof_tree) = type_system::check_program(&elaborated).into_singleton()?;
+    if !predicate.holds(&program) {
+        anyhow::bail!("the program does not satisfy the predicate ({predicate})");
+    }
+    let reduced = reduce::reduce(&program, |program| predicate.holds(program));
+    println!("{reduced:?}");
+    Ok(())
+}
+
+fn run_fuzzer(seed: u64, count: u64) -> Fallible<()> {
+    let summary = fuzz::fuzz(seed..seed + count);
+    for bug in &summary.bugs {
+        println!("{bug}");
+    }
+    print!("{summary}");
+    if !summary.bugs.is_empty() {
+        anyhow::bail!("found {} soundness bug(s)", summary.bugs.len());
+    }
     Ok(())
 }
@@ -0,0 +1,507 @@
+//! A language server for `.dada` files (`dada-model lsp`), speaking LSP over
+//! stdin and stdout.
+//!
+//! Each open file is a [`Document`], which answers queries in terms of byte
+//! offsets; [`run`] converts to and from LSP positions. The parser does not
+//! record spans, so declarations and statements are found in the text by
+//! [`Outline`], and types come from the last version of the file that parsed.
+//! The server provides:
+//!
+//! * diagnostics for each class or method that fails to type check, with the
+//!   leaves of the failed judgment;
+//! * hover on a local variable, showing its type in the env after the
+//!   top-level statement under the cursor (see [`method_envs`]);
+//! * go-to-definition for classes, and for fields and methods after a `.`;
+//! * completion of field and method names (and accesses such as `give`) after a `.`.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, GotoDefinition, HoverRequest, Request as LspRequest};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, DiagnosticSeverity,
+    GotoDefinitionParams, Hover, HoverContents, HoverParams, HoverProviderCapability, Location,
+    MarkupContent, MarkupKind, OneOf, Position, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+
+use crate::dada_lang;
+use crate::elaborator::ElaboratedProgram;
+use crate::grammar::{MethodId, NamedTy, Parameter, Program, Ty, TypeName, ValueId};
+use crate::outline::{tokenize, Outline, Token};
+use crate::type_system::{self, env::Env, locals::method_envs};
+
+#[cfg(test)]
+mod tests;
+
+/// Accesses that can follow a `.`, offered along with fields and methods.
+const ACCESSES: &[&str] = &["give", "ref", "mut", "share", "drop"];
+
+/// A problem to report in a [`Document`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    Field,
+    Method,
+    Access,
+}
+
+/// A name to offer after a `.`, with its type for fields.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CompletionEntry {
+    pub label: String,
+    pub kind: CompletionKind,
+    pub detail: Option<String>,
+}
+
+/// The text of an open file, and the last version of it that parsed.
+pub struct Document {
+    text: String,
+    outline: Outline,
+    program: Option<ElaboratedProgram>,
+    parse_error: Option<String>,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        let mut document = Document {
+            text: String::new(),
+            outline: Outline::default(),
+            program: None,
+            parse_error: None,
+        };
+        document.update(text);
+        document
+    }
+
+    /// Replace the text. If it does not parse, the previous program is kept
+    /// for hover, definitions and completion.
+    pub fn update(&mut self, text: String) {
+        match dada_lang::try_term::<Arc<Program>>(&text) {
+            Ok(program) => {
+                self.program = Some(ElaboratedProgram::elaborate(&program));
+                self.parse_error = None;
+            }
+            Err(e) => self.parse_error = Some(format!("{e:#}")),
+        }
+        self.outline = Outline::new(&text);
+        self.text = text;
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The parse error, or a diagnostic on the name of each class (or method)
+    /// that fails to type check.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        if let Some(message) = &self.parse_error {
+            return vec![Diagnostic {
+                span: 0..0,
+                message: message.clone(),
+            }];
+        }
+        let Some(program) = &self.program else {
+            return vec![];
+        };
+
+        let report = type_system::check_program_all(program);
+        let mut diagnostics = vec![];
+        for failure in &report.failures {
+            let class = self.outline.class(&format!("{:?}", failure.class));
+            if failure.methods.is_empty() {
+                diagnostics.push(Diagnostic {
+                    span: class.map_or(0..0, |class| class.span.clone()),
+                    message: failure.error.format_leaves(),
+                });
+            }
+            for (method_name, error) in &failure.methods {
+                let method_name = format!("{method_name:?}");
+                let method = class.and_then(|class| {
+                    class
+                        .methods
+                        .iter()
+                        .find(|method| method.name == method_name)
+                });
+                diagnostics.push(Diagnostic {
+                    span: method.map_or(0..0, |method| method.span.clone()),
+                    message: error.format_leaves(),
+                });
+            }
+        }
+        diagnostics
+    }
+
+    /// The type of the local variable under `offset`.
+    pub fn hover(&self, offset: usize) -> Option<String> {
+        let tokens = tokenize(&self.text);
+        let index = token_at(&tokens, offset)?;
+        let name = &tokens[index].text;
+        if index > 0 && tokens[index - 1].text == "." {
+            return None;
+        }
+        let env = self.env_at(tokens[index].span.start)?;
+        let var = env
+            .local_variable_names()
+            .into_iter()
+            .find(|var| format!("{var:?}") == *name)?;
+        let ty = env.var_ty(&var).ok()?;
+        Some(format!("```dada\n{name}: {ty:?}\n```"))
+    }
+
+    /// The declaration of the class, field or method named under `offset`.
+    pub fn definition(&self, offset: usize) -> Option<Range<usize>> {
+        let tokens = tokenize(&self.text);
+        let index = token_at(&tokens, offset)?;
+        let name = &tokens[index].text;
+
+        if index > 0 && tokens[index - 1].text == "." {
+            let (class_name, _) = self.receiver_class(&tokens, index - 1)?;
+            let class = self.outline.class(&format!("{class_name:?}"))?;
+            let field = class.fields.iter().find(|field| field.name == *name);
+            let method = class.methods.iter().find(|method| method.name == *name);
+            return field
+                .map(|field| field.span.clone())
+                .or_else(|| method.map(|method| method.span.clone()));
+        }
+
+        let program = self.program.as_ref()?;
+        let class_name: ValueId = dada_lang::try_term(name).ok()?;
+        program.class_named(&class_name).ok()?;
+        Some(self.outline.class(name)?.span.clone())
+    }
+
+    /// The fields, methods and accesses that can follow the `.` before
+    /// `offset` (possibly followed by the start of a name).
+    pub fn completions(&self, offset: usize) -> Vec<CompletionEntry> {
+        let tokens = tokenize(&self.text[..offset]);
+        let mut dot = tokens.len().saturating_sub(1);
+        if tokens.get(dot).is_some_and(|token| token.is_ident()) {
+            dot = dot.saturating_sub(1);
+        }
+        if !tokens.get(dot).is_some_and(|token| token.text == ".") {
+            return vec![];
+        }
+        let Some((class_name, parameters)) = self.receiver_class(&tokens, dot) else {
+            return vec![];
+        };
+        let Some((fields, methods)) = self.members(&class_name, &parameters) else {
+            return vec![];
+        };
+
+        let fields = fields.into_iter().map(|(name, ty)| CompletionEntry {
+            label: name,
+            kind: CompletionKind::Field,
+            detail: Some(ty),
+        });
+        let methods = methods.into_iter().map(|name| CompletionEntry {
+            label: name,
+            kind: CompletionKind::Method,
+            detail: None,
+        });
+        let accesses = ACCESSES.iter().map(|access| CompletionEntry {
+            label: access.to_string(),
+            kind: CompletionKind::Access,
+            detail: None,
+        });
+        fields.chain(methods).chain(accesses).collect()
+    }
+
+    /// The env after the top-level statement that contains `offset`, or after
+    /// the last statement that type checks if that one does not.
+    fn env_at(&self, offset: usize) -> Option<Env> {
+        let program = self.program.as_ref()?;
+        let (class, method, statement) = self.outline.statement_at(offset)?;
+        let class_name: ValueId = dada_lang::try_term(&class.name).ok()?;
+        let method_name: MethodId = dada_lang::try_term(&method.name).ok()?;
+        let mut envs = method_envs(program, &class_name, &method_name).ok()?;
+        envs.truncate(statement + 2);
+        envs.pop()
+    }
+
+    /// The class (and its parameters) of the place that ends just before
+    /// `tokens[dot]`, a `.`: a local variable followed by any number of `.field`s.
+    fn receiver_class(&self, tokens: &[Token], dot: usize) -> Option<(ValueId, Vec<Parameter>)> {
+        let mut start = dot.checked_sub(1)?;
+        while start >= 2 && tokens[start - 1].text == "." && tokens[start - 2].is_ident() {
+            start -= 2;
+        }
+        let path: Vec<&str> = tokens[start..dot]
+            .iter()
+            .filter(|token| token.text != ".")
+            .map(|token| token.text.as_str())
+            .collect();
+        let (var_name, fields) = path.split_first()?;
+
+        let env = self.env_at(tokens[start].span.start)?;
+        let var = env
+            .local_variable_names()
+            .into_iter()
+            .find(|var| format!("{var:?}") == *var_name)?;
+        let mut ty = env.var_ty(&var).ok()?.clone();
+        for field_name in fields {
+            let (class_name, parameters) = class_of(&ty)?;
+            let class_decl = self.program.as_ref()?.class_named(&class_name).ok()?;
+            let class_data = class_decl.binder.instantiate_with(&parameters).ok()?;
+            let field = class_data
+                .fields
+                .iter()
+                .find(|field| format!("{:?}", field.name) == *field_name)?;
+            ty = field.ty.clone();
+        }
+        class_of(&ty)
+    }
+
+    /// The fields (with their types) and methods of `class_name[parameters]`.
+    fn members(
+        &self,
+        class_name: &ValueId,
+        parameters: &[Parameter],
+    ) -> Option<(Vec<(String, String)>, Vec<String>)> {
+        let class_decl = self.program.as_ref()?.class_named(class_name).ok()?;
+        let class_data = class_decl.binder.instantiate_with(parameters).ok()?;
+        let fields = class_data
+            .fields
+            .iter()
+            .map(|field| (format!("{:?}", field.name), format!("{:?}", field.ty)))
+            .collect();
+        let methods = class_data
+            .methods
+            .iter()
+            .map(|method| format!("{:?}", method.name))
+            .collect();
+        Some((fields, methods))
+    }
+}
+
+/// The class that `ty` is an instance of, ignoring its permissions.
+fn class_of(ty: &Ty) -> Option<(ValueId, Vec<Parameter>)> {
+    match ty.strip_perm().to_named_ty()? {
+        NamedTy {
+            name: TypeName::Id(class_name),
+            parameters,
+        } => Some((class_name, parameters)),
+        _ => None,
+    }
+}
+
+/// The index of the identifier token that contains `offset` (or ends at it).
+fn token_at(tokens: &[Token], offset: usize) -> Option<usize> {
+    tokens.iter().position(|token| {
+        token.is_ident() && token.span.start <= offset && offset <= token.span.end
+    })
+}
+
+/// The LSP position of byte `offset` in `text`. LSP counts columns in UTF-16
+/// code units.
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position::new(
+        before.matches('\n').count() as u32,
+        before[line_start..].encode_utf16().count() as u32,
+    )
+}
+
+/// The byte offset of LSP `position` in `text`, clamped to the end of its line.
+fn offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let mut units = 0;
+    for (i, ch) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + i;
+        }
+        units += ch.len_utf16();
+    }
+    line_start + line.len()
+}
+
+fn range(text: &str, span: &Range<usize>) -> lsp_types::Range {
+    lsp_types::Range::new(position(text, span.start), position(text, span.end))
+}
+
+/// Serve requests on stdin and stdout until the client shuts the server down.
+pub fn run() -> anyhow::Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![".".to_string()]),
+            ..CompletionOptions::default()
+        }),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    let mut documents: HashMap<Url, Document> = HashMap::new();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    break;
+                }
+                let response = handle_request(&documents, request);
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                if let Some(uri) = handle_notification(&mut documents, notification)? {
+                    let diagnostics = match documents.get(&uri) {
+                        Some(document) => lsp_diagnostics(document),
+                        None => vec![],
+                    };
+                    let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+                    connection
+                        .sender
+                        .send(Message::Notification(Notification::new(
+                            PublishDiagnostics::METHOD.to_string(),
+                            params,
+                        )))?;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    io_threads.join()?;
+    Ok(())
+}
+
+/// Apply an open, change or close notification, returning the file whose
+/// diagnostics need to be published again.
+fn handle_notification(
+    documents: &mut HashMap<Url, Document>,
+    notification: Notification,
+) -> anyhow::Result<Option<Url>> {
+    match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: <DidOpenTextDocument as LspNotification>::Params =
+                serde_json::from_value(notification.params)?;
+            let document = params.text_document;
+            documents.insert(document.uri.clone(), Document::new(document.text));
+            Ok(Some(document.uri))
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: <DidChangeTextDocument as LspNotification>::Params =
+                serde_json::from_value(notification.params)?;
+            let uri = params.text_document.uri;
+            // With full sync, the last change holds the whole text.
+            let (Some(document), Some(change)) = (
+                documents.get_mut(&uri),
+                params.content_changes.into_iter().last(),
+            ) else {
+                return Ok(None);
+            };
+            document.update(change.text);
+            Ok(Some(uri))
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: <DidCloseTextDocument as LspNotification>::Params =
+                serde_json::from_value(notification.params)?;
+            documents.remove(&params.text_document.uri);
+            Ok(Some(params.text_document.uri))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn handle_request(documents: &HashMap<Url, Document>, request: Request) -> Response {
+    let id = request.id.clone();
+    let result = match request.method.as_str() {
+        HoverRequest::METHOD => serde_json::from_value(request.params)
+            .map(|params: HoverParams| {
+                let position = params.text_document_position_params;
+                let document = documents.get(&position.text_document.uri)?;
+                let offset = offset(document.text(), position.position);
+                Some(Hover {
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: document.hover(offset)?,
+                    }),
+                    range: None,
+                })
+            })
+            .map(|hover| serde_json::to_value(hover).unwrap()),
+        GotoDefinition::METHOD => serde_json::from_value(request.params)
+            .map(|params: GotoDefinitionParams| {
+                let position = params.text_document_position_params;
+                let uri = position.text_document.uri;
+                let document = documents.get(&uri)?;
+                let span = document.definition(offset(document.text(), position.position))?;
+                Some(Location::new(uri, range(document.text(), &span)))
+            })
+            .map(|location| serde_json::to_value(location).unwrap()),
+        Completion::METHOD => serde_json::from_value(request.params)
+            .map(|params: CompletionParams| {
+                let position = params.text_document_position;
+                let Some(document) = documents.get(&position.text_document.uri) else {
+                    return vec![];
+                };
+                let offset = offset(document.text(), position.position);
+                document
+                    .completions(offset)
+                    .into_iter()
+                    .map(completion_item)
+                    .collect::<Vec<_>>()
+            })
+            .map(|items| serde_json::to_value(items).unwrap()),
+        method => {
+            return Response::new_err(
+                id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request `{method}`"),
+            );
+        }
+    };
+    match result {
+        Ok(result) => Response::new_ok(id, result),
+        Err(e) => Response::new_err(id, ErrorCode::InvalidParams as i32, e.to_string()),
+    }
+}
+
+fn lsp_diagnostics(document: &Document) -> Vec<lsp_types::Diagnostic> {
+    document
+        .diagnostics()
+        .into_iter()
+        .map(|diagnostic| lsp_types::Diagnostic {
+            range: range(document.text(), &diagnostic.span),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some("dada-model".to_string()),
+            message: diagnostic.message,
+            ..lsp_types::Diagnostic::default()
+        })
+        .collect()
+}
+
+fn completion_item(entry: CompletionEntry) -> CompletionItem {
+    let kind = match entry.kind {
+        CompletionKind::Field => CompletionItemKind::FIELD,
+        CompletionKind::Method => CompletionItemKind::METHOD,
+        CompletionKind::Access => CompletionItemKind::KEYWORD,
+    };
+    CompletionItem {
+        label: entry.label,
+        kind: Some(kind),
+        detail: entry.detail,
+        ..CompletionItem::default()
+    }
+}
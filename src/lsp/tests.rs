@@ -0,0 +1,96 @@
+use lsp_types::Position;
+
+use super::{offset, position, CompletionKind, Document};
+
+const POINT: &str = "class Point {
+    x: Int;
+    y: Int;
+}
+
+class Main {
+    fn main(given self) -> Int {
+        let p = new Point(22, 44);
+        p.x.give;
+    }
+}
+";
+
+/// The offset of the `n`th occurrence of `needle` in `text`, plus `delta`.
+fn at(text: &str, needle: &str, n: usize, delta: usize) -> usize {
+    text.match_indices(needle).nth(n).unwrap().0 + delta
+}
+
+#[test]
+fn hover_shows_the_type_of_a_local() {
+    let document = Document::new(POINT.to_string());
+    let hover = document.hover(at(POINT, "p.x", 0, 0)).unwrap();
+    assert_eq!(hover, "```dada\np: Point\n```");
+    assert_eq!(document.hover(at(POINT, "Point", 1, 0)), None);
+}
+
+#[test]
+fn definition_of_classes_and_fields() {
+    let document = Document::new(POINT.to_string());
+    let class = document.definition(at(POINT, "Point", 1, 2)).unwrap();
+    assert_eq!(class, at(POINT, "Point", 0, 0)..at(POINT, "Point", 0, 5));
+
+    let field = document.definition(at(POINT, "p.x", 0, 2)).unwrap();
+    assert_eq!(field, at(POINT, "x:", 0, 0)..at(POINT, "x:", 0, 1));
+}
+
+#[test]
+fn completion_after_a_dot() {
+    let document = Document::new(POINT.to_string());
+    let completions = document.completions(at(POINT, "p.x", 0, 2));
+    let fields: Vec<&str> = completions
+        .iter()
+        .filter(|entry| entry.kind == CompletionKind::Field)
+        .map(|entry| entry.label.as_str())
+        .collect();
+    assert_eq!(fields, vec!["x", "y"]);
+    assert_eq!(completions[0].detail.as_deref(), Some("Int"));
+    assert!(completions.iter().any(|entry| entry.label == "give"));
+
+    // The previous program is used while the text does not parse.
+    let mut document = document;
+    let text = POINT.replace("p.x.give;", "p.");
+    document.update(text.clone());
+    assert_eq!(document.completions(at(&text, "p.", 0, 2)).len(), 7);
+    assert!(document.completions(at(&text, "let", 0, 3)).is_empty());
+}
+
+#[test]
+fn diagnostics_point_at_the_failing_method() {
+    let text = "class Data { }
+class Main {
+    fn main(given self) -> Data {
+        let d = new Data();
+        d.give;
+        d.give;
+    }
+}
+";
+    let document = Document::new(text.to_string());
+    let diagnostics = document.diagnostics();
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(&text[diagnostics[0].span.clone()], "main");
+    assert!(
+        diagnostics[0].message.contains("prove_copy_predicate"),
+        "{diagnostics:?}"
+    );
+
+    assert!(Document::new(POINT.to_string()).diagnostics().is_empty());
+    let diagnostics = Document::new("class {".to_string()).diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span, 0..0);
+}
+
+#[test]
+fn positions_count_utf16_units() {
+    let text = "ab\nc🙂d\n";
+    let d = text.find('d').unwrap();
+    assert_eq!(position(text, d), Position::new(1, 3));
+    assert_eq!(offset(text, Position::new(1, 3)), d);
+    assert_eq!(offset(text, Position::new(0, 99)), 2);
+    assert_eq!(offset(text, Position::new(9, 0)), text.len());
+}
@@ -0,0 +1,294 @@
+//! A MIR-like control-flow-graph IR for method bodies.
+//!
+//! Method bodies are lowered (see [`lower`]) from the tree-shaped [`Block`] AST
+//! into a list of basic blocks. Compared to the AST:
+//!
+//! * every intermediate value is stored in an explicit temporary (`_0`, `_1`, ...),
+//!   so there is no need for `Var::Fresh` or `Var::InFlight`;
+//! * the drops performed when a block scope ends, when a statement's value is
+//!   discarded, or when control leaves a scope through `break` or `return` are
+//!   explicit `drop` statements;
+//! * `if`, `loop`, `break`, `return` and `!` become terminators (`if`, `goto`,
+//!   `return`, `panic`).
+//!
+//! The [`Display`](std::fmt::Display) impls pretty-print the IR, which is what
+//! `dada-model mir` shows.
+//!
+//! The IR is only for showing control flow and drop placement. Neither the type
+//! checker nor the interpreter consumes it: both still walk the AST, and the
+//! liveness analysis builds its own graph over the AST (see
+//! `type_system::liveness`). Moving either of them onto the IR is left for later.
+//!
+//! [`Block`]: crate::grammar::Block
+
+use std::fmt;
+
+use crate::elaborator::ElaboratedProgram;
+use crate::grammar::{
+    Access, BasicBlockId, BinaryOp, ClassDecl, ClassDeclBoundData, MethodBody, MethodDecl,
+    MethodDeclBoundData, MethodId, Parameter, Projection, ValueId, Var,
+};
+use crate::type_system::env::Env;
+
+pub mod lower;
+
+#[cfg(test)]
+mod tests;
+
+/// The IR for one method body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MirBody {
+    /// Name of the method, e.g. `Main.main`.
+    pub name: String,
+
+    /// `self` followed by the method's inputs.
+    pub params: Vec<Var>,
+
+    /// Number of temporaries used by the body.
+    pub temps: usize,
+
+    /// The basic blocks; execution starts in the first one.
+    pub blocks: Vec<BasicBlockData>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasicBlockData {
+    pub id: BasicBlockId,
+    pub statements: Vec<MirStatement>,
+    pub terminator: Terminator,
+}
+
+/// A local slot: either a variable from the source or a temporary introduced by lowering.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Local {
+    Var(Var),
+    Temp(usize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MirPlace {
+    pub local: Local,
+    pub projections: Vec<Projection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operand {
+    /// Access a place, e.g. `x.f.ref` or `_3.give`.
+    Place(MirPlace, Access),
+    Integer(usize),
+    Bool(bool),
+    Unit,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rvalue {
+    Use(Operand),
+    BinaryOp(BinaryOp, Operand, Operand),
+    Tuple(Vec<Operand>),
+    Share(Operand),
+    Call(Operand, MethodId, Vec<Parameter>, Vec<Operand>),
+    New(ValueId, Vec<Parameter>, Vec<Operand>),
+    SizeOf(Vec<Parameter>),
+    ArrayNew(Vec<Parameter>, Operand),
+    ArrayCapacity(Vec<Parameter>, Operand),
+    ArrayGive(Vec<Parameter>, Operand, Operand),
+    ArrayDrop(Vec<Parameter>, Operand, Operand, Operand),
+    ArrayWrite(Vec<Parameter>, Operand, Operand, Operand),
+    IsLastRef(Vec<Parameter>, Operand),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MirStatement {
+    Assign(MirPlace, Rvalue),
+    Print(Operand),
+
+    /// Drop the value stored in a local at the end of its scope,
+    /// or a discarded temporary.
+    Drop(Local),
+
+    /// `$clear(x)`: mark `x` as uninitialized without dropping it.
+    Clear(ValueId),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Terminator {
+    Goto(BasicBlockId),
+    If(Operand, BasicBlockId, BasicBlockId),
+
+    /// Return the value stored in `return`.
+    Return,
+
+    /// `!`
+    Panic,
+
+    /// The end of a block that control never reaches (e.g., after a loop without `break`).
+    Unreachable,
+}
+
+/// Lower every method of every class in `program` that has a body.
+pub fn lower_program(program: &ElaboratedProgram) -> Vec<MirBody> {
+    let mut bodies = vec![];
+    for decl in &program.decls {
+        let crate::grammar::Decl::ClassDecl(ClassDecl { name, binder, .. }) = decl;
+        let (env, _, ClassDeclBoundData { methods, .. }) =
+            Env::new(program).open_universally(binder);
+        for MethodDecl {
+            name: method_name,
+            binder,
+        } in &methods
+        {
+            let (_, _, MethodDeclBoundData { inputs, body, .. }) = env.open_universally(binder);
+            let MethodBody::Block(block) = body else {
+                continue;
+            };
+            let params = std::iter::once(Var::This)
+                .chain(inputs.iter().map(|input| Var::Id(input.name.clone())))
+                .collect();
+            bodies.push(lower::lower_body(
+                format!("{name:?}.{method_name:?}"),
+                params,
+                &block,
+            ));
+        }
+    }
+    bodies
+}
+
+impl fmt::Display for MirBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params: Vec<String> = self.params.iter().map(|p| format!("{p:?}")).collect();
+        writeln!(f, "fn {}({}) {{", self.name, params.join(", "))?;
+        for (index, block) in self.blocks.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "    {:?}: {{", block.id)?;
+            for statement in &block.statements {
+                writeln!(f, "        {statement};")?;
+            }
+            writeln!(f, "        {};", block.terminator)?;
+            writeln!(f, "    }}")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl fmt::Display for Local {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Local::Var(var) => write!(f, "{var:?}"),
+            Local::Temp(index) => write!(f, "_{index}"),
+        }
+    }
+}
+
+impl fmt::Display for MirPlace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.local)?;
+        for projection in &self.projections {
+            match projection {
+                Projection::Field(field) => write!(f, ".{field:?}")?,
+                Projection::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Place(place, access) => write!(f, "{place}.{access:?}"),
+            Operand::Integer(value) => write!(f, "{value}"),
+            Operand::Bool(value) => write!(f, "{value}"),
+            Operand::Unit => write!(f, "()"),
+        }
+    }
+}
+
+/// Format `operands` separated by commas.
+fn comma(operands: &[Operand]) -> String {
+    operands
+        .iter()
+        .map(|o| o.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format explicit generic parameters as `[P1, P2]`, or nothing if there are none.
+fn generics(parameters: &[Parameter]) -> String {
+    if parameters.is_empty() {
+        String::new()
+    } else {
+        let parameters: Vec<String> = parameters.iter().map(|p| format!("{p:?}")).collect();
+        format!("[{}]", parameters.join(", "))
+    }
+}
+
+impl fmt::Display for Rvalue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rvalue::Use(operand) => write!(f, "{operand}"),
+            Rvalue::BinaryOp(op, lhs, rhs) => write!(f, "{lhs} {op:?} {rhs}"),
+            Rvalue::Tuple(operands) => write!(f, "({})", comma(operands)),
+            Rvalue::Share(operand) => write!(f, "share({operand})"),
+            Rvalue::Call(receiver, method, parameters, args) => write!(
+                f,
+                "call {receiver}.{method:?}{}({})",
+                generics(parameters),
+                comma(args)
+            ),
+            Rvalue::New(class, parameters, args) => {
+                write!(f, "new {class:?}{}({})", generics(parameters), comma(args))
+            }
+            Rvalue::SizeOf(parameters) => write!(f, "size_of{}()", generics(parameters)),
+            Rvalue::ArrayNew(parameters, length) => {
+                write!(f, "array_new{}({length})", generics(parameters))
+            }
+            Rvalue::ArrayCapacity(parameters, array) => {
+                write!(f, "array_capacity{}({array})", generics(parameters))
+            }
+            Rvalue::ArrayGive(parameters, array, index) => {
+                write!(f, "array_give{}({array}, {index})", generics(parameters))
+            }
+            Rvalue::ArrayDrop(parameters, array, from, to) => write!(
+                f,
+                "array_drop{}({array}, {from}, {to})",
+                generics(parameters)
+            ),
+            Rvalue::ArrayWrite(parameters, array, index, value) => write!(
+                f,
+                "array_write{}({array}, {index}, {value})",
+                generics(parameters)
+            ),
+            Rvalue::IsLastRef(parameters, value) => {
+                write!(f, "is_last_ref{}({value})", generics(parameters))
+            }
+        }
+    }
+}
+
+impl fmt::Display for MirStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MirStatement::Assign(place, rvalue) => write!(f, "{place} = {rvalue}"),
+            MirStatement::Print(operand) => write!(f, "print({operand})"),
+            MirStatement::Drop(local) => write!(f, "drop({local})"),
+            MirStatement::Clear(var) => write!(f, "clear({var:?})"),
+        }
+    }
+}
+
+impl fmt::Display for Terminator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Terminator::Goto(target) => write!(f, "goto {target:?}"),
+            Terminator::If(cond, if_true, if_false) => {
+                write!(f, "if {cond} -> [true: {if_true:?}, false: {if_false:?}]")
+            }
+            Terminator::Return => write!(f, "return"),
+            Terminator::Panic => write!(f, "panic"),
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
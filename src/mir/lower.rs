@@ -0,0 +1,366 @@
+//! Lowering of method bodies from the AST into [`MirBody`].
+//!
+//! Drops follow the interpreter: each variable introduced by `let` is dropped
+//! (in reverse order of declaration) when its block ends, the value of every
+//! statement but the last one in a block is dropped, and the parameters are
+//! dropped when the method returns. Leaving scopes early through `break` or
+//! `return` drops the variables of every scope being left, and a panic (`!`)
+//! drops the variables of every enclosing scope as it unwinds.
+
+use crate::grammar::{Access, BasicBlockId, Block, Expr, Place, PlaceExpr, Statement, Var};
+
+use super::{BasicBlockData, Local, MirBody, MirPlace, MirStatement, Operand, Rvalue, Terminator};
+
+/// Lower `block`, the body of the method `name`, whose parameters are `params`.
+pub fn lower_body(name: String, params: Vec<Var>, block: &Block) -> MirBody {
+    let mut builder = Builder {
+        blocks: vec![],
+        current: None,
+        temps: 0,
+        scopes: vec![params.clone()],
+        loops: vec![],
+    };
+    builder.current = Some(builder.new_block());
+
+    let value = builder.block(block);
+    builder.assign(MirPlace::var(Var::Return), Rvalue::Use(value));
+    builder.exit_scopes(0);
+    builder.terminate(Terminator::Return);
+
+    MirBody {
+        name,
+        params,
+        temps: builder.temps,
+        blocks: builder
+            .blocks
+            .into_iter()
+            .enumerate()
+            .map(|(index, (statements, terminator))| BasicBlockData {
+                id: block_id(index),
+                statements,
+                terminator: terminator.unwrap_or(Terminator::Unreachable),
+            })
+            .collect(),
+    }
+}
+
+fn block_id(index: usize) -> BasicBlockId {
+    BasicBlockId::new(&format!("bb{index}"))
+}
+
+impl MirPlace {
+    fn var(var: Var) -> Self {
+        MirPlace {
+            local: Local::Var(var),
+            projections: vec![],
+        }
+    }
+
+    fn temp(index: usize) -> Self {
+        MirPlace {
+            local: Local::Temp(index),
+            projections: vec![],
+        }
+    }
+}
+
+impl From<&Place> for MirPlace {
+    fn from(place: &Place) -> Self {
+        let Place { var, projections } = place;
+        MirPlace {
+            local: Local::Var(var.clone()),
+            projections: projections.clone(),
+        }
+    }
+}
+
+struct LoopScope {
+    /// Block that `break` jumps to.
+    exit: usize,
+
+    /// Number of scopes that were open when the loop started.
+    depth: usize,
+}
+
+struct Builder {
+    /// Statements and terminator of each basic block, indexed by block number.
+    blocks: Vec<(Vec<MirStatement>, Option<Terminator>)>,
+
+    /// Block that code is currently being appended to, or `None` if the
+    /// current point is unreachable (e.g., after a `break`).
+    current: Option<usize>,
+
+    temps: usize,
+
+    /// Variables declared in each enclosing scope, outermost (the parameters) first.
+    scopes: Vec<Vec<Var>>,
+
+    loops: Vec<LoopScope>,
+}
+
+impl Builder {
+    fn new_block(&mut self) -> usize {
+        self.blocks.push((vec![], None));
+        self.blocks.len() - 1
+    }
+
+    fn new_temp(&mut self) -> MirPlace {
+        self.temps += 1;
+        MirPlace::temp(self.temps - 1)
+    }
+
+    fn push(&mut self, statement: MirStatement) {
+        if let Some(current) = self.current {
+            self.blocks[current].0.push(statement);
+        }
+    }
+
+    fn assign(&mut self, place: MirPlace, rvalue: Rvalue) {
+        self.push(MirStatement::Assign(place, rvalue));
+    }
+
+    /// End the current block with `terminator`; what follows is unreachable.
+    fn terminate(&mut self, terminator: Terminator) {
+        if let Some(current) = self.current.take() {
+            self.blocks[current].1 = Some(terminator);
+        }
+    }
+
+    fn goto(&mut self, target: usize) {
+        self.terminate(Terminator::Goto(block_id(target)));
+    }
+
+    /// Drop the variables of all scopes above `depth`, innermost first, without popping them.
+    fn exit_scopes(&mut self, depth: usize) {
+        let vars: Vec<Var> = self.scopes[depth..]
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev().cloned())
+            .collect();
+        for var in vars {
+            self.push(MirStatement::Drop(Local::Var(var)));
+        }
+    }
+
+    /// Lower a block expression and return its value.
+    fn block(&mut self, block: &Block) -> Operand {
+        let Block { statements } = block;
+        self.scopes.push(vec![]);
+
+        let mut value = Operand::Unit;
+        for (index, statement) in statements.iter().enumerate() {
+            if self.current.is_none() {
+                break;
+            }
+            let is_last = index + 1 == statements.len();
+            let statement_value = self.statement(statement);
+            if is_last {
+                value = statement_value;
+            } else {
+                self.discard(statement_value);
+            }
+        }
+
+        // The value may refer to variables of this scope, so move it out before they are dropped.
+        if !self.scopes.last().unwrap().is_empty() {
+            value = self.materialize(value);
+        }
+        let depth = self.scopes.len() - 1;
+        self.exit_scopes(depth);
+        self.scopes.pop();
+        value
+    }
+
+    /// Drop a value that is not used.
+    fn discard(&mut self, value: Operand) {
+        if let Operand::Place(..) = value {
+            if let Operand::Place(temp, _) = self.materialize(value) {
+                self.push(MirStatement::Drop(temp.local));
+            }
+        }
+    }
+
+    /// Store `value` in a fresh temporary, unless it is a constant or already one.
+    fn materialize(&mut self, value: Operand) -> Operand {
+        match &value {
+            Operand::Place(
+                MirPlace {
+                    local: Local::Temp(_),
+                    ..
+                },
+                _,
+            )
+            | Operand::Integer(_)
+            | Operand::Bool(_)
+            | Operand::Unit => value,
+            Operand::Place(..) => self.temp_for(Rvalue::Use(value)),
+        }
+    }
+
+    /// Assign `rvalue` to a fresh temporary and return an operand that moves it.
+    fn temp_for(&mut self, rvalue: Rvalue) -> Operand {
+        let temp = self.new_temp();
+        self.assign(temp.clone(), rvalue);
+        Operand::Place(temp, Access::Gv)
+    }
+
+    /// Lower a statement and return its value.
+    fn statement(&mut self, statement: &Statement) -> Operand {
+        match statement {
+            Statement::Expr(expr) => self.operand(expr),
+            Statement::Let(var, _ascription, expr) => {
+                let rvalue = self.rvalue(expr);
+                let var = Var::Id(var.clone());
+                self.assign(MirPlace::var(var.clone()), rvalue);
+                self.scopes.last_mut().unwrap().push(var);
+                Operand::Unit
+            }
+            Statement::Reassign(place, expr) => {
+                let rvalue = self.rvalue(expr);
+                self.assign(place.into(), rvalue);
+                Operand::Unit
+            }
+            Statement::Loop(body) => {
+                let head = self.new_block();
+                let exit = self.new_block();
+                self.goto(head);
+                self.current = Some(head);
+                self.loops.push(LoopScope {
+                    exit,
+                    depth: self.scopes.len(),
+                });
+                let value = self.block(body);
+                self.discard(value);
+                self.loops.pop();
+                self.goto(head);
+                self.current = Some(exit);
+                Operand::Unit
+            }
+            Statement::Break => {
+                if let Some(&LoopScope { exit, depth }) = self.loops.last() {
+                    self.exit_scopes(depth);
+                    self.goto(exit);
+                } else {
+                    // `break` outside of a loop: there is nowhere to go.
+                    self.terminate(Terminator::Unreachable);
+                }
+                Operand::Unit
+            }
+            Statement::Return(expr) => {
+                let rvalue = self.rvalue(expr);
+                self.assign(MirPlace::var(Var::Return), rvalue);
+                self.exit_scopes(0);
+                self.terminate(Terminator::Return);
+                Operand::Unit
+            }
+            Statement::Print(expr) => {
+                let operand = self.operand(expr);
+                self.push(MirStatement::Print(operand));
+                Operand::Unit
+            }
+        }
+    }
+
+    /// Lower `exprs` (evaluated left to right) to operands.
+    fn operands<'e>(&mut self, exprs: impl IntoIterator<Item = &'e Expr>) -> Vec<Operand> {
+        exprs.into_iter().map(|expr| self.operand(expr)).collect()
+    }
+
+    /// Lower `expr` to an operand, introducing a temporary if it is not a place or constant.
+    fn operand(&mut self, expr: &Expr) -> Operand {
+        match expr {
+            Expr::Place(PlaceExpr { place, access }) => Operand::Place(place.into(), *access),
+            Expr::Integer(value) => Operand::Integer(*value),
+            Expr::True => Operand::Bool(true),
+            Expr::False => Operand::Bool(false),
+            Expr::Tuple(exprs) if exprs.is_empty() => Operand::Unit,
+            Expr::Block(block) => self.block(block),
+            Expr::If(cond, if_true, if_false) => {
+                let cond = self.operand(cond);
+                let result = self.new_temp();
+                let true_block = self.new_block();
+                let false_block = self.new_block();
+                let join = self.new_block();
+                self.terminate(Terminator::If(
+                    cond,
+                    block_id(true_block),
+                    block_id(false_block),
+                ));
+                for (block, expr) in [(true_block, if_true), (false_block, if_false)] {
+                    self.current = Some(block);
+                    let rvalue = self.rvalue(expr);
+                    self.assign(result.clone(), rvalue);
+                    self.goto(join);
+                }
+                self.current = Some(join);
+                Operand::Place(result, Access::Gv)
+            }
+            Expr::Panic => {
+                // Like the interpreter, unwinding drops the variables of every enclosing scope.
+                self.exit_scopes(0);
+                self.terminate(Terminator::Panic);
+                Operand::Unit
+            }
+            Expr::Clear(var) => {
+                self.push(MirStatement::Clear(var.clone()));
+                Operand::Unit
+            }
+            _ => {
+                let rvalue = self.rvalue(expr);
+                self.temp_for(rvalue)
+            }
+        }
+    }
+
+    /// Lower `expr` to an rvalue.
+    fn rvalue(&mut self, expr: &Expr) -> Rvalue {
+        match expr {
+            Expr::BinaryOp(lhs, op, rhs) => {
+                let lhs = self.operand(lhs);
+                let rhs = self.operand(rhs);
+                Rvalue::BinaryOp(op.clone(), lhs, rhs)
+            }
+            Expr::Tuple(exprs) if !exprs.is_empty() => Rvalue::Tuple(self.operands(exprs)),
+            Expr::Share(expr) => Rvalue::Share(self.operand(expr)),
+            Expr::Call(receiver, method, parameters, args) => {
+                let receiver = self.operand(receiver);
+                let args = self.operands(args);
+                Rvalue::Call(receiver, method.clone(), parameters.clone(), args)
+            }
+            Expr::New(class, parameters, args) => {
+                Rvalue::New(class.clone(), parameters.clone(), self.operands(args))
+            }
+            Expr::SizeOf(parameters) => Rvalue::SizeOf(parameters.clone()),
+            Expr::ArrayNew(parameters, length) => {
+                Rvalue::ArrayNew(parameters.clone(), self.operand(length))
+            }
+            Expr::ArrayCapacity(parameters, array) => {
+                Rvalue::ArrayCapacity(parameters.clone(), self.operand(array))
+            }
+            Expr::ArrayGive(parameters, array, index) => {
+                let [array, index] = [array, index].map(|e| self.operand(e));
+                Rvalue::ArrayGive(parameters.clone(), array, index)
+            }
+            Expr::ArrayDrop(parameters, array, from, to) => {
+                let [array, from, to] = [array, from, to].map(|e| self.operand(e));
+                Rvalue::ArrayDrop(parameters.clone(), array, from, to)
+            }
+            Expr::ArrayWrite(parameters, array, index, value) => {
+                let [array, index, value] = [array, index, value].map(|e| self.operand(e));
+                Rvalue::ArrayWrite(parameters.clone(), array, index, value)
+            }
+            Expr::IsLastRef(parameters, value) => {
+                Rvalue::IsLastRef(parameters.clone(), self.operand(value))
+            }
+            Expr::Place(_)
+            | Expr::Integer(_)
+            | Expr::True
+            | Expr::False
+            | Expr::Tuple(_)
+            | Expr::Block(_)
+            | Expr::If(..)
+            | Expr::Panic
+            | Expr::Clear(_) => Rvalue::Use(self.operand(expr)),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::test_util::parse_program;
+
+use super::lower_program;
+
+fn mir(program: &str) -> String {
+    lower_program(&parse_program(&[program]))
+        .iter()
+        .map(|body| body.to_string())
+        .collect()
+}
+
+#[test]
+fn straight_line() {
+    expect_test::expect![[r#"
+        fn Main.main(self) {
+            bb0: {
+                p = new Point(22, 44);
+                print(p.x.ref);
+                _0 = p.x.give;
+                drop(p);
+                return = _0.give;
+                drop(self);
+                return;
+            }
+        }
+    "#]]
+    .assert_eq(&mir("
+        class Point { x: Int; y: Int; }
+
+        class Main {
+            fn main(given self) -> Int {
+                let p = new Point(22, 44);
+                print(p.x.ref);
+                p.x.give;
+            }
+        }
+    "));
+}
+
+/// `break` drops the variables of the loop body before leaving it,
+/// and the value of the `if` statement is dropped.
+#[test]
+fn loop_with_break() {
+    expect_test::expect![[r#"
+        fn Main.main(self) {
+            bb0: {
+                i = 0;
+                goto bb1;
+            }
+
+            bb1: {
+                j = i.give + 1;
+                _0 = j.give >= 3;
+                if _0.give -> [true: bb3, false: bb4];
+            }
+
+            bb2: {
+                drop(i);
+                return = ();
+                drop(self);
+                return;
+            }
+
+            bb3: {
+                drop(j);
+                goto bb2;
+            }
+
+            bb4: {
+                _1 = ();
+                goto bb5;
+            }
+
+            bb5: {
+                drop(_1);
+                i = j.give;
+                drop(j);
+                goto bb1;
+            }
+        }
+    "#]]
+    .assert_eq(&mir("
+        class Main {
+            fn main(given self) {
+                let i = 0;
+                loop {
+                    let j = i.give + 1;
+                    if j.give >= 3 { break; } else { };
+                    i = j.give;
+                }
+            }
+        }
+    "));
+}
+
+/// `return` drops every variable in scope, including the parameters.
+#[test]
+fn early_return() {
+    expect_test::expect![[r#"
+        fn Main.pick(self, a, b) {
+            bb0: {
+                if a.give -> [true: bb1, false: bb2];
+            }
+
+            bb1: {
+                return = b.give;
+                drop(b);
+                drop(a);
+                drop(self);
+                return;
+            }
+
+            bb2: {
+                _0 = ();
+                goto bb3;
+            }
+
+            bb3: {
+                drop(_0);
+                return = 0;
+                drop(b);
+                drop(a);
+                drop(self);
+                return;
+            }
+        }
+    "#]]
+    .assert_eq(&mir("
+        class Main {
+            fn pick(given self, a: Bool, b: Int) -> Int {
+                if a.give { return b.give; } else { };
+                0;
+            }
+        }
+    "));
+}
+
+/// A panic drops every variable in scope as it unwinds, like the interpreter does.
+#[test]
+fn panic_drops_variables_in_scope() {
+    expect_test::expect![[r#"
+        fn Main.main(self) {
+            bb0: {
+                p = 22;
+                drop(p);
+                drop(self);
+                panic;
+            }
+        }
+    "#]]
+    .assert_eq(&mir("
+        class Main {
+            fn main(given self) -> Int {
+                let p = 22;
+                let q: Int = !;
+                q.give;
+            }
+        }
+    "));
+}
@@ -0,0 +1,214 @@
+//! Where the classes, fields, methods and statements of a program are in its text.
+//!
+//! The parser does not record spans, so the language server and the `tests/ui`
+//! harness find declarations with a small lexer instead. It only relies on the
+//! shape of the grammar (`class Name ... { fields methods drop }`, `name: Ty;`,
+//! `fn name(...) ... { ... }`, statements ending in `;`), so it also works on
+//! text that does not parse, e.g., while a field access is being typed.
+
+use std::ops::Range;
+
+/// A token: an identifier or number, or a single punctuation character.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub text: String,
+    pub span: Range<usize>,
+}
+
+impl Token {
+    pub fn is_ident(&self) -> bool {
+        self.text
+            .starts_with(|c: char| c.is_alphabetic() || c == '_')
+    }
+}
+
+/// Split `text` into tokens, skipping whitespace and `//` comments.
+pub(crate) fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            continue;
+        }
+        if ch == '/' && text[start..].starts_with("//") {
+            while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+            continue;
+        }
+        let mut end = start + ch.len_utf8();
+        if ch.is_alphanumeric() || ch == '_' {
+            while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_alphanumeric() || c == '_') {
+                end = i + c.len_utf8();
+            }
+        }
+        tokens.push(Token {
+            text: text[start..end].to_string(),
+            span: start..end,
+        });
+    }
+    tokens
+}
+
+/// The index of the token that closes the `open` token at `tokens[start]`.
+fn matching(tokens: &[Token], start: usize, open: &str, close: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        if token.text == open {
+            depth += 1;
+        } else if token.text == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Outline {
+    pub classes: Vec<ClassOutline>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ClassOutline {
+    pub name: String,
+    /// The span of the name in `class Name`.
+    pub span: Range<usize>,
+    pub fields: Vec<FieldOutline>,
+    pub methods: Vec<MethodOutline>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct FieldOutline {
+    pub name: String,
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct MethodOutline {
+    pub name: String,
+    pub span: Range<usize>,
+    /// The top-level statements of the body, from their first token through
+    /// their `;` (or the `}` of a `loop`). Empty for trusted methods.
+    pub statements: Vec<Range<usize>>,
+}
+
+impl Outline {
+    pub fn new(text: &str) -> Self {
+        let tokens = tokenize(text);
+        let mut classes = vec![];
+        let mut i = 0;
+        while i + 1 < tokens.len() {
+            if tokens[i].text == "class" && tokens[i + 1].is_ident() {
+                let (class, next) = class_outline(&tokens, i + 1);
+                classes.push(class);
+                i = next;
+            } else {
+                i += 1;
+            }
+        }
+        Outline { classes }
+    }
+
+    pub fn class(&self, name: &str) -> Option<&ClassOutline> {
+        self.classes.iter().find(|class| class.name == name)
+    }
+
+    /// The class and method whose body contains `offset`, and the index of
+    /// the top-level statement that contains it.
+    pub fn statement_at(&self, offset: usize) -> Option<(&ClassOutline, &MethodOutline, usize)> {
+        self.classes.iter().find_map(|class| {
+            class.methods.iter().find_map(|method| {
+                let index = method
+                    .statements
+                    .iter()
+                    .position(|statement| statement.start <= offset && offset <= statement.end)?;
+                Some((class, method, index))
+            })
+        })
+    }
+}
+
+/// Outline the class whose name is `tokens[name]`, returning it along with the
+/// index of the first token after it.
+fn class_outline(tokens: &[Token], name: usize) -> (ClassOutline, usize) {
+    let mut class = ClassOutline {
+        name: tokens[name].text.clone(),
+        span: tokens[name].span.clone(),
+        fields: vec![],
+        methods: vec![],
+    };
+    let Some(open) = (name..tokens.len()).find(|&i| tokens[i].text == "{") else {
+        return (class, tokens.len());
+    };
+    let close = matching(tokens, open, "{", "}").unwrap_or(tokens.len());
+
+    let mut i = open + 1;
+    while i < close {
+        let token = &tokens[i];
+        if token.text == "fn" && i + 1 < close && tokens[i + 1].is_ident() {
+            let (method, next) = method_outline(tokens, i + 1, close);
+            class.methods.push(method);
+            i = next;
+        } else if token.text == "drop" && tokens.get(i + 1).is_some_and(|t| t.text == "{") {
+            i = matching(tokens, i + 1, "{", "}").map_or(close, |end| end + 1);
+        } else if token.is_ident() && tokens.get(i + 1).is_some_and(|t| t.text == ":") {
+            class.fields.push(FieldOutline {
+                name: token.text.clone(),
+                span: token.span.clone(),
+            });
+            i = (i..close)
+                .find(|&j| tokens[j].text == ";")
+                .map_or(close, |end| end + 1);
+        } else {
+            i += 1;
+        }
+    }
+    (class, close + 1)
+}
+
+/// Outline the method whose name is `tokens[name]`, which ends before `limit`,
+/// returning it along with the index of the first token after it.
+fn method_outline(tokens: &[Token], name: usize, limit: usize) -> (MethodOutline, usize) {
+    let mut method = MethodOutline {
+        name: tokens[name].text.clone(),
+        span: tokens[name].span.clone(),
+        statements: vec![],
+    };
+
+    // The body is the first `{`; a trusted method ends at the first `;` instead.
+    let Some(open) = (name..limit).find(|&i| matches!(&*tokens[i].text, "{" | ";")) else {
+        return (method, limit);
+    };
+    if tokens[open].text == ";" {
+        return (method, open + 1);
+    }
+    let close = matching(tokens, open, "{", "}").map_or(limit, |close| close.min(limit));
+
+    let mut start = open + 1;
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().take(close).skip(open + 1) {
+        match &*token.text {
+            "{" => depth += 1,
+            "}" => depth -= 1,
+            _ => {}
+        }
+        let ends_loop = token.text == "}" && depth == 0 && tokens[start].text == "loop";
+        if (token.text == ";" && depth == 0) || ends_loop {
+            method
+                .statements
+                .push(tokens[start].span.start..token.span.end);
+            start = i + 1;
+        }
+    }
+    // A statement that is still being typed runs to the end of the body.
+    if start < close {
+        method
+            .statements
+            .push(tokens[start].span.start..tokens[close - 1].span.end);
+    }
+    (method, close + 1)
+}
+
+#[cfg(test)]
+mod tests;
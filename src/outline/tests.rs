@@ -0,0 +1,45 @@
+use super::Outline;
+
+const POINT: &str = "class Point {
+    x: Int;
+    y: Int;
+}
+
+class Main {
+    fn main(given self) -> Int {
+        let p = new Point(22, 44);
+        p.x.give;
+    }
+}
+";
+
+#[test]
+fn outline_finds_declarations_and_statements() {
+    let outline = Outline::new(POINT);
+    let names: Vec<&str> = outline.classes.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["Point", "Main"]);
+
+    let point = outline.class("Point").unwrap();
+    let fields: Vec<&str> = point.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(fields, vec!["x", "y"]);
+
+    let main = &outline.class("Main").unwrap().methods[0];
+    assert_eq!(main.name, "main");
+    let statements: Vec<&str> = main.statements.iter().map(|s| &POINT[s.clone()]).collect();
+    assert_eq!(statements, vec!["let p = new Point(22, 44);", "p.x.give;"]);
+}
+
+#[test]
+fn outline_handles_loops_and_unfinished_statements() {
+    let text = "class Main {
+        fn main(given self) -> () {
+            loop { break; }
+            let x = 1;
+            x.
+        }
+    }";
+    let outline = Outline::new(text);
+    let main = &outline.class("Main").unwrap().methods[0];
+    let statements: Vec<&str> = main.statements.iter().map(|s| &text[s.clone()]).collect();
+    assert_eq!(statements, vec!["loop { break; }", "let x = 1;", "x."]);
+}
@@ -0,0 +1,410 @@
+//! Judgment profiler (`dada-model --profile`).
+//!
+//! Every `judgment_fn!` invocation runs inside a `tracing` span named after the
+//! judgment, whose fields are the judgment's inputs. [`Profiler`] is a `tracing`
+//! subscriber that records, for each judgment, how many times it was invoked and how
+//! much time was spent in it (total and self). It also records the time spent along
+//! each stack of nested judgments, which is written out in the "folded stacks" format
+//! understood by `flamegraph.pl` and `inferno`. Spans that do not come from this crate
+//! (e.g., formality's own bookkeeping) are ignored.
+//!
+//! Memoized judgments (see [`crate::type_system::memo`]) do not create a span when
+//! they are answered from the cache; [`memo_hit`] reports those invocations instead.
+//!
+//! Spans do not know which rule eventually succeeded, so per-rule numbers come from
+//! the proof tree of the final derivation (see [`Profiler::record_proof_tree`]). Each
+//! invocation is identified by its judgment and inputs, which is also how proof trees
+//! print their nodes; the time of the invocation that proved a node is charged to the
+//! rule the node was proven by. Invocations whose inputs appear nowhere in the final
+//! derivation are candidate derivations that were explored and then discarded.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Debug, Write as _};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use formality_core::judgment::ProofTree;
+use formality_core::{Fallible, Map, ProvenSet, Set};
+use tracing::field::{Field, Visit};
+use tracing::span;
+
+#[cfg(test)]
+mod tests;
+
+/// Target of the event emitted by [`memo_hit`].
+const MEMO_HIT_TARGET: &str = "dada_model::memo_hit";
+
+/// Statistics for one judgment.
+#[derive(Clone, Debug, Default)]
+pub struct JudgmentStats {
+    /// Number of times the judgment was invoked, including memo hits.
+    pub invocations: u64,
+
+    /// Number of invocations answered from the memo cache.
+    pub memo_hits: u64,
+
+    /// Time spent in the judgment, including nested judgments.
+    /// Recursive invocations are only counted once.
+    pub total: Duration,
+
+    /// Time spent in the judgment itself, excluding nested judgments.
+    pub self_time: Duration,
+
+    /// Number of invocations (not counting memo hits) whose inputs
+    /// appear nowhere in a final derivation.
+    pub discarded: u64,
+
+    /// Statistics for each rule of the judgment that appears in a final derivation.
+    pub rules: Map<String, RuleStats>,
+}
+
+/// Statistics for one rule of a judgment.
+#[derive(Clone, Debug, Default)]
+pub struct RuleStats {
+    /// Number of times the rule appears in the final derivations.
+    pub applied: u64,
+
+    /// Time spent in the invocations proven by this rule, including nested judgments.
+    pub total: Duration,
+
+    /// Time spent in the invocations proven by this rule, excluding nested judgments.
+    pub self_time: Duration,
+}
+
+/// Time spent in all invocations of one judgment with the same inputs.
+#[derive(Copy, Clone, Debug, Default)]
+struct InvocationTimes {
+    /// Number of spans created for these inputs.
+    count: u64,
+    total: Duration,
+    self_time: Duration,
+}
+
+impl InvocationTimes {
+    /// Time of an average invocation, as `(total, self)`.
+    fn mean(&self) -> (Duration, Duration) {
+        let count = u32::try_from(self.count.max(1)).unwrap_or(u32::MAX);
+        (self.total / count, self.self_time / count)
+    }
+}
+
+#[derive(Default)]
+struct ProfileState {
+    /// Each judgment span that is open, indexed by span id.
+    spans: Map<u64, SpanInfo>,
+
+    /// Stack of currently entered spans.
+    stack: Vec<ActiveSpan>,
+
+    judgments: Map<&'static str, JudgmentStats>,
+
+    /// Time per distinct invocation, keyed by judgment name and a hash of the invocation's text.
+    invocations: Map<(&'static str, u64), InvocationTimes>,
+
+    /// Hashes of the invocations that appear in a recorded proof tree.
+    used: Set<(&'static str, u64)>,
+
+    /// Self time for each stack of span names, keyed by the `;`-separated stack.
+    folded: Map<String, Duration>,
+}
+
+#[derive(Copy, Clone)]
+struct SpanInfo {
+    name: &'static str,
+
+    /// Hash of the invocation's text, as printed in a proof tree.
+    key: u64,
+}
+
+struct ActiveSpan {
+    id: u64,
+    info: SpanInfo,
+    entered: Instant,
+
+    /// Time spent in spans nested inside of this one.
+    children: Duration,
+}
+
+/// A `tracing` subscriber that profiles judgment invocations.
+/// Clones share the same underlying data.
+#[derive(Clone, Default)]
+pub struct Profiler {
+    next_id: Arc<AtomicU64>,
+    state: Arc<Mutex<ProfileState>>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `op` with this profiler installed as the current thread's subscriber.
+    pub fn profile<R>(&self, op: impl FnOnce() -> R) -> R {
+        tracing::subscriber::with_default(self.clone(), op)
+    }
+
+    /// Profile type checking `program` and record the final derivation. The profile
+    /// is recorded even if the program fails to type check; the error is returned after.
+    pub fn check_program(&self, program: &crate::elaborator::ElaboratedProgram) -> Fallible<()> {
+        let result: ProvenSet<()> = self.profile(|| {
+            crate::type_system::memo::with_memo(|| crate::type_system::check_program(program)).0
+        });
+        let ((), proof_tree) = result.into_singleton()?;
+        self.record_proof_tree(&proof_tree);
+        Ok(())
+    }
+
+    /// Count the judgments and rules used by a successful derivation.
+    pub fn record_proof_tree(&self, proof_tree: &ProofTree) {
+        let mut state = self.state.lock().unwrap();
+        record_proof_tree(&mut state, proof_tree);
+    }
+
+    /// Statistics for every judgment observed so far, keyed by judgment name.
+    pub fn judgments(&self) -> Map<String, JudgmentStats> {
+        let state = self.state.lock().unwrap();
+        state
+            .judgments
+            .iter()
+            .map(|(&name, stats)| {
+                let mut stats = stats.clone();
+                stats.discarded = state
+                    .invocations
+                    .range((name, 0)..=(name, u64::MAX))
+                    .filter(|(key, _)| !state.used.contains(key))
+                    .map(|(_, times)| times.count)
+                    .sum();
+                (name.to_string(), stats)
+            })
+            .collect()
+    }
+
+    /// A table of judgments sorted by self time, followed by the rules of each judgment.
+    pub fn report(&self) -> String {
+        let mut judgments: Vec<(String, JudgmentStats)> = self.judgments().into_iter().collect();
+        judgments.sort_by(|a, b| b.1.self_time.cmp(&a.1.self_time).then(a.0.cmp(&b.0)));
+
+        let mut out = format!(
+            "{:<40} {:>10} {:>10} {:>12} {:>12} {:>10}\n",
+            "judgment", "calls", "memo hits", "total (ms)", "self (ms)", "discarded"
+        );
+        for (name, stats) in &judgments {
+            out.push_str(&format!(
+                "{:<40} {:>10} {:>10} {:>12.3} {:>12.3} {:>10}\n",
+                name,
+                stats.invocations,
+                stats.memo_hits,
+                millis(stats.total),
+                millis(stats.self_time),
+                stats.discarded,
+            ));
+            for (rule, rule_stats) in &stats.rules {
+                out.push_str(&format!(
+                    "    rule {:<31} {:>10} {:>10} {:>12.3} {:>12.3}\n",
+                    format!("{rule:?}"),
+                    rule_stats.applied,
+                    "",
+                    millis(rule_stats.total),
+                    millis(rule_stats.self_time),
+                ));
+            }
+        }
+        out
+    }
+
+    /// Self time along each stack of judgments, one `stack count` line per stack,
+    /// with the count in microseconds.
+    pub fn folded_stacks(&self) -> String {
+        let state = self.state.lock().unwrap();
+        state
+            .folded
+            .iter()
+            .map(|(stack, time)| format!("{stack} {}\n", time.as_micros()))
+            .collect()
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn record_proof_tree(state: &mut ProfileState, proof_tree: &ProofTree) {
+    let name = judgment_name(&proof_tree.judgment);
+    if let Some((&name, _)) = state.judgments.get_key_value(name) {
+        let key = (name, hash_text(&proof_tree.judgment));
+        state.used.insert(key);
+        let (total, self_time) = state
+            .invocations
+            .get(&key)
+            .map(InvocationTimes::mean)
+            .unwrap_or_default();
+        if let Some(rule) = &proof_tree.rule_name {
+            let stats = state.judgments.get_mut(name).unwrap();
+            let rule_stats = stats.rules.entry(rule.clone()).or_default();
+            rule_stats.applied += 1;
+            rule_stats.total += total;
+            rule_stats.self_time += self_time;
+        }
+    }
+    for child in &proof_tree.children {
+        record_proof_tree(state, child);
+    }
+}
+
+/// Report that the memoized judgment `name` was answered from the cache,
+/// so that a [`Profiler`] counts it as an invocation.
+pub(crate) fn memo_hit(name: &'static str) {
+    tracing::trace!(target: MEMO_HIT_TARGET, judgment = name);
+}
+
+/// Extract the name of a judgment from its debug text, e.g.
+/// `prove_is_copy { a: .., env: .. }` becomes `prove_is_copy`.
+pub(crate) fn judgment_name(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    &text[..end]
+}
+
+fn hash_text(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// True for spans created by `judgment_fn!`s in this crate.
+fn is_judgment_span(metadata: &tracing::Metadata<'_>) -> bool {
+    metadata.is_span() && metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+}
+
+/// Rebuilds the text of a judgment invocation, `name { input: value, .. }`,
+/// from the fields of its span.
+struct InvocationText {
+    text: String,
+    fields: usize,
+}
+
+impl Visit for InvocationText {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        let separator = if self.fields == 0 { " { " } else { ", " };
+        write!(self.text, "{separator}{}: {value:?}", field.name()).unwrap();
+        self.fields += 1;
+    }
+}
+
+/// Finds the `judgment` field of a [`memo_hit`] event.
+#[derive(Default)]
+struct MemoHitName(Option<String>);
+
+impl Visit for MemoHitName {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "judgment" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn Debug) {}
+}
+
+impl tracing::Subscriber for Profiler {
+    fn enabled(&self, metadata: &tracing::Metadata<'_>) -> bool {
+        is_judgment_span(metadata) || metadata.target() == MEMO_HIT_TARGET
+    }
+
+    fn new_span(&self, attributes: &span::Attributes<'_>) -> span::Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let name = attributes.metadata().name();
+        let mut text = InvocationText {
+            text: name.to_string(),
+            fields: 0,
+        };
+        attributes.record(&mut text);
+        if text.fields > 0 {
+            text.text.push_str(" }");
+        }
+        let key = hash_text(&text.text);
+
+        let mut state = self.state.lock().unwrap();
+        state.spans.insert(id, SpanInfo { name, key });
+        state.judgments.entry(name).or_default().invocations += 1;
+        state.invocations.entry((name, key)).or_default().count += 1;
+        span::Id::from_u64(id)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        if event.metadata().target() != MEMO_HIT_TARGET {
+            return;
+        }
+        let mut hit = MemoHitName::default();
+        event.record(&mut hit);
+        let Some(hit) = hit.0 else {
+            return;
+        };
+        let mut state = self.state.lock().unwrap();
+        // Judgment names are the `&'static str`s of span metadata, so a judgment
+        // that has never run uncached has no entry to attribute the hit to.
+        if let Some(stats) = state.judgments.get_mut(hit.as_str()) {
+            stats.invocations += 1;
+            stats.memo_hits += 1;
+        }
+    }
+
+    fn enter(&self, span: &span::Id) {
+        let mut state = self.state.lock().unwrap();
+        let id = span.into_u64();
+        let Some(&info) = state.spans.get(&id) else {
+            return;
+        };
+        state.stack.push(ActiveSpan {
+            id,
+            info,
+            entered: Instant::now(),
+            children: Duration::ZERO,
+        });
+    }
+
+    fn exit(&self, span: &span::Id) {
+        let mut state = self.state.lock().unwrap();
+        if state.stack.last().map(|s| s.id) != Some(span.into_u64()) {
+            return;
+        }
+        let active = state.stack.pop().unwrap();
+        let name = active.info.name;
+        let elapsed = active.entered.elapsed();
+        let self_time = elapsed.saturating_sub(active.children);
+
+        let mut stack: Vec<&str> = state.stack.iter().map(|s| s.info.name).collect();
+        let recursive = stack.contains(&name);
+        stack.push(name);
+        *state.folded.entry(stack.join(";")).or_default() += self_time;
+
+        if let Some(parent) = state.stack.last_mut() {
+            parent.children += elapsed;
+        }
+
+        let times = state
+            .invocations
+            .entry((name, active.info.key))
+            .or_default();
+        times.total += elapsed;
+        times.self_time += self_time;
+
+        let stats = state.judgments.entry(name).or_default();
+        stats.self_time += self_time;
+        if !recursive {
+            stats.total += elapsed;
+        }
+    }
+
+    fn try_close(&self, span: span::Id) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state.spans.remove(&span.into_u64());
+        true
+    }
+}
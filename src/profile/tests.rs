@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use crate::test_util::parse_program;
+use crate::type_system;
+
+use super::Profiler;
+
+const GIVE_DATA: &str = "
+    class Data { }
+    class Main {
+        fn main(given self) -> Data {
+            let d = new Data();
+            d.give;
+        }
+    }
+";
+
+const GIVE_DATA_TWICE: &str = "
+    class Data { }
+    class Main {
+        fn main(given self) -> Data {
+            let d = new Data();
+            d.give;
+            d.give;
+        }
+    }
+";
+
+#[test]
+fn counts_only_judgment_spans() {
+    let profiler = Profiler::new();
+    profiler.profile(|| {
+        let _outer = tracing::debug_span!("outer_judgment", a = ?1).entered();
+        for i in 0..2 {
+            let _inner = tracing::debug_span!("inner_judgment", a = ?i).entered();
+        }
+        let _foreign =
+            tracing::debug_span!(target: "formality_core::fixed_point", "fixed_point").entered();
+    });
+
+    let judgments = profiler.judgments();
+    let names: Vec<&String> = judgments.keys().collect();
+    expect_test::expect![[r#"
+        [
+            "inner_judgment",
+            "outer_judgment",
+        ]
+    "#]]
+    .assert_debug_eq(&names);
+    assert_eq!(judgments["outer_judgment"].invocations, 1);
+    assert_eq!(judgments["inner_judgment"].invocations, 2);
+
+    let folded = profiler.folded_stacks();
+    let stacks: Vec<&str> = folded
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().0)
+        .collect();
+    assert_eq!(stacks, ["outer_judgment", "outer_judgment;inner_judgment"]);
+}
+
+#[test]
+fn charges_time_to_the_rules_of_the_final_derivation() {
+    let profiler = Profiler::new();
+    profiler.check_program(&parse_program(&[GIVE_DATA])).unwrap();
+
+    let judgments = profiler.judgments();
+    let move_place = &judgments["move_place"];
+    let give = &move_place.rules["give"];
+    assert!(give.applied >= 1, "{move_place:?}");
+    assert!(give.total > Duration::ZERO, "{move_place:?}");
+    assert!(give.self_time <= give.total, "{move_place:?}");
+    assert!(!move_place.rules.contains_key("copy"), "{move_place:?}");
+
+    for (name, stats) in &judgments {
+        assert!(
+            stats.discarded + stats.memo_hits <= stats.invocations,
+            "{name}: {stats:?}"
+        );
+    }
+    assert!(profiler.report().contains("rule \"give\""));
+}
+
+#[test]
+fn counts_memo_hits_as_invocations() {
+    let program = parse_program(&[GIVE_DATA]);
+    let profiler = Profiler::new();
+    let (_result, memo) = profiler.profile(|| {
+        type_system::memo::with_memo(|| type_system::check_program(&program))
+    });
+
+    let judgments = profiler.judgments();
+    let profiled_hits: u64 = judgments.values().map(|stats| stats.memo_hits).sum();
+    assert_eq!(profiled_hits, memo.total().hits);
+    for (name, memo_stats) in &memo.judgments {
+        let stats = &judgments[*name];
+        assert_eq!(stats.memo_hits, memo_stats.hits, "{name}");
+        assert!(stats.invocations >= memo_stats.hits + memo_stats.misses, "{name}");
+    }
+}
+
+#[test]
+fn failed_program_is_still_profiled() {
+    let profiler = Profiler::new();
+    let result = profiler.check_program(&parse_program(&[GIVE_DATA_TWICE]));
+    assert!(result.is_err());
+
+    // There is no final derivation, so every invocation was discarded.
+    let judgments = profiler.judgments();
+    let move_place = &judgments["move_place"];
+    assert!(move_place.invocations > 0);
+    assert!(move_place.rules.is_empty());
+    assert_eq!(
+        move_place.discarded,
+        move_place.invocations - move_place.memo_hits
+    );
+    assert!(!profiler.folded_stacks().is_empty());
+}
@@ -0,0 +1,551 @@
+//! Test-case reduction for Dada programs.
+//!
+//! Given a program and a [`Predicate`] that holds for it (say, "the type error
+//! mentions `prove_copy_predicate`"), [`reduce`] repeatedly tries smaller
+//! variants of the program and keeps the first one for which the predicate
+//! still holds, until no variant does. Variants are made by deleting classes,
+//! fields, methods, predicates and statements, by replacing expressions with one
+//! of their subexpressions, and so forth. Because the reduction works on the
+//! [`Program`] AST rather than on text, every variant is syntactically valid.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use crate::elaborator::ElaboratedProgram;
+use crate::fuzz::{self, Outcome};
+use crate::grammar::{
+    Ascription, Binder, Block, ClassDecl, ClassDeclBoundData, Decl, DropBody, Expr, MethodBody,
+    MethodDecl, MethodDeclBoundData, MethodId, Program, Statement, ValueId,
+};
+use crate::type_system;
+
+#[cfg(test)]
+mod tests;
+
+/// A property of a program that reduction preserves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Predicate {
+    /// The type checker rejects the program with an error whose text
+    /// (the full failure tree) contains this string.
+    TypeErrorContains(String),
+
+    /// The interpreter faults with a message containing this string.
+    /// The program is run whether or not it type checks.
+    FaultContains(String),
+
+    /// The program type checks and then faults in the interpreter.
+    /// Without a `Main.main` to run, the interpreter faults before running
+    /// anything, so such a program does not count.
+    Unsound,
+}
+
+impl Predicate {
+    pub fn holds(&self, program: &Program) -> bool {
+        match self {
+            Predicate::TypeErrorContains(text) => {
+                type_error(program).is_some_and(|error| error.contains(text.as_str()))
+            }
+            Predicate::FaultContains(text) => {
+                let elaborated = ElaboratedProgram::elaborate(program);
+                let outcome = fuzz::interpret(elaborated);
+                matches!(outcome, Outcome::Fault(fault) if fault.contains(text.as_str()))
+            }
+            Predicate::Unsound => {
+                has_main(program) && matches!(fuzz::run_program(program), Outcome::Fault(_))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Predicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Predicate::TypeErrorContains(text) => write!(f, "type error contains {text:?}"),
+            Predicate::FaultContains(text) => write!(f, "interpreter faults with {text:?}"),
+            Predicate::Unsound => write!(f, "type checks, then faults in the interpreter"),
+        }
+    }
+}
+
+/// True if `program` has a `Main` class with a `main` method.
+fn has_main(program: &Program) -> bool {
+    let main_class: ValueId = crate::dada_lang::term("Main");
+    let main_method: MethodId = crate::dada_lang::term("main");
+    program.decls.iter().any(|Decl::ClassDecl(class)| {
+        let (_, data) = class.binder.open();
+        class.name == main_class && data.methods.iter().any(|method| method.name == main_method)
+    })
+}
+
+/// The type checker's error for `program`, or `None` if it type checks.
+/// A panic in the type checker counts as neither.
+fn type_error(program: &Program) -> Option<String> {
+    let elaborated = ElaboratedProgram::elaborate(program);
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        type_system::memo::with_memo(|| {
+            type_system::check_program(&elaborated)
+                .into_singleton()
+                .err()
+                .map(|error| error.to_string())
+        })
+        .0
+    }))
+    .unwrap_or(None)
+}
+
+/// Shrink `program` for as long as `interesting` holds.
+/// `interesting` must hold for `program` itself.
+pub fn reduce(program: &Program, mut interesting: impl FnMut(&Program) -> bool) -> Program {
+    let mut program = program.clone();
+    'reduce: loop {
+        let loops_and_breaks = count_loops_and_breaks(&program);
+        for candidate in program_variants(&program) {
+            // Deleting a `break` (or the `if` around it) without deleting its
+            // loop is likely to make the interpreter run until it is out of fuel.
+            let (loops, breaks) = count_loops_and_breaks(&candidate);
+            if breaks < loops_and_breaks.1 && loops == loops_and_breaks.0 {
+                continue;
+            }
+
+            if interesting(&candidate) {
+                program = candidate;
+                continue 'reduce;
+            }
+        }
+        return program;
+    }
+}
+
+/// Every program obtained from `program` by a single deletion or
+/// simplification, roughly from the biggest reduction to the smallest.
+fn program_variants(program: &Program) -> Vec<Program> {
+    let Program { decls } = program;
+    let mut out = vec![];
+
+    for index in 0..decls.len() {
+        let mut decls = decls.clone();
+        decls.remove(index);
+        out.push(Program { decls });
+    }
+
+    for decl in decls {
+        let Decl::ClassDecl(class) = decl;
+        let (_, ClassDeclBoundData { fields, .. }) = class.binder.open();
+        for index in 0..fields.len() {
+            out.push(remove_field(program, &class.name, index));
+        }
+    }
+
+    for (index, decl) in decls.iter().enumerate() {
+        let Decl::ClassDecl(class) = decl;
+        for class in class_variants(class) {
+            let mut decls = decls.clone();
+            decls[index] = Decl::ClassDecl(class);
+            out.push(Program { decls });
+        }
+    }
+
+    out
+}
+
+/// Variants of `class` that change only the class itself.
+fn class_variants(class: &ClassDecl) -> Vec<ClassDecl> {
+    let (vars, data) = class.binder.open();
+    let ClassDeclBoundData {
+        predicates,
+        fields: _,
+        methods,
+        drop_body,
+    } = &data;
+
+    let mut variants = vec![];
+    for index in 0..methods.len() {
+        let mut data = data.clone();
+        data.methods.remove(index);
+        variants.push(data);
+    }
+    if !drop_body.block.statements.is_empty() {
+        variants.push(ClassDeclBoundData {
+            drop_body: DropBody::default(),
+            ..data.clone()
+        });
+    }
+    for index in 0..predicates.len() {
+        let mut data = data.clone();
+        data.predicates.remove(index);
+        variants.push(data);
+    }
+    for (index, method) in methods.iter().enumerate() {
+        for method in method_variants(method) {
+            let mut data = data.clone();
+            data.methods[index] = method;
+            variants.push(data);
+        }
+    }
+    for block in block_variants(&drop_body.block) {
+        variants.push(ClassDeclBoundData {
+            drop_body: DropBody { block },
+            ..data.clone()
+        });
+    }
+
+    variants
+        .into_iter()
+        .map(|data| ClassDecl {
+            binder: Binder::new(vars.clone(), data),
+            ..class.clone()
+        })
+        .collect()
+}
+
+fn method_variants(method: &MethodDecl) -> Vec<MethodDecl> {
+    let (vars, data) = method.binder.open();
+    let MethodDeclBoundData {
+        predicates, body, ..
+    } = &data;
+
+    let mut variants = vec![];
+    for index in 0..predicates.len() {
+        let mut data = data.clone();
+        data.predicates.remove(index);
+        variants.push(data);
+    }
+    if let MethodBody::Block(block) = body {
+        for block in block_variants(block) {
+            variants.push(MethodDeclBoundData {
+                body: MethodBody::Block(block),
+                ..data.clone()
+            });
+        }
+    }
+
+    variants
+        .into_iter()
+        .map(|data| MethodDecl {
+            name: method.name.clone(),
+            binder: Binder::new(vars.clone(), data),
+        })
+        .collect()
+}
+
+/// Remove the `index`th field of `class`, along with the corresponding
+/// argument of every `new` expression that constructs it.
+fn remove_field(program: &Program, class: &ValueId, index: usize) -> Program {
+    let program = map_bodies(program, &|block| {
+        map_block(block, &|expr| match expr {
+            Expr::New(name, parameters, mut args) if name == *class && index < args.len() => {
+                args.remove(index);
+                Expr::New(name, parameters, args)
+            }
+            expr => expr,
+        })
+    });
+
+    let decls = program
+        .decls
+        .iter()
+        .map(|Decl::ClassDecl(decl)| {
+            if decl.name != *class {
+                return Decl::ClassDecl(decl.clone());
+            }
+            let (vars, mut data) = decl.binder.open();
+            data.fields.remove(index);
+            Decl::ClassDecl(ClassDecl {
+                binder: Binder::new(vars, data),
+                ..decl.clone()
+            })
+        })
+        .collect();
+    Program { decls }
+}
+
+/// Apply `op` to every method body and drop body in `program`.
+fn map_bodies(program: &Program, op: &dyn Fn(&Block) -> Block) -> Program {
+    let decls = program
+        .decls
+        .iter()
+        .map(|Decl::ClassDecl(decl)| {
+            let (vars, mut data) = decl.binder.open();
+            data.drop_body.block = op(&data.drop_body.block);
+            for method in &mut data.methods {
+                let (method_vars, mut method_data) = method.binder.open();
+                if let MethodBody::Block(block) = &method_data.body {
+                    method_data.body = MethodBody::Block(op(block));
+                }
+                method.binder = Binder::new(method_vars, method_data);
+            }
+            Decl::ClassDecl(ClassDecl {
+                binder: Binder::new(vars, data),
+                ..decl.clone()
+            })
+        })
+        .collect();
+    Program { decls }
+}
+
+/// Every method body and drop body in `program`.
+fn bodies(program: &Program) -> Vec<Block> {
+    let mut bodies = vec![];
+    for Decl::ClassDecl(decl) in &program.decls {
+        let (_, data) = decl.binder.open();
+        bodies.push(data.drop_body.block);
+        for method in &data.methods {
+            let (_, method_data) = method.binder.open();
+            if let MethodBody::Block(block) = method_data.body {
+                bodies.push(block);
+            }
+        }
+    }
+    bodies
+}
+
+/// Apply `op` bottom-up to every expression in `block`.
+fn map_block(block: &Block, op: &dyn Fn(Expr) -> Expr) -> Block {
+    let Block { statements } = block;
+    let statements = statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Expr(expr) => Statement::Expr(map_expr(expr, op)),
+            Statement::Let(var, ascription, expr) => Statement::Let(
+                var.clone(),
+                ascription.clone(),
+                Arc::new(map_expr(expr, op)),
+            ),
+            Statement::Reassign(place, expr) => {
+                Statement::Reassign(place.clone(), map_expr(expr, op))
+            }
+            Statement::Loop(block) => Statement::Loop(map_block(block, op)),
+            Statement::Break => Statement::Break,
+            Statement::Return(expr) => Statement::Return(map_expr(expr, op)),
+            Statement::Print(expr) => Statement::Print(map_expr(expr, op)),
+        })
+        .collect();
+    Block { statements }
+}
+
+fn map_expr(expr: &Expr, op: &dyn Fn(Expr) -> Expr) -> Expr {
+    let expr = match expr {
+        Expr::Block(block) => Expr::Block(map_block(block, op)),
+        _ => with_children(
+            expr,
+            children(expr)
+                .iter()
+                .map(|child| map_expr(child, op))
+                .collect(),
+        ),
+    };
+    op(expr)
+}
+
+/// The number of `loop` and `break` statements in `program`.
+fn count_loops_and_breaks(program: &Program) -> (usize, usize) {
+    let (mut loops, mut breaks) = (0, 0);
+    for block in bodies(program) {
+        visit_statements(&block, &mut |statement| match statement {
+            Statement::Loop(_) => loops += 1,
+            Statement::Break => breaks += 1,
+            _ => {}
+        });
+    }
+    (loops, breaks)
+}
+
+/// Call `op` on every statement in `block`, including nested ones.
+fn visit_statements(block: &Block, op: &mut dyn FnMut(&Statement)) {
+    for statement in &block.statements {
+        op(statement);
+        match statement {
+            Statement::Loop(block) => visit_statements(block, op),
+            Statement::Break => {}
+            Statement::Expr(expr)
+            | Statement::Reassign(_, expr)
+            | Statement::Return(expr)
+            | Statement::Print(expr) => visit_expr_statements(expr, op),
+            Statement::Let(_, _, expr) => visit_expr_statements(expr, op),
+        }
+    }
+}
+
+fn visit_expr_statements(expr: &Expr, op: &mut dyn FnMut(&Statement)) {
+    match expr {
+        Expr::Block(block) => visit_statements(block, op),
+        _ => {
+            for child in children(expr) {
+                visit_expr_statements(&child, op);
+            }
+        }
+    }
+}
+
+fn block_variants(block: &Block) -> Vec<Block> {
+    let Block { statements } = block;
+    statement_list_variants(statements)
+        .into_iter()
+        .map(|statements| Block { statements })
+        .collect()
+}
+
+fn statement_list_variants(statements: &[Statement]) -> Vec<Vec<Statement>> {
+    let replace = |index: usize, with: Vec<Statement>| {
+        let mut statements = statements.to_vec();
+        statements.splice(index..=index, with);
+        statements
+    };
+
+    let mut out = vec![];
+    for index in 0..statements.len() {
+        out.push(replace(index, vec![]));
+    }
+    for (index, statement) in statements.iter().enumerate() {
+        if let Statement::Loop(Block { statements: body }) = statement {
+            out.push(replace(index, body.clone()));
+        }
+        for statement in statement_variants(statement) {
+            out.push(replace(index, vec![statement]));
+        }
+    }
+    out
+}
+
+fn statement_variants(statement: &Statement) -> Vec<Statement> {
+    match statement {
+        Statement::Expr(expr) => expr_variants(expr)
+            .into_iter()
+            .map(Statement::Expr)
+            .collect(),
+        Statement::Let(var, ascription, expr) => {
+            let mut out = vec![];
+            if let Ascription::Ty(_) = ascription {
+                out.push(Statement::Let(var.clone(), Ascription::NoTy, expr.clone()));
+            }
+            out.extend(
+                expr_variants(expr)
+                    .into_iter()
+                    .map(|expr| Statement::Let(var.clone(), ascription.clone(), Arc::new(expr))),
+            );
+            out
+        }
+        Statement::Reassign(place, expr) => expr_variants(expr)
+            .into_iter()
+            .map(|expr| Statement::Reassign(place.clone(), expr))
+            .collect(),
+        Statement::Loop(block) => block_variants(block)
+            .into_iter()
+            .map(Statement::Loop)
+            .collect(),
+        Statement::Break => vec![],
+        Statement::Return(expr) => expr_variants(expr)
+            .into_iter()
+            .map(Statement::Return)
+            .collect(),
+        Statement::Print(expr) => std::iter::once(Statement::Expr(expr.clone()))
+            .chain(expr_variants(expr).into_iter().map(Statement::Print))
+            .collect(),
+    }
+}
+
+fn expr_variants(expr: &Expr) -> Vec<Expr> {
+    let mut out = vec![];
+    match expr {
+        Expr::Block(block) => {
+            if let [Statement::Expr(expr)] = &block.statements[..] {
+                out.push(expr.clone());
+            }
+            out.extend(block_variants(block).into_iter().map(Expr::Block));
+            return out;
+        }
+        Expr::Integer(value) if *value != 0 => out.push(Expr::Integer(0)),
+        Expr::Tuple(exprs) => {
+            for index in 0..exprs.len() {
+                let mut exprs = exprs.clone();
+                exprs.remove(index);
+                out.push(Expr::Tuple(exprs));
+            }
+        }
+        _ => {}
+    }
+
+    // Replace the expression with one of its subexpressions,
+    // or simplify one of the subexpressions.
+    let children = children(expr);
+    out.extend(children.iter().cloned());
+    for (index, child) in children.iter().enumerate() {
+        for child in expr_variants(child) {
+            let mut children = children.clone();
+            children[index] = child;
+            out.push(with_children(expr, children));
+        }
+    }
+    out
+}
+
+/// The immediate subexpressions of `expr`, in evaluation order.
+/// The statements of a block are not included.
+fn children(expr: &Expr) -> Vec<Expr> {
+    let arcs = |exprs: &[&Arc<Expr>]| -> Vec<Expr> {
+        exprs.iter().map(|expr| Expr::clone(expr)).collect()
+    };
+    match expr {
+        Expr::Block(_)
+        | Expr::Integer(_)
+        | Expr::True
+        | Expr::False
+        | Expr::Place(_)
+        | Expr::Clear(_)
+        | Expr::SizeOf(_)
+        | Expr::Panic => vec![],
+        Expr::BinaryOp(lhs, _, rhs) => arcs(&[lhs, rhs]),
+        Expr::Share(expr) | Expr::ArrayNew(_, expr) | Expr::ArrayCapacity(_, expr) => arcs(&[expr]),
+        Expr::IsLastRef(_, expr) => arcs(&[expr]),
+        Expr::Tuple(exprs) | Expr::New(_, _, exprs) => exprs.clone(),
+        Expr::Call(receiver, _, _, args) => std::iter::once(Expr::clone(receiver))
+            .chain(args.iter().cloned())
+            .collect(),
+        Expr::If(cond, if_true, if_false) => arcs(&[cond, if_true, if_false]),
+        Expr::ArrayGive(_, array, index) => arcs(&[array, index]),
+        Expr::ArrayDrop(_, array, from, to) => arcs(&[array, from, to]),
+        Expr::ArrayWrite(_, array, index, value) => arcs(&[array, index, value]),
+    }
+}
+
+/// Rebuild `expr` with its subexpressions (as returned by [`children`]) replaced by `children`.
+fn with_children(expr: &Expr, children: Vec<Expr>) -> Expr {
+    let mut children = children.into_iter();
+    let mut next = || Arc::new(children.next().unwrap());
+    match expr {
+        Expr::Block(_)
+        | Expr::Integer(_)
+        | Expr::True
+        | Expr::False
+        | Expr::Place(_)
+        | Expr::Clear(_)
+        | Expr::SizeOf(_)
+        | Expr::Panic => expr.clone(),
+        Expr::BinaryOp(_, op, _) => Expr::BinaryOp(next(), op.clone(), next()),
+        Expr::Share(_) => Expr::Share(next()),
+        Expr::ArrayNew(parameters, _) => Expr::ArrayNew(parameters.clone(), next()),
+        Expr::ArrayCapacity(parameters, _) => Expr::ArrayCapacity(parameters.clone(), next()),
+        Expr::IsLastRef(parameters, _) => Expr::IsLastRef(parameters.clone(), next()),
+        Expr::Tuple(_) => Expr::Tuple(children.collect()),
+        Expr::New(class, parameters, _) => {
+            Expr::New(class.clone(), parameters.clone(), children.collect())
+        }
+        Expr::Call(_, method, parameters, _) => {
+            let receiver = next();
+            Expr::Call(
+                receiver,
+                method.clone(),
+                parameters.clone(),
+                children.collect(),
+            )
+        }
+        Expr::If(..) => Expr::If(next(), next(), next()),
+        Expr::ArrayGive(parameters, ..) => Expr::ArrayGive(parameters.clone(), next(), next()),
+        Expr::ArrayDrop(parameters, ..) => {
+            Expr::ArrayDrop(parameters.clone(), next(), next(), next())
+        }
+        Expr::ArrayWrite(parameters, ..) => {
+            Expr::ArrayWrite(parameters.clone(), next(), next(), next())
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::dada_lang;
+use crate::grammar::{Decl, Expr, Program, Statement, ValueId};
+
+use super::{bodies, reduce, remove_field, Predicate};
+
+fn parse(text: &str) -> Program {
+    let program: Arc<Program> = dada_lang::try_term(text).expect("parse error");
+    Program::clone(&program)
+}
+
+/// The statements of the method bodies of `program`, ignoring empty drop bodies.
+fn method_statements(program: &Program) -> Vec<Vec<Statement>> {
+    bodies(program)
+        .into_iter()
+        .map(|block| block.statements)
+        .filter(|statements| !statements.is_empty())
+        .collect()
+}
+
+#[test]
+fn deletes_classes_and_statements() {
+    let program = parse(
+        "class Noise { x: Int; }
+        class Main {
+            fn helper(given self) -> Int { 1; }
+            fn main(given self) -> Int {
+                let a = 1;
+                print(a.give);
+                let b = 2;
+                0;
+            }
+        }",
+    );
+    let reduced = reduce(&program, |program| format!("{program:?}").contains("print"));
+
+    assert_eq!(reduced.decls.len(), 1);
+    let statements = method_statements(&reduced);
+    assert_eq!(statements.len(), 1, "{reduced:?}");
+    assert!(
+        matches!(&statements[0][..], [Statement::Print(_)]),
+        "{reduced:?}"
+    );
+}
+
+#[test]
+fn removing_a_field_removes_constructor_arguments() {
+    let program = parse(
+        "class Pair { a: Int; b: Int; }
+        class Main {
+            fn main(given self) -> Int {
+                let p = new Pair(1, 2);
+                0;
+            }
+        }",
+    );
+    let pair: ValueId = dada_lang::try_term("Pair").unwrap();
+    let reduced = remove_field(&program, &pair, 0);
+
+    let Decl::ClassDecl(class) = &reduced.decls[0];
+    let (_, data) = class.binder.open();
+    assert_eq!(data.fields.len(), 1);
+
+    let statements = method_statements(&reduced);
+    let [Statement::Let(_, _, expr), _] = &statements[0][..] else {
+        panic!("unexpected statements: {statements:?}");
+    };
+    let Expr::New(_, _, args) = &**expr else {
+        panic!("unexpected expression: {expr:?}");
+    };
+    assert_eq!(args, &vec![Expr::Integer(2)]);
+}
+
+#[test]
+fn reduces_interpreter_fault() {
+    let program = parse(
+        "class Noise { x: Int; }
+        class Main {
+            fn main(given self) -> Int {
+                let a = 1;
+                print(a.give);
+                if a.give == 1 { !; } else { 2; };
+                0;
+            }
+        }",
+    );
+    let predicate = Predicate::FaultContains("panic!".to_string());
+    assert!(predicate.holds(&program));
+
+    let reduced = reduce(&program, |program| predicate.holds(program));
+    assert!(predicate.holds(&reduced));
+    assert_eq!(reduced.decls.len(), 1);
+    let statements = method_statements(&reduced);
+    assert!(
+        matches!(&statements[..], [s] if matches!(&s[..], [Statement::Expr(Expr::Panic)])),
+        "{reduced:?}"
+    );
+}
+
+/// A program the interpreter cannot start, for want of a `Main.main`, is not
+/// unsound, so reduction never deletes `Main` or `main` to get there.
+#[test]
+fn unsound_needs_main() {
+    let program = parse("class Main { fn helper(given self) -> Int { 0; } }");
+    assert!(!Predicate::Unsound.holds(&program));
+    let program = parse("class Data {}");
+    assert!(!Predicate::Unsound.holds(&program));
+}
@@ -1,18 +1,61 @@
+use std::ffi::OsStr;
+use std::ops::{Range, RangeInclusive};
 use std::sync::Arc;
 
 use formality_core::judgment::{FailedJudgment, ProofTree};
-use formality_core::Fallible;
+use formality_core::{Fallible, ProvenSet};
 
+use crate::coverage::Coverage;
 use crate::dada_lang;
 use crate::elaborator::ElaboratedProgram;
 use crate::grammar::Program;
-use crate::interpreter::Interpreter;
-use crate::type_system;
+use crate::interpreter::{with_stack, Interpreter, DEFAULT_FUEL, DEFAULT_MAX_CALL_DEPTH};
+use crate::outline::Outline;
+use crate::profile::Profiler;
+use crate::type_system::{self, locals::method_envs};
+
+/// Type check `program` with memoization enabled. If the `DADA_PROFILE` environment
+/// variable is set, the judgments are profiled and their folded stacks are appended to
+/// the file it names, so that a whole `cargo test` run can be turned into a single flamegraph.
+/// Likewise, if `DADA_COVERAGE` is set, the rules that were applied or failed are appended
+/// to the file it names (see [`Coverage`]).
+fn check_program(program: &ElaboratedProgram) -> ProvenSet<()> {
+    let result = profile_check_program(program);
+    if let Some(coverage_path) = std::env::var_os("DADA_COVERAGE") {
+        let mut coverage = Coverage::new();
+        coverage.record(&result);
+        append_to(&coverage_path, "DADA_COVERAGE", &coverage.to_lines());
+    }
+    result
+}
+
+fn profile_check_program(program: &ElaboratedProgram) -> ProvenSet<()> {
+    let check = || type_system::memo::with_memo(|| type_system::check_program(program)).0;
+    let Some(profile_path) = std::env::var_os("DADA_PROFILE") else {
+        return check();
+    };
+    let profiler = Profiler::new();
+    let result = profiler.profile(check);
+    append_to(&profile_path, "DADA_PROFILE", &profiler.folded_stacks());
+    result
+}
+
+/// Append `text` to the file at `path`, named by the environment variable `var`.
+/// Each call is a single write, so that tests running in parallel do not interleave.
+fn append_to(path: &OsStr, var: &str, text: &str) {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap_or_else(|e| panic!("failed to open `{var}` file: {e}"));
+    std::io::Write::write_all(&mut file, text.as_bytes())
+        .unwrap_or_else(|e| panic!("failed to write `{var}` file: {e}"));
+}
 
 pub fn test_program_ok(input: &str) -> Fallible<ProofTree> {
     let program: Arc<Program> = dada_lang::try_term(input)?;
     let elaborated = ElaboratedProgram::elaborate(&program);
-    let ((), proof_tree) = type_system::check_program(&elaborated).into_singleton()?;
+    let ((), proof_tree) = check_program(&elaborated).into_singleton()?;
     Ok(proof_tree)
 }
 
@@ -46,13 +89,105 @@ impl InterpretResult {
 /// Parse input fragments (concatenated), return the program. Panics on parse error.
 pub fn parse_program(inputs: &[&str]) -> ElaboratedProgram {
     let combined: String = inputs.concat();
-    let program: Arc<Program> = dada_lang::try_term(&combined).expect("parse error");
-    ElaboratedProgram::elaborate(&program)
+    try_parse_program(&combined).expect("parse error")
+}
+
+/// Parse and elaborate `input`, returning the parse error if there is one.
+pub fn try_parse_program(input: &str) -> Fallible<ElaboratedProgram> {
+    let program: Arc<Program> = dada_lang::try_term(input)?;
+    Ok(ElaboratedProgram::elaborate(&program))
+}
+
+/// Type check `program`. On failure, returns the leaf failures, as [`assert_type_err`] does.
+pub fn type_check_leaves(program: &ElaboratedProgram) -> Result<(), String> {
+    match check_program(program).into_singleton() {
+        Ok(_proof_tree) => Ok(()),
+        Err(e) => Err(formality_core::test_util::normalize_paths(
+            e.format_leaves(),
+        )),
+    }
+}
+
+/// A type error, with the lines of the source text that it is reported on.
+#[derive(Debug)]
+pub struct TypeError {
+    /// The first and last line (counting from 1) of the top-level statement that
+    /// fails to type check, or of the name of the method or class when no
+    /// statement does (e.g., the method's result has the wrong type).
+    pub lines: RangeInclusive<usize>,
+
+    /// The leaf failures, as [`assert_type_err`] reports them, but with the
+    /// `line:column` of each rule replaced by `LL:CC`.
+    pub leaves: String,
+}
+
+/// Type check every class of `program`, whose text is `source`, and return an error for
+/// each class or method that fails (see [`type_system::check_program_all`]). A method is
+/// reported on its first top-level statement that does not type check (see [`method_envs`]).
+pub fn located_type_errors(source: &str, program: &ElaboratedProgram) -> Vec<TypeError> {
+    let outline = Outline::new(source);
+    let report = type_system::check_program_all(program);
+    let mut errors = vec![];
+    for failure in &report.failures {
+        let class = outline.class(&format!("{:?}", failure.class));
+        if failure.methods.is_empty() {
+            errors.push(TypeError {
+                lines: lines_of(source, class.map_or(0..0, |class| class.span.clone())),
+                leaves: normalize_locations(&failure.error.format_leaves()),
+            });
+        }
+        for (method_name, error) in &failure.methods {
+            let name = format!("{method_name:?}");
+            let method =
+                class.and_then(|class| class.methods.iter().find(|method| method.name == name));
+            let span = method.map_or(0..0, |method| {
+                let failing_statement = match method_envs(program, &failure.class, method_name) {
+                    Ok(envs) => method.statements.get(envs.len() - 1),
+                    Err(_) => None,
+                };
+                failing_statement.unwrap_or(&method.span).clone()
+            });
+            errors.push(TypeError {
+                lines: lines_of(source, span),
+                leaves: normalize_locations(&error.format_leaves()),
+            });
+        }
+    }
+    errors
+}
+
+/// The lines of `source` (counting from 1) that `span` starts and ends on.
+fn lines_of(source: &str, span: Range<usize>) -> RangeInclusive<usize> {
+    let line = |offset: usize| source[..offset].matches('\n').count() + 1;
+    line(span.start)..=line(span.end)
+}
+
+/// Normalize the paths in `text`, and replace each `file.rs:line:column` with
+/// `file.rs:LL:CC`, so that snapshots do not change whenever a rule moves.
+fn normalize_locations(text: &str) -> String {
+    let text = formality_core::test_util::normalize_paths(text.to_string());
+    let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let mut normalized = String::new();
+    let mut rest = &text[..];
+    while let Some(index) = rest.find(".rs:") {
+        let (before, after) = rest.split_at(index + ".rs:".len());
+        normalized.push_str(before);
+        let line = digits(after);
+        let column = after[line..].strip_prefix(':').map_or(0, digits);
+        if line > 0 && column > 0 {
+            normalized.push_str("LL:CC");
+            rest = &after[line + 1 + column..];
+        } else {
+            rest = after;
+        }
+    }
+    normalized.push_str(rest);
+    normalized
 }
 
 /// Assert the type checker passes. Panics with the error if it fails.
 pub fn assert_type_ok(program: &ElaboratedProgram) {
-    match type_system::check_program(program).into_singleton() {
+    match check_program(program).into_singleton() {
         Ok(_proof_tree) => {}
         Err(e) => {
             panic!("expected type checker to pass, but it failed:\n{e}");
@@ -63,7 +198,7 @@ pub fn assert_type_ok(program: &ElaboratedProgram) {
 /// Assert the type checker fails. Returns the error string for snapshot comparison.
 /// Panics if the type checker passes.
 pub fn assert_type_err(program: &ElaboratedProgram) -> String {
-    match type_system::check_program(program).into_singleton() {
+    match check_program(program).into_singleton() {
         Ok(proof_tree) => panic!("expected type checker to fail, but it passed: {proof_tree:?}"),
         Err(e) => {
             println!("full error:\n\n{e}");
@@ -81,9 +216,26 @@ pub fn assert_interpret_result(r: &InterpretResult, expected_prefix: &str) {
     );
 }
 
+/// Run `program` with the default fuel and call-depth limits,
+/// so that a program that never terminates faults instead of hanging the test.
+/// It runs on a thread with a large enough stack for the call depth (see [`with_stack`]).
+///
+/// When the run finishes, the heap is audited (see [`Interpreter::audit`]), and any
+/// problem is reported as a fault: a finished run must not leak or double free.
+/// A run that faults is not audited, since it leaves its live values behind.
 pub fn run_interpreter(program: &ElaboratedProgram) -> InterpretResult {
+    with_stack(|| run_interpreter_here(program))
+}
+
+fn run_interpreter_here(program: &ElaboratedProgram) -> InterpretResult {
     let mut interp = Interpreter::new(program.clone());
-    let result = interp.interpret();
+    interp.set_fuel(DEFAULT_FUEL);
+    interp.set_max_call_depth(DEFAULT_MAX_CALL_DEPTH);
+    let result = interp.interpret().and_then(|v| {
+        let audit = interp.audit(Some(&v));
+        anyhow::ensure!(audit.is_clean(), "{audit}");
+        Ok(v)
+    });
     let result_str = result
         .and_then(|v| interp.display_value(&crate::type_system::env::Env::new(program.clone()), &v))
         .map(|s| format!("Ok: {s}"))
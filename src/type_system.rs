@@ -5,12 +5,15 @@ use crate::grammar::Decl;
 
 mod accesses;
 mod blocks;
+mod check_all;
 mod classes;
 pub mod env;
 mod expressions;
 pub mod in_flight;
 pub mod liveness;
 mod local_liens;
+pub mod locals;
+pub mod memo;
 mod methods;
 mod perm_matcher;
 mod places;
@@ -18,13 +21,14 @@ pub mod pop_normalize;
 pub mod predicates;
 mod redperms;
 mod statements;
-mod subtypes;
+pub mod subtypes;
 pub mod types;
 
+pub use check_all::{check_program_all, ClassFailure, ProgramReport};
+
 #[cfg(test)]
 mod tests;
 
-// ANCHOR: check_program
 judgment_fn! {
     pub fn check_program(
         program: ElaboratedProgram,
@@ -54,4 +58,3 @@ judgment_fn! {
         )
     }
 }
-// ANCHOR_END: check_program
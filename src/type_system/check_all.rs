@@ -0,0 +1,141 @@
+//! Checking every class of a program and reporting all failures.
+//!
+//! The `check_program` judgment stops at the first class that fails. For
+//! interactive use we would rather see every failing class at once, so
+//! [`check_program_all`] checks each class independently, spread across
+//! threads, and aggregates the failures into a [`ProgramReport`].
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use formality_core::judgment::FailedJudgment;
+
+use crate::elaborator::ElaboratedProgram;
+use crate::grammar::{Decl, MethodId, ValueId};
+
+use super::{
+    classes::{check_class, failing_methods},
+    memo::{with_memo, MemoReport},
+};
+
+/// A class that failed to type check.
+#[derive(Debug)]
+pub struct ClassFailure {
+    pub class: ValueId,
+
+    /// The failure of the class as a whole.
+    pub error: Box<FailedJudgment>,
+
+    /// The methods of the class that fail when checked on their own.
+    /// Empty if the failure is in a field, predicate, or drop body.
+    pub methods: Vec<(MethodId, Box<FailedJudgment>)>,
+}
+
+/// The result of [`check_program_all`].
+#[derive(Debug, Default)]
+pub struct ProgramReport {
+    /// Failing classes, in declaration order.
+    pub failures: Vec<ClassFailure>,
+
+    /// Judgment cache statistics, summed across threads.
+    pub memo: MemoReport,
+}
+
+impl ProgramReport {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// `Ok` if no class failed, otherwise an error listing every failure.
+    pub fn into_result(self) -> anyhow::Result<()> {
+        if self.is_ok() {
+            Ok(())
+        } else {
+            anyhow::bail!("{self}")
+        }
+    }
+}
+
+impl std::fmt::Display for ProgramReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} class(es) failed to type check:", self.failures.len())?;
+        for failure in &self.failures {
+            if failure.methods.is_empty() {
+                writeln!(f, "  class `{:?}`", failure.class)?;
+            } else {
+                let methods: Vec<String> = failure
+                    .methods
+                    .iter()
+                    .map(|(name, _)| format!("`{name:?}`"))
+                    .collect();
+                writeln!(
+                    f,
+                    "  class `{:?}`: method(s) {}",
+                    failure.class,
+                    methods.join(", ")
+                )?;
+            }
+        }
+
+        for failure in &self.failures {
+            if failure.methods.is_empty() {
+                writeln!(f, "\nclass `{:?}`:", failure.class)?;
+                writeln!(f, "{}", failure.error.format_leaves())?;
+            }
+            for (name, error) in &failure.methods {
+                writeln!(f, "\nclass `{:?}`, method `{name:?}`:", failure.class)?;
+                writeln!(f, "{}", error.format_leaves())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check every class of `program` independently, using up to one thread per
+/// available core, and report all the classes (and methods) that fail.
+pub fn check_program_all(program: &ElaboratedProgram) -> ProgramReport {
+    let decls = &program.decls;
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(decls.len())
+        .max(1);
+
+    let next_decl = AtomicUsize::new(0);
+    let results: Mutex<Vec<(usize, ClassFailure)>> = Mutex::new(vec![]);
+    let memo: Mutex<MemoReport> = Mutex::new(MemoReport::default());
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                let ((), report) = with_memo(|| loop {
+                    let index = next_decl.fetch_add(1, Ordering::Relaxed);
+                    let Some(decl) = decls.get(index) else {
+                        break;
+                    };
+                    if let Some(failure) = check_decl(program, decl) {
+                        results.lock().unwrap().push((index, failure));
+                    }
+                });
+                memo.lock().unwrap().merge(&report);
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    ProgramReport {
+        failures: results.into_iter().map(|(_, failure)| failure).collect(),
+        memo: memo.into_inner().unwrap(),
+    }
+}
+
+fn check_decl(program: &ElaboratedProgram, decl: &Decl) -> Option<ClassFailure> {
+    let Decl::ClassDecl(class_decl) = decl;
+    let error = check_class(program, class_decl).into_map().err()?;
+    Some(ClassFailure {
+        class: class_decl.name.clone(),
+        error,
+        methods: failing_methods(program, class_decl),
+    })
+}
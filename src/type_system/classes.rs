@@ -1,9 +1,10 @@
+use formality_core::judgment::FailedJudgment;
 use formality_core::judgment_fn;
 
 use crate::elaborator::ElaboratedProgram;
 use crate::grammar::{
-    Atomic, ClassDecl, ClassDeclBoundData, ClassPredicate, DropBody, FieldDecl, Kind, NamedTy,
-    Perm, Predicate, Ty, UniversalVar, Var, VarianceKind,
+    Atomic, ClassDecl, ClassDeclBoundData, ClassPredicate, DropBody, FieldDecl, Kind, MethodId,
+    NamedTy, Perm, Predicate, Ty, UniversalVar, Var, VarianceKind,
 };
 
 use super::{
@@ -15,7 +16,6 @@ use super::{
     types::check_type,
 };
 
-// ANCHOR: check_class
 judgment_fn! {
     pub fn check_class(
         program: ElaboratedProgram,
@@ -24,15 +24,10 @@ judgment_fn! {
         debug(decl, program)
 
         (
-            (let ClassDecl { class_predicate, name, binder } = decl)
-            (let env = Env::new(program))
+            (let ClassDecl { class_predicate, .. } = decl)
 
-            (let (env, substitution, ClassDeclBoundData { predicates, fields, methods, drop_body }) =
-                env.open_universally(binder))
-
-            (let class_ty = NamedTy::new(name, substitution))
-
-            (let env = env.add_assumptions(predicates))
+            (let (env, class_ty, substitution, ClassDeclBoundData { predicates, fields, methods, drop_body }) =
+                class_env(program, decl))
 
             (check_predicates(env, predicates) => ())
 
@@ -49,7 +44,48 @@ judgment_fn! {
         )
     }
 }
-// ANCHOR_END: check_class
+
+/// The env in which the members of `decl` are checked: the class's generic
+/// parameters are opened as universal variables and its where-clauses are assumed.
+/// Returns that env along with the class type (e.g., `Foo[!T]`), the universal
+/// variables, and the opened declaration. Tools that need to reproduce what
+/// `check_class` sees should start from here.
+pub fn class_env(
+    program: &ElaboratedProgram,
+    decl: &ClassDecl,
+) -> (Env, NamedTy, Vec<UniversalVar>, ClassDeclBoundData) {
+    let ClassDecl {
+        class_predicate: _,
+        name,
+        binder,
+    } = decl;
+    let env = Env::new(program.clone());
+    let (env, substitution, data) = env.open_universally(binder);
+    let class_ty = NamedTy::new(name.clone(), substitution.clone());
+    let env = env.add_assumptions(data.predicates.clone());
+    (env, class_ty, substitution, data)
+}
+
+/// Check each method of `decl` on its own, in the same environment that
+/// `check_class` uses, and return the methods that fail along with their errors.
+/// This is used to attribute the failure of a class to specific methods.
+pub fn failing_methods(
+    program: &ElaboratedProgram,
+    decl: &ClassDecl,
+) -> Vec<(MethodId, Box<FailedJudgment>)> {
+    let (env, class_ty, substitution, ClassDeclBoundData { methods, .. }) =
+        class_env(program, decl);
+
+    methods
+        .into_iter()
+        .filter_map(|method| {
+            match check_method(&class_ty, &env, &substitution, &method).into_map() {
+                Ok(_) => None,
+                Err(error) => Some((method.name.clone(), error)),
+            }
+        })
+        .collect()
+}
 
 judgment_fn! {
     fn check_drop_body(
@@ -98,7 +134,6 @@ judgment_fn! {
     }
 }
 
-// ANCHOR: check_field
 judgment_fn! {
     fn check_field(
         class_ty: NamedTy,
@@ -154,7 +189,6 @@ judgment_fn! {
         )
     }
 }
-// ANCHOR_END: check_field
 
 impl ClassDecl {
     /// Compute, for each generic parameter of this class,
@@ -45,7 +45,7 @@ pub trait InFlight: Sized {
 pub enum Transform<'a> {
     Give(&'a Place),
     Put(&'a [Var], &'a [Place]),
-    /// Alpha-rename: map `Var → Var`. Unlike `Put`, this operates on
+    /// Rename: map `Var → Var`. Unlike `Put`, this operates on
     /// variables (not places), so `Place` keeps its projections and
     /// raw `ValueId` sites (e.g., `let x`, `$$clear(x)`) are renamed
     /// naturally without going through `Place` at all.
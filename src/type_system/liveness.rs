@@ -1,16 +1,48 @@
-//! We do something really dumb and brute force to manage liveness.
-//! Basically, in between every statement, we have the option of making
-//! initialized variables be considered moved. Note that this could cause
-//! type check errors later on if that variable is used again. In "real life"
-//! we would use a liveness check to detect that possibility, but to keep
-//! these rules simple, we let the judgments just explore all possibilities.
+//! Liveness analysis.
+//!
+//! A place is *live* at some point if it (or some part of it) may be accessed later.
+//! The type checker threads the set of live places through its judgments as
+//! `live_after`, the places live after the term being typed. Liveness for lists of
+//! statements is computed by a backward dataflow analysis over their control-flow
+//! graph (see the `cfg` submodule), which accounts for `loop` back-edges, `break`,
+//! and `return`. `type_statements` computes the places live after each statement
+//! once, via [`LivePlaces::after_each`], and then looks them up as it goes.
+//!
+//! Inside of [`with_memo`](super::memo::with_memo), the graph of a list of statements
+//! is built and solved once: the results for it and for every block nested inside of it
+//! are recorded in the memo scope, so typing the nested blocks (and computing the places
+//! live before a statement) looks them up instead of solving again. Outside of a memo
+//! scope each query solves its own graph.
+//!
+//! A `break` only knows where it goes if its loop is part of the statements being
+//! analyzed; otherwise it is treated as falling through to the end of those statements.
+//! So that an answer does not depend on which list was solved first, a nested block
+//! containing such a `break` is not recorded when its enclosing body is solved; asked
+//! about on its own, it is solved on its own.
+//! The type system has no rules for `loop`, `break`, or `return` yet, so this is
+//! exercised only by the tests of this module.
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use formality_core::{cast_impl, Set, SetExt, Upcast};
 
 use crate::grammar::{Block, Expr, Place, PlaceExpr, Statement, Var};
 
+use super::memo;
+
+mod cfg;
+
+/// The memo table of [`statements_liveness`]. It maps each list of statements to
+/// its liveness for each set of places live after it, so that a lookup borrows the
+/// statements rather than cloning them into a key.
+const MEMO_TABLE: &str = "liveness";
+
+/// The recorded liveness of one list of statements, by the places live after it.
+type ListLiveness = Rc<RefCell<HashMap<LivePlaces, cfg::StatementsLiveness>>>;
+
 /// Tracks the set of live variables at a given point in execution.
 /// The `Default` impl returns an empty set.
 #[derive(Clone, Default, Debug, Ord, Eq, PartialEq, PartialOrd, Hash)]
@@ -37,6 +69,12 @@ impl LivePlaces {
         term.adjust_live_vars(self.clone())
     }
 
+    /// Compute the set of live-vars after each of `statements`, given that
+    /// `self` is the set live after all of them. The result has one entry per statement.
+    pub fn after_each(&self, statements: &[Statement]) -> Vec<Self> {
+        statements_liveness(statements, self).after_each
+    }
+
     /// Compute a new set of live-vars just before `terms` have been evaluated.
     pub fn before_all(&self, terms: impl IntoIterator<Item = impl AdjustLiveVars>) -> Self {
         terms
@@ -81,6 +119,50 @@ impl LivePlaces {
     }
 }
 
+/// The liveness of `statements`, given the places live after them. On a miss, solves
+/// the graph of `statements` and records the results for every nested block too.
+fn statements_liveness(
+    statements: &[Statement],
+    live_after: &LivePlaces,
+) -> cfg::StatementsLiveness {
+    if let Some(liveness) = recorded_liveness(statements, live_after) {
+        return liveness;
+    }
+
+    let (liveness, nested) = cfg::Cfg::new(statements).liveness(live_after);
+    for ((nested_statements, nested_live_after), nested_liveness) in nested {
+        record_liveness(nested_statements, nested_live_after, nested_liveness);
+    }
+    record_liveness(statements.to_vec(), live_after.clone(), liveness.clone());
+    liveness
+}
+
+/// The liveness recorded for `statements` in the memo scope, if any.
+fn recorded_liveness(
+    statements: &[Statement],
+    live_after: &LivePlaces,
+) -> Option<cfg::StatementsLiveness> {
+    let list = memo::lookup::<Vec<Statement>, _, ListLiveness>(MEMO_TABLE, statements)?;
+    let liveness = list.borrow().get(live_after).cloned();
+    liveness
+}
+
+fn record_liveness(
+    statements: Vec<Statement>,
+    live_after: LivePlaces,
+    liveness: cfg::StatementsLiveness,
+) {
+    let list = match memo::lookup::<Vec<Statement>, _, ListLiveness>(MEMO_TABLE, &statements[..]) {
+        Some(list) => list,
+        None => {
+            let list = ListLiveness::default();
+            memo::record(MEMO_TABLE, statements, list.clone());
+            list
+        }
+    };
+    list.borrow_mut().insert(live_after, liveness);
+}
+
 pub trait AdjustLiveVars: std::fmt::Debug {
     fn adjust_live_vars(&self, vars: LivePlaces) -> LivePlaces;
 }
@@ -99,29 +181,13 @@ impl<T: AdjustLiveVars> AdjustLiveVars for Arc<T> {
 
 impl AdjustLiveVars for Vec<Statement> {
     fn adjust_live_vars(&self, vars: LivePlaces) -> LivePlaces {
-        self.iter()
-            .rev()
-            .fold(vars, |vars, stmt| stmt.adjust_live_vars(vars))
+        statements_liveness(self, &vars).on_entry
     }
 }
 
 impl AdjustLiveVars for Statement {
     fn adjust_live_vars(&self, live: LivePlaces) -> LivePlaces {
-        match self {
-            Statement::Expr(expr) => expr.adjust_live_vars(live),
-            Statement::Let(var, _ty, expr) => expr.adjust_live_vars(live.overwritten(var)),
-            Statement::Reassign(place, expr) => {
-                // x.f.g will be assigned...
-                let live = live.overwritten(place);
-
-                // ...and computing the expression
-                expr.adjust_live_vars(live)
-            }
-            Statement::Loop(expr) => expr.adjust_live_vars(live),
-            Statement::Break => live,
-            Statement::Return(expr) => expr.adjust_live_vars(live),
-            Statement::Print(expr) => expr.adjust_live_vars(live),
-        }
+        statements_liveness(std::slice::from_ref(self), &live).on_entry
     }
 }
 
@@ -210,3 +276,6 @@ impl AdjustLiveVars for Var {
         vars.accessed(self)
     }
 }
+
+#[cfg(test)]
+mod tests;
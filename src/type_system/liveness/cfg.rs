@@ -0,0 +1,318 @@
+//! Backward liveness dataflow over a control-flow graph.
+//!
+//! A list of statements is lowered into a graph whose nodes either access a
+//! place, overwrite a place, or do nothing (branch and join points, loop heads,
+//! `break`). Edges follow evaluation order, including the back-edge of each
+//! `loop` and the jumps out of it made by `break` and `return`. Liveness is
+//! then the usual fixed point: the places live on entry to a node are the
+//! result of its transfer function applied to the union of the places live on
+//! entry to its successors.
+//!
+//! For straight-line code this computes exactly what folding
+//! [`AdjustLiveVars`](super::AdjustLiveVars) backwards over the statements would.
+//!
+//! Solving the graph of a method body gives the liveness of every block nested
+//! inside of it as well, with `break`s going to their actual loop; see [`Cfg::liveness`].
+//! That is only what solving a block on its own would give if none of its `break`s
+//! leave it, so blocks with such a `break` are left out.
+
+use formality_core::Upcast;
+
+use crate::grammar::{Block, Expr, Place, Statement};
+
+use super::LivePlaces;
+
+type NodeId = usize;
+
+#[derive(Debug)]
+enum NodeKind {
+    /// Where the statements fall through to, or where a `break` goes if its loop
+    /// is not part of the graph. Its liveness is given from the outside.
+    Exit,
+
+    /// Function return; nothing is live afterwards.
+    Return,
+
+    /// The place is read.
+    Access(Place),
+
+    /// The place is assigned.
+    Overwrite(Place),
+
+    /// Branch, join, loop head, or `break`.
+    Nop,
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: NodeKind,
+    successors: Vec<NodeId>,
+}
+
+/// A list of statements in the graph: the top-level statements,
+/// or the statements of a nested block.
+#[derive(Debug)]
+struct StatementList {
+    statements: Vec<Statement>,
+
+    /// The node where each of the statements begins.
+    entries: Vec<NodeId>,
+
+    /// Where the statements fall through to.
+    succ: NodeId,
+
+    /// True if a `break` in the statements goes to a loop outside of them.
+    escapes: bool,
+}
+
+/// Liveness of a list of statements, given the places live after them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct StatementsLiveness {
+    /// The places live before the statements run.
+    pub on_entry: LivePlaces,
+
+    /// The places live after each statement, i.e., on entry to the next
+    /// statement (or the places live after the list, for the last one).
+    pub after_each: Vec<LivePlaces>,
+}
+
+/// The control-flow graph of a list of statements.
+#[derive(Debug)]
+pub(super) struct Cfg {
+    nodes: Vec<Node>,
+    exit: NodeId,
+
+    /// Every list of statements in the graph. The top-level statements come last,
+    /// after the blocks nested inside of them.
+    lists: Vec<StatementList>,
+}
+
+impl Cfg {
+    pub(super) fn new(statements: &[Statement]) -> Self {
+        let mut builder = CfgBuilder {
+            nodes: vec![],
+            break_targets: vec![],
+            breaks: vec![],
+            lists: vec![],
+        };
+        let exit = builder.node(NodeKind::Exit, vec![]);
+        builder.statements(statements, exit, exit);
+        Cfg {
+            nodes: builder.nodes,
+            exit,
+            lists: builder.lists,
+        }
+    }
+
+    /// Compute the places live on entry to every node,
+    /// given the places live where the statements fall through.
+    fn solve(&self, live_at_exit: &LivePlaces) -> Vec<LivePlaces> {
+        let mut live_in = vec![LivePlaces::default(); self.nodes.len()];
+        live_in[self.exit] = live_at_exit.clone();
+
+        // Nodes are created back to front, so iterating in index order visits
+        // successors before predecessors except along loop back-edges.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (id, node) in self.nodes.iter().enumerate() {
+                let live_out = node
+                    .successors
+                    .iter()
+                    .fold(LivePlaces::default(), |live, &succ| {
+                        live.union(live_in[succ].clone())
+                    });
+                let live = match &node.kind {
+                    NodeKind::Exit => continue,
+                    NodeKind::Return => LivePlaces::default(),
+                    NodeKind::Access(place) => live_out.accessed(place),
+                    NodeKind::Overwrite(place) => live_out.overwritten(place),
+                    NodeKind::Nop => live_out,
+                };
+                if live != live_in[id] {
+                    live_in[id] = live;
+                    changed = true;
+                }
+            }
+        }
+
+        live_in
+    }
+
+    /// The liveness of the top-level statements, given the places live where they
+    /// fall through, followed by the liveness of the statements of every nested block
+    /// whose `break`s stay inside of it, each with the places live after that block.
+    pub(super) fn liveness(
+        &self,
+        live_at_exit: &LivePlaces,
+    ) -> (
+        StatementsLiveness,
+        Vec<((Vec<Statement>, LivePlaces), StatementsLiveness)>,
+    ) {
+        let live_in = self.solve(live_at_exit);
+        let (top, nested) = self
+            .lists
+            .split_last()
+            .expect("the top-level statements are always recorded");
+        let liveness = |list: &StatementList| {
+            let live_after = live_in[list.succ].clone();
+            StatementsLiveness {
+                on_entry: match list.entries.first() {
+                    Some(&entry) => live_in[entry].clone(),
+                    None => live_after.clone(),
+                },
+                after_each: list
+                    .entries
+                    .iter()
+                    .skip(1)
+                    .map(|&entry| live_in[entry].clone())
+                    .chain(list.entries.first().map(|_| live_after.clone()))
+                    .collect(),
+            }
+        };
+        let nested = nested
+            .iter()
+            .filter(|list| !list.escapes)
+            .map(|list| {
+                let key = (list.statements.clone(), live_in[list.succ].clone());
+                (key, liveness(list))
+            })
+            .collect();
+        (liveness(top), nested)
+    }
+}
+
+struct CfgBuilder {
+    nodes: Vec<Node>,
+
+    /// Where `break` goes for each enclosing loop, innermost last.
+    break_targets: Vec<NodeId>,
+
+    /// For each `break` lowered so far, how many loops enclosed it. A `break`
+    /// leaves a list of statements if no more loops enclose it than the list.
+    breaks: Vec<usize>,
+
+    /// Every list of statements lowered so far.
+    lists: Vec<StatementList>,
+}
+
+impl CfgBuilder {
+    fn node(&mut self, kind: NodeKind, successors: Vec<NodeId>) -> NodeId {
+        self.nodes.push(Node { kind, successors });
+        self.nodes.len() - 1
+    }
+
+    /// Lower `statement`, which continues to `succ`, and return its entry node.
+    /// A `break` outside of any loop in the graph goes to `exit`.
+    fn statement(&mut self, statement: &Statement, succ: NodeId, exit: NodeId) -> NodeId {
+        match statement {
+            Statement::Expr(expr) | Statement::Print(expr) => self.expr(expr, succ, exit),
+            Statement::Let(var, _ty, expr) => {
+                let place: Place = var.upcast();
+                let assign = self.node(NodeKind::Overwrite(place), vec![succ]);
+                self.expr(expr, assign, exit)
+            }
+            Statement::Reassign(place, expr) => {
+                let assign = self.node(NodeKind::Overwrite(place.clone()), vec![succ]);
+                self.expr(expr, assign, exit)
+            }
+            Statement::Loop(block) => {
+                // The successors of the loop head are filled in once the body exists.
+                let head = self.node(NodeKind::Nop, vec![]);
+                self.break_targets.push(succ);
+                let body = self.block(block, head, exit);
+                self.break_targets.pop();
+                self.nodes[head].successors.push(body);
+                head
+            }
+            Statement::Break => {
+                self.breaks.push(self.break_targets.len());
+                let target = self.break_targets.last().copied().unwrap_or(exit);
+                self.node(NodeKind::Nop, vec![target])
+            }
+            Statement::Return(expr) => {
+                let ret = self.node(NodeKind::Return, vec![]);
+                self.expr(expr, ret, exit)
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block, succ: NodeId, exit: NodeId) -> NodeId {
+        let Block { statements } = block;
+        self.statements(statements, succ, exit)
+    }
+
+    /// Lower `statements`, which continue to `succ`, record where each of them
+    /// begins, and return the entry node of the first (or `succ` if there are none).
+    fn statements(&mut self, statements: &[Statement], succ: NodeId, exit: NodeId) -> NodeId {
+        let loops = self.break_targets.len();
+        let breaks = self.breaks.len();
+        let mut entries = vec![];
+        let mut next = succ;
+        for statement in statements.iter().rev() {
+            next = self.statement(statement, next, exit);
+            entries.push(next);
+        }
+        entries.reverse();
+        self.lists.push(StatementList {
+            statements: statements.to_vec(),
+            entries,
+            succ,
+            escapes: self.breaks[breaks..].iter().any(|&depth| depth <= loops),
+        });
+        next
+    }
+
+    /// Lower `exprs`, evaluated left to right.
+    fn exprs<'e>(
+        &mut self,
+        exprs: impl DoubleEndedIterator<Item = &'e Expr>,
+        succ: NodeId,
+        exit: NodeId,
+    ) -> NodeId {
+        exprs
+            .rev()
+            .fold(succ, |succ, expr| self.expr(expr, succ, exit))
+    }
+
+    fn expr(&mut self, expr: &Expr, succ: NodeId, exit: NodeId) -> NodeId {
+        match expr {
+            Expr::Block(block) => self.block(block, succ, exit),
+            Expr::Integer(_)
+            | Expr::True
+            | Expr::False
+            | Expr::Clear(_)
+            | Expr::SizeOf(_)
+            | Expr::Panic => succ,
+            Expr::Place(place_expr) => {
+                self.node(NodeKind::Access(place_expr.place.clone()), vec![succ])
+            }
+            Expr::BinaryOp(lhs, _op, rhs) => self.exprs([&**lhs, &**rhs].into_iter(), succ, exit),
+            Expr::Tuple(exprs) => self.exprs(exprs.iter(), succ, exit),
+            Expr::Share(expr)
+            | Expr::ArrayNew(_, expr)
+            | Expr::ArrayCapacity(_, expr)
+            | Expr::IsLastRef(_, expr) => self.expr(expr, succ, exit),
+            Expr::Call(func, _method_name, _parameters, args) => {
+                let args = self.exprs(args.iter(), succ, exit);
+                self.expr(func, args, exit)
+            }
+            Expr::New(_ty, _parameters, args) => self.exprs(args.iter(), succ, exit),
+            Expr::If(cond, if_true, if_false) => {
+                let if_true = self.expr(if_true, succ, exit);
+                let if_false = self.expr(if_false, succ, exit);
+                let branch = self.node(NodeKind::Nop, vec![if_true, if_false]);
+                self.expr(cond, branch, exit)
+            }
+            Expr::ArrayGive(_, array, index) => {
+                self.exprs([&**array, &**index].into_iter(), succ, exit)
+            }
+            Expr::ArrayDrop(_, array, from, to) => {
+                self.exprs([&**array, &**from, &**to].into_iter(), succ, exit)
+            }
+            Expr::ArrayWrite(_, array, index, value) => {
+                self.exprs([&**array, &**index, &**value].into_iter(), succ, exit)
+            }
+        }
+    }
+}
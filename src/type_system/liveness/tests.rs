@@ -0,0 +1,126 @@
+use crate::grammar::{Block, Place};
+use crate::type_system::memo;
+
+use super::{recorded_liveness, LivePlaces};
+
+fn block(text: &str) -> Block {
+    crate::dada_lang::term(text)
+}
+
+/// Live places before `text`, followed by the live places after each of its statements.
+fn liveness(text: &str) -> String {
+    let Block { statements } = block(text);
+    let live_after = LivePlaces::default();
+    let mut lines = vec![format!("before: {:?}", live_after.before(&statements))];
+    for (index, live) in live_after.after_each(&statements).iter().enumerate() {
+        lines.push(format!("after {index}: {live:?}"));
+    }
+    lines.join("\n")
+}
+
+#[test]
+fn straight_line() {
+    expect_test::expect![[r#"
+        before: LivePlaces { accessed: {a, b}, traversed: {} }
+        after 0: LivePlaces { accessed: {b, x}, traversed: {x} }
+        after 1: LivePlaces { accessed: {x}, traversed: {} }
+        after 2: LivePlaces { accessed: {}, traversed: {} }"#]]
+    .assert_eq(&liveness("{ let x = a.give; x.f = b.give; print(x.ref); }"));
+}
+
+/// Nothing after a `return` is live before it.
+#[test]
+fn return_ends_liveness() {
+    expect_test::expect![[r#"
+        before: LivePlaces { accessed: {a}, traversed: {} }
+        after 0: LivePlaces { accessed: {b}, traversed: {} }
+        after 1: LivePlaces { accessed: {}, traversed: {} }"#]]
+    .assert_eq(&liveness("{ return a.give; print(b.ref); }"));
+}
+
+/// `break` continues after the loop, skipping the rest of the body.
+#[test]
+fn break_jumps_past_loop() {
+    expect_test::expect![[r#"
+        before: LivePlaces { accessed: {a, c}, traversed: {} }
+        after 0: LivePlaces { accessed: {c}, traversed: {} }
+        after 1: LivePlaces { accessed: {}, traversed: {} }"#]]
+    .assert_eq(&liveness(
+        "{ loop { print(a.ref); break; print(b.ref); } print(c.ref); }",
+    ));
+}
+
+/// A loop without a `break` never exits, so nothing after it is live.
+#[test]
+fn loop_without_break() {
+    expect_test::expect![[r#"
+        before: LivePlaces { accessed: {a}, traversed: {} }
+        after 0: LivePlaces { accessed: {c}, traversed: {} }
+        after 1: LivePlaces { accessed: {}, traversed: {} }"#]]
+    .assert_eq(&liveness("{ loop { print(a.ref); } print(c.ref); }"));
+}
+
+fn place(text: &str) -> Place {
+    crate::dada_lang::term(text)
+}
+
+/// Inside of a memo scope, solving the statements of a body also records the
+/// liveness of the blocks nested inside of it, with the places live after them.
+#[test]
+fn nested_blocks_are_solved_with_their_body() {
+    let Block { statements } = block("{ let x = a.give; if x.give { print(b.ref); } else { }; }");
+    let if_true = block("{ print(b.ref); }").statements;
+
+    let (nested, _report) = memo::with_memo(|| {
+        LivePlaces::default().after_each(&statements);
+        recorded_liveness(&if_true, &LivePlaces::default())
+    });
+    let nested = nested.expect("the `if` branch was recorded");
+    assert_eq!(nested.on_entry, LivePlaces::default().accessed(place("b")));
+}
+
+/// A loop body whose `break` goes to a loop outside of it is not recorded while
+/// solving its enclosing body: asked about on its own, where the `break` falls
+/// through, it gets the same answer whichever is solved first.
+#[test]
+fn blocks_that_break_out_are_solved_on_their_own() {
+    let Block { statements } =
+        block("{ loop { print(a.ref); break; print(b.ref); } print(c.ref); }");
+    let loop_body = block("{ print(a.ref); break; print(b.ref); }").statements;
+    let live_at_head = LivePlaces::default()
+        .accessed(place("a"))
+        .accessed(place("c"));
+    let alone = live_at_head.after_each(&loop_body);
+    assert_eq!(alone[0], live_at_head);
+
+    let (recorded, _report) = memo::with_memo(|| {
+        LivePlaces::default().after_each(&statements);
+        let recorded = recorded_liveness(&loop_body, &live_at_head);
+        assert_eq!(live_at_head.after_each(&loop_body), alone);
+        recorded
+    });
+    assert_eq!(recorded, None);
+
+    let (body_first, _report) = memo::with_memo(|| {
+        let body = live_at_head.after_each(&loop_body);
+        LivePlaces::default().after_each(&statements);
+        assert_eq!(live_at_head.after_each(&loop_body), body);
+        body
+    });
+    assert_eq!(body_first, alone);
+}
+
+/// Within a memo scope, asking again gives the recorded answer, which matches
+/// what solving without a memo scope gives.
+#[test]
+fn cached_liveness_matches_uncached() {
+    let Block { statements } = block("{ let x = a.give; x.f = b.give; print(x.ref); }");
+    let uncached = LivePlaces::default().after_each(&statements);
+    let (cached, _report) = memo::with_memo(|| {
+        let first = LivePlaces::default().after_each(&statements);
+        let second = LivePlaces::default().after_each(&statements);
+        assert_eq!(first, second);
+        second
+    });
+    assert_eq!(cached, uncached);
+}
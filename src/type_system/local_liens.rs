@@ -1,4 +1,4 @@
-use formality_core::{judgment_fn, term, Set, SetExt};
+use formality_core::{judgment_fn, term, ProvenSet, Set, SetExt, Upcast};
 
 use crate::{
     grammar::{NamedTy, Parameter, Perm, Place, Ty, Variable},
@@ -16,99 +16,110 @@ pub enum Lien {
 }
 // ANCHOR_END: Lien
 
-judgment_fn! {
-    /// Compute the liens required for a parameter `a` to be valid.
-    pub fn liens(
-        env: Env,
-        a: Parameter,
-    ) => Set<Lien> {
-        debug(a, env)
-
-        // PERMISSIONS
-        // (Perm::Var covered under "VARIABLES" below)
-
-        (
-            ----------------------------------- ("perm-given")
-            (liens(_env, Perm::Given) => ())
-        )
-
-        (
-            ----------------------------------- ("perm-shared")
-            (liens(_env, Perm::Shared) => ())
-        )
-
-        (
-            (let liens: Set<Lien> = Set::new())
-            (for_all(place in places) with(liens)
-                (place_liens(env, (), place) => new_liens)
-                (let liens: Set<Lien> = (&liens).union_with(new_liens)))
-            ----------------------------------- ("perm-given")
-            (liens(env, Perm::Mv(places)) => liens)
-        )
-
-        (
-            (let liens: Set<Lien> = Set::new())
-            (for_all(place in places) with(liens)
-                (place_liens(env, (Lien::rf(place),), place) => new_liens)
-                (let liens: Set<Lien> = (&liens).union_with(new_liens)))
-            ----------------------------------- ("perm-shared")
-            (liens(env, Perm::Rf(places)) => liens)
-        )
-
-        (
-            (let liens: Set<Lien> = Set::new())
-            (for_all(place in places) with(liens)
-                (place_liens(env, (Lien::mt(place),), place) => new_liens)
-                (let liens: Set<Lien> = (&liens).union_with(new_liens)))
-            ----------------------------------- ("perm-leased")
-            (liens(env, Perm::Mt(places)) => liens)
-        )
-
-        (
-            (liens(env, &**lhs) => liens_lhs)
-            (apply_liens(env, liens_lhs, &**rhs) => liens)
-            ----------------------------------- ("perm-apply")
-
-            (liens(env, Perm::Apply(lhs, rhs)) => liens)
-        )
-
-        (
-            (let all_liens: Set<Lien> = Set::new())
-            (for_all(perm in perms) with(all_liens)
-                (liens(env, perm) => branch_liens)
-                (let all_liens: Set<Lien> = (&all_liens).union_with(branch_liens)))
-            ----------------------------------- ("perm-or")
-            (liens(env, Perm::Or(perms)) => all_liens)
-        )
-
-        // TYPES
-        // (Ty::Var covered under "VARIABLES" below)
-
-        (
-            (let liens_parameters: Set<Lien> = Set::new())
-            (for_all(parameter in parameters) with(liens_parameters)
-                (liens(env, parameter) => new_liens)
-                (let liens_parameters: Set<Lien> = (&liens_parameters).union_with(new_liens)))
-            ----------------------------------- ("ty-named")
-            (liens(env, NamedTy { name: _, parameters }) => liens_parameters)
-        )
-
-        (
-            (liens(env, lhs) => liens_lhs)
-            (apply_liens(env, liens_lhs, &**rhs) => liens)
-            ----------------------------------- ("ty-apply-perm")
-            (liens(env, Ty::ApplyPerm(lhs, rhs)) => liens)
-        )
-
-        // VARIABLES (either `Ty` or `Perm`)
-
-        (
-            ----------------------------------- ("!X")
-            (liens(_env, Variable::UniversalVar(_)) => ())
-        )
+/// `liens` is memoized: it is wrapped below by a function of the same name
+/// that consults the cache (see [`crate::type_system::memo`]).
+mod uncached {
+    use super::*;
+
+    judgment_fn! {
+        /// Compute the liens required for a parameter `a` to be valid.
+        pub fn liens(
+            env: Env,
+            a: Parameter,
+        ) => Set<Lien> {
+            debug(a, env)
+
+            // PERMISSIONS
+            // (Perm::Var covered under "VARIABLES" below)
+
+            (
+                ----------------------------------- ("perm-given")
+                (liens(_env, Perm::Given) => ())
+            )
+
+            (
+                ----------------------------------- ("perm-shared")
+                (liens(_env, Perm::Shared) => ())
+            )
+
+            (
+                (let liens: Set<Lien> = Set::new())
+                (for_all(place in places) with(liens)
+                    (place_liens(env, (), place) => new_liens)
+                    (let liens: Set<Lien> = (&liens).union_with(new_liens)))
+                ----------------------------------- ("perm-given")
+                (liens(env, Perm::Mv(places)) => liens)
+            )
+
+            (
+                (let liens: Set<Lien> = Set::new())
+                (for_all(place in places) with(liens)
+                    (place_liens(env, (Lien::rf(place),), place) => new_liens)
+                    (let liens: Set<Lien> = (&liens).union_with(new_liens)))
+                ----------------------------------- ("perm-shared")
+                (liens(env, Perm::Rf(places)) => liens)
+            )
+
+            (
+                (let liens: Set<Lien> = Set::new())
+                (for_all(place in places) with(liens)
+                    (place_liens(env, (Lien::mt(place),), place) => new_liens)
+                    (let liens: Set<Lien> = (&liens).union_with(new_liens)))
+                ----------------------------------- ("perm-leased")
+                (liens(env, Perm::Mt(places)) => liens)
+            )
+
+            (
+                (liens(env, &**lhs) => liens_lhs)
+                (apply_liens(env, liens_lhs, &**rhs) => liens)
+                ----------------------------------- ("perm-apply")
+
+                (liens(env, Perm::Apply(lhs, rhs)) => liens)
+            )
+
+            (
+                (let all_liens: Set<Lien> = Set::new())
+                (for_all(perm in perms) with(all_liens)
+                    (liens(env, perm) => branch_liens)
+                    (let all_liens: Set<Lien> = (&all_liens).union_with(branch_liens)))
+                ----------------------------------- ("perm-or")
+                (liens(env, Perm::Or(perms)) => all_liens)
+            )
+
+            // TYPES
+            // (Ty::Var covered under "VARIABLES" below)
+
+            (
+                (let liens_parameters: Set<Lien> = Set::new())
+                (for_all(parameter in parameters) with(liens_parameters)
+                    (liens(env, parameter) => new_liens)
+                    (let liens_parameters: Set<Lien> = (&liens_parameters).union_with(new_liens)))
+                ----------------------------------- ("ty-named")
+                (liens(env, NamedTy { name: _, parameters }) => liens_parameters)
+            )
+
+            (
+                (liens(env, lhs) => liens_lhs)
+                (apply_liens(env, liens_lhs, &**rhs) => liens)
+                ----------------------------------- ("ty-apply-perm")
+                (liens(env, Ty::ApplyPerm(lhs, rhs)) => liens)
+            )
+
+            // VARIABLES (either `Ty` or `Perm`)
+
+            (
+                ----------------------------------- ("!X")
+                (liens(_env, Variable::UniversalVar(_)) => ())
+            )
+        }
     }
 }
 
+pub fn liens(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<Set<Lien>> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("liens", key, |(env, a)| uncached::liens(env, a))
+}
+
 judgment_fn! {
     fn place_liens(
         env: Env,
@@ -0,0 +1,65 @@
+//! The local variables in scope at each statement of a method body, for tools
+//! that show the types of variables (e.g., hover in the language server).
+
+use formality_core::Fallible;
+
+use crate::elaborator::ElaboratedProgram;
+use crate::grammar::{ClassDeclBoundData, MethodBody, MethodDeclBoundData, MethodId, ValueId};
+
+use super::{
+    classes::class_env,
+    env::Env,
+    liveness::LivePlaces,
+    memo,
+    methods::{method_env, open_method},
+    statements::type_statement,
+};
+
+/// The env before each top-level statement of the body of `method_name`,
+/// followed by the env after the last one. The class and method are opened with
+/// the helpers that `check_class` and `check_method` use; the statements are then
+/// typed one at a time, stopping at the first that does not type check, so the
+/// result is shorter than the body when the body has a type error.
+pub fn method_envs(
+    program: &ElaboratedProgram,
+    class_name: &ValueId,
+    method_name: &MethodId,
+) -> Fallible<Vec<Env>> {
+    let class_decl = program.class_named(class_name)?;
+    let (env, class_ty, substitution, ClassDeclBoundData { methods, .. }) =
+        class_env(program, class_decl);
+
+    let Some(method) = methods.iter().find(|method| method.name == *method_name) else {
+        anyhow::bail!("class `{class_name:?}` has no method `{method_name:?}`");
+    };
+    let (
+        env,
+        MethodDeclBoundData {
+            this,
+            inputs,
+            output: _,
+            predicates,
+            body,
+        },
+    ) = open_method(&env, &substitution, method);
+    let mut env = method_env(&env, &class_ty, &this, &predicates, &inputs)?;
+
+    let mut envs = vec![env.clone()];
+    let MethodBody::Block(block) = body else {
+        return Ok(envs);
+    };
+    let live_afters = LivePlaces::default().after_each(&block.statements);
+    memo::with_memo(|| {
+        for (statement, live_after) in block.statements.iter().zip(live_afters) {
+            let Ok(typings) = type_statement(&env, live_after, statement).into_map() else {
+                break;
+            };
+            let Some((next_env, _ty)) = typings.into_keys().next() else {
+                break;
+            };
+            env = next_env;
+            envs.push(env.clone());
+        }
+    });
+    Ok(envs)
+}
@@ -0,0 +1,238 @@
+//! Memoization of frequently repeated judgments.
+//!
+//! Judgments like `prove_is_copy` or `liens` are invoked over and over with the
+//! same inputs, e.g., once per field inside a `for_all` or once per value touched
+//! by the interpreter. Those judgments are defined with `judgment_fn!` in a private
+//! `uncached` module next to a wrapper of the same name that consults the cache
+//! via [`memoize`], so callers (and proof trees) are unaffected.
+//!
+//! The cache only exists while inside of [`with_memo`], which is expected to wrap
+//! the checking or interpretation of a single program. Outside of it, [`memoize`]
+//! simply invokes the judgment. Cached entries are full `ProvenSet`s, so the
+//! result of a cached judgment (including its proof trees or failure) is exactly
+//! what the judgment would have returned.
+//!
+//! Only judgments whose recursion is structural should be memoized: if a judgment
+//! can reach itself with the same inputs, the result it computes in the inner
+//! invocation depends on formality's cycle handling and must not be reused.
+
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use formality_core::{Map, ProvenSet};
+
+/// Hit and miss counts for one memoized judgment.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoStats {
+    /// Fraction of invocations answered from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Statistics for every memoized judgment invoked inside a [`with_memo`] scope.
+#[derive(Clone, Debug, Default)]
+pub struct MemoReport {
+    pub judgments: Map<&'static str, MemoStats>,
+}
+
+impl MemoReport {
+    /// Add the statistics from `other` into `self`.
+    pub fn merge(&mut self, other: &MemoReport) {
+        for (name, stats) in &other.judgments {
+            let entry = self.judgments.entry(name).or_default();
+            entry.hits += stats.hits;
+            entry.misses += stats.misses;
+        }
+    }
+
+    /// Combined statistics across all judgments.
+    pub fn total(&self) -> MemoStats {
+        self.judgments
+            .values()
+            .fold(MemoStats::default(), |a, b| MemoStats {
+                hits: a.hits + b.hits,
+                misses: a.misses + b.misses,
+            })
+    }
+}
+
+impl std::fmt::Display for MemoReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<30} {:>10} {:>10} {:>8}",
+            "judgment", "hits", "misses", "hit rate"
+        )?;
+        let total = self.total();
+        for (name, stats) in self.judgments.iter().chain([(&"total", &total)]) {
+            writeln!(
+                f,
+                "{:<30} {:>10} {:>10} {:>7.1}%",
+                name,
+                stats.hits,
+                stats.misses,
+                stats.hit_rate() * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemoScope {
+    /// One table per judgment, each a `HashMap<K, ProvenSet<V>>` for that judgment's `K` and `V`,
+    /// plus the tables of [`record`].
+    tables: HashMap<&'static str, Box<dyn Any>>,
+
+    report: MemoReport,
+}
+
+thread_local! {
+    static SCOPE: RefCell<Option<MemoScope>> = const { RefCell::new(None) };
+}
+
+/// Run `op` with memoization enabled on the current thread and return its result
+/// along with the cache statistics. The cache is discarded when `op` returns.
+///
+/// Nested calls share the outermost cache; the inner call reports no statistics.
+pub fn with_memo<R>(op: impl FnOnce() -> R) -> (R, MemoReport) {
+    let already_active = SCOPE.with(|scope| scope.borrow().is_some());
+    if already_active {
+        return (op(), MemoReport::default());
+    }
+
+    SCOPE.with(|scope| *scope.borrow_mut() = Some(MemoScope::default()));
+
+    // Clear the scope even if `op` panics, so that a later scope starts fresh.
+    struct ClearOnDrop;
+    impl Drop for ClearOnDrop {
+        fn drop(&mut self) {
+            SCOPE.with(|scope| scope.borrow_mut().take());
+        }
+    }
+    let _clear = ClearOnDrop;
+
+    let result = op();
+    let report = SCOPE.with(|scope| {
+        scope
+            .borrow()
+            .as_ref()
+            .map(|s| s.report.clone())
+            .unwrap_or_default()
+    });
+    (result, report)
+}
+
+/// Return the cached result of judgment `name` for `key`, computing it with `judgment`
+/// if it is not yet known. Outside of [`with_memo`], this just calls `judgment`.
+///
+/// The cache is not borrowed while `judgment` runs, so it may itself invoke memoized judgments.
+pub fn memoize<K, V>(
+    name: &'static str,
+    key: K,
+    judgment: impl FnOnce(&K) -> ProvenSet<V>,
+) -> ProvenSet<V>
+where
+    K: Hash + Eq + 'static,
+    V: Clone + 'static,
+{
+    let cached = SCOPE.with(|scope| {
+        let mut scope = scope.borrow_mut();
+        let scope = scope.as_mut()?;
+        let cached = table::<K, ProvenSet<V>>(scope, name).get(&key).cloned();
+        let stats = scope.report.judgments.entry(name).or_default();
+        match cached {
+            Some(result) => {
+                stats.hits += 1;
+                Some(Some(result))
+            }
+            None => {
+                stats.misses += 1;
+                Some(None)
+            }
+        }
+    });
+
+    match cached {
+        // Not inside of `with_memo`.
+        None => judgment(&key),
+
+        Some(Some(result)) => {
+            crate::profile::memo_hit(name);
+            result
+        }
+
+        Some(None) => {
+            let result = judgment(&key);
+            SCOPE.with(|scope| {
+                if let Some(scope) = scope.borrow_mut().as_mut() {
+                    table::<K, ProvenSet<V>>(scope, name).insert(key, result.clone());
+                }
+            });
+            result
+        }
+    }
+}
+
+/// Look up the value that [`record`] stored for `key` in the table `name`,
+/// whose keys are `K`s. As with a `HashMap`, `key` may be a borrowed form of
+/// `K` (e.g., `[T]` for `Vec<T>`), so that looking up does not build a `K`.
+/// Always `None` outside of [`with_memo`].
+///
+/// Unlike [`memoize`], this is meant for analyses that compute many results at
+/// once (e.g., liveness for every block of a method body) rather than for
+/// judgments, so lookups are not counted in the [`MemoReport`].
+pub fn lookup<K, Q, V>(name: &'static str, key: &Q) -> Option<V>
+where
+    K: Borrow<Q> + Hash + Eq + 'static,
+    Q: Hash + Eq + ?Sized,
+    V: Clone + 'static,
+{
+    SCOPE.with(|scope| {
+        let mut scope = scope.borrow_mut();
+        let scope = scope.as_mut()?;
+        table::<K, V>(scope, name).get(key).cloned()
+    })
+}
+
+/// Store `value` for `key` in the table `name`, for a later [`lookup`].
+/// Does nothing outside of [`with_memo`].
+pub fn record<K, V>(name: &'static str, key: K, value: V)
+where
+    K: Hash + Eq + 'static,
+    V: Clone + 'static,
+{
+    SCOPE.with(|scope| {
+        if let Some(scope) = scope.borrow_mut().as_mut() {
+            table::<K, V>(scope, name).insert(key, value);
+        }
+    });
+}
+
+/// The table `name`, mapping `K`s to `V`s, creating it if needed.
+fn table<K, V>(scope: &mut MemoScope, name: &'static str) -> &mut HashMap<K, V>
+where
+    K: Hash + Eq + 'static,
+    V: 'static,
+{
+    scope
+        .tables
+        .entry(name)
+        .or_insert_with(|| Box::new(HashMap::<K, V>::new()))
+        .downcast_mut()
+        .unwrap_or_else(|| panic!("memo table `{name}` used with inconsistent types"))
+}
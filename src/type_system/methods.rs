@@ -1,8 +1,8 @@
-use formality_core::judgment_fn;
+use formality_core::{judgment_fn, Fallible};
 
 use crate::grammar::{
-    LocalVariableDecl, MethodBody, MethodDecl, MethodDeclBoundData, NamedTy, ThisDecl, Ty,
-    UniversalVar, Var::This,
+    LocalVariableDecl, MethodBody, MethodDecl, MethodDeclBoundData, NamedTy, Predicate, ThisDecl,
+    Ty, UniversalVar, Var::This,
 };
 
 use super::{
@@ -10,7 +10,6 @@ use super::{
     types::check_type,
 };
 
-// ANCHOR: check_method
 judgment_fn! {
     pub fn check_method(
         class_ty: NamedTy,
@@ -21,22 +20,12 @@ judgment_fn! {
         debug(decl, class_ty, env)
 
         (
-            (let MethodDecl { name: _, binder } = decl)
-            (let (env, method_vars, MethodDeclBoundData { this, inputs, output, predicates, body }) =
-                env.open_universally(binder))
-
-            // Methods don't really care about variance, so they can assume all their
-            // parameters (and the class's parameters) are relative/atomic for purposes of WF checking.
-            (let env = env.with_variance_assumed(class_vars).with_variance_assumed(method_vars))
+            (let (env, MethodDeclBoundData { this, inputs, output, predicates, body }) =
+                open_method(env, class_vars, decl))
 
             (check_predicates(env, predicates) => ())
-            (let env = env.add_assumptions(predicates))
-
-            (let ThisDecl { perm: this_perm } = &this)
-            (let this_ty = Ty::apply_perm(this_perm, class_ty))
-            (let env = env.push_local_variable(This, this_ty)?)
 
-            (let env = env.push_local_variable_decls(inputs)?)
+            (let env = method_env(env, class_ty, this, predicates, inputs)?)
 
             (for_all(input in inputs)
                 (let LocalVariableDecl { name: _, ty } = input)
@@ -50,9 +39,41 @@ judgment_fn! {
         )
     }
 }
-// ANCHOR_END: check_method
 
-// ANCHOR: check_body
+/// Open the generic parameters of the method `decl` in `env`, the env of its class
+/// (see [`class_env`](super::classes::class_env)). Methods don't really care about
+/// variance, so they can assume all their parameters (and the class's parameters)
+/// are relative/atomic for purposes of WF checking. The method's where-clauses are
+/// not assumed yet, so that they can be checked first; see [`method_env`].
+pub fn open_method(
+    env: &Env,
+    class_vars: &[UniversalVar],
+    decl: &MethodDecl,
+) -> (Env, MethodDeclBoundData) {
+    let MethodDecl { name: _, binder } = decl;
+    let (env, method_vars, data) = env.open_universally(binder);
+    let env = env
+        .with_variance_assumed(class_vars.to_vec())
+        .with_variance_assumed(method_vars);
+    (env, data)
+}
+
+/// The env in which the body of a method is checked: the env from [`open_method`]
+/// with the method's where-clauses assumed and `self` and the inputs in scope.
+pub fn method_env(
+    env: &Env,
+    class_ty: &NamedTy,
+    this: &ThisDecl,
+    predicates: &[Predicate],
+    inputs: &[LocalVariableDecl],
+) -> Fallible<Env> {
+    let ThisDecl { perm: this_perm } = this;
+    let env = env.add_assumptions(predicates.to_vec());
+    let this_ty = Ty::apply_perm(this_perm, class_ty);
+    let env = env.push_local_variable(This, this_ty)?;
+    env.push_local_variable_decls(inputs)
+}
+
 judgment_fn! {
     fn check_body(
         env: Env,
@@ -74,4 +95,3 @@ judgment_fn! {
         )
     }
 }
-// ANCHOR_END: check_body
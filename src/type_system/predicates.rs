@@ -1,4 +1,4 @@
-use super::{env::Env, types::check_parameter};
+use super::{env::Env, memo::memoize, types::check_parameter};
 use crate::{
     dada_lang::grammar::UniversalVar,
     grammar::{
@@ -77,92 +77,145 @@ judgment_fn! {
     }
 }
 
-judgment_fn! {
-    pub fn prove_is_copy(
-        env: Env,
-        a: Parameter,
-    ) => () {
-        debug(a, env)
+/// Judgments that are memoized. Each one is wrapped below by a function of the
+/// same name that consults the cache (see [`crate::type_system::memo`]).
+mod uncached {
+    use super::*;
+
+    judgment_fn! {
+        pub fn prove_is_copy(
+            env: Env,
+            a: Parameter,
+        ) => () {
+            debug(a, env)
+
+            (
+                (prove_predicate(env, Predicate::copy(a)) => ())
+                ---------------------------- ("is")
+                (prove_is_copy(env, a) => ())
+            )
+        }
+    }
 
-        (
-            (prove_predicate(env, Predicate::copy(a)) => ())
-            ---------------------------- ("is")
-            (prove_is_copy(env, a) => ())
-        )
+    judgment_fn! {
+        pub fn prove_is_shareable(
+            env: Env,
+            a: Parameter,
+        ) => () {
+            debug(a, env)
+
+            (
+                (prove_predicate(env, Predicate::share(a)) => ())
+                ---------------------------- ("is")
+                (prove_is_shareable(env, a) => ())
+            )
+        }
     }
-}
 
-judgment_fn! {
-    pub fn prove_is_shareable(
-        env: Env,
-        a: Parameter,
-    ) => () {
-        debug(a, env)
+    judgment_fn! {
+        pub fn prove_is_move(
+            env: Env,
+            a: Parameter,
+        ) => () {
+            debug(a, env)
+
+            (
+                (prove_predicate(env, Predicate::move_(a)) => ())
+                ---------------------------- ("is-moved")
+                (prove_is_move(env, a) => ())
+            )
+        }
+    }
 
-        (
-            (prove_predicate(env, Predicate::share(a)) => ())
-            ---------------------------- ("is")
-            (prove_is_shareable(env, a) => ())
-        )
+    judgment_fn! {
+        pub fn prove_is_mut(
+            env: Env,
+            a: Parameter,
+        ) => () {
+            debug(a, env)
+
+            (
+                (prove_predicate(env, Predicate::mut_(a)) => ())
+                ---------------------------- ("is-mut")
+                (prove_is_mut(env, a) => ())
+            )
+        }
     }
-}
 
-judgment_fn! {
-    pub fn prove_isnt_known_to_be_copy(
-        env: Env,
-        p: Parameter,
-    ) => () {
-        debug(p, env)
+    judgment_fn! {
+        pub fn prove_is_owned(
+            env: Env,
+            a: Parameter,
+        ) => () {
+            debug(a, env)
+
+            (
+                (prove_predicate(env, Predicate::owned(a)) => ())
+                ---------------------------- ("is-owned")
+                (prove_is_owned(env, a) => ())
+            )
+        }
+    }
 
-        (
-            (if !prove_is_copy(env, p).is_proven())
-            ---------------------------- ("isnt copy")
-            (prove_isnt_known_to_be_copy(env, p) => ())
-        )
+    judgment_fn! {
+        pub fn prove_is_boxed(
+            env: Env,
+            a: Parameter,
+        ) => () {
+            debug(a, env)
+
+            (
+                (prove_predicate(env, Predicate::boxed(a)) => ())
+                ---------------------------- ("prove")
+                (prove_is_boxed(env, a) => ())
+            )
+        }
     }
 }
 
-judgment_fn! {
-    pub fn prove_is_move(
-        env: Env,
-        a: Parameter,
-    ) => () {
-        debug(a, env)
+pub fn prove_is_copy(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<()> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("prove_is_copy", key, |(env, a)| uncached::prove_is_copy(env, a))
+}
 
-        (
-            (prove_predicate(env, Predicate::move_(a)) => ())
-            ---------------------------- ("is-moved")
-            (prove_is_move(env, a) => ())
-        )
-    }
+pub fn prove_is_shareable(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<()> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("prove_is_shareable", key, |(env, a)| {
+        uncached::prove_is_shareable(env, a)
+    })
 }
 
-judgment_fn! {
-    pub fn prove_is_mut(
-        env: Env,
-        a: Parameter,
-    ) => () {
-        debug(a, env)
+pub fn prove_is_move(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<()> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("prove_is_move", key, |(env, a)| uncached::prove_is_move(env, a))
+}
 
-        (
-            (prove_predicate(env, Predicate::mut_(a)) => ())
-            ---------------------------- ("is-mut")
-            (prove_is_mut(env, a) => ())
-        )
-    }
+pub fn prove_is_mut(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<()> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("prove_is_mut", key, |(env, a)| uncached::prove_is_mut(env, a))
+}
+
+pub fn prove_is_owned(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<()> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("prove_is_owned", key, |(env, a)| uncached::prove_is_owned(env, a))
+}
+
+pub fn prove_is_boxed(env: impl Upcast<Env>, a: impl Upcast<Parameter>) -> ProvenSet<()> {
+    let key: (Env, Parameter) = (env.upcast(), a.upcast());
+    memoize("prove_is_boxed", key, |(env, a)| uncached::prove_is_boxed(env, a))
 }
 
 judgment_fn! {
-    pub fn prove_is_owned(
+    pub fn prove_isnt_known_to_be_copy(
         env: Env,
-        a: Parameter,
+        p: Parameter,
     ) => () {
-        debug(a, env)
+        debug(p, env)
 
         (
-            (prove_predicate(env, Predicate::owned(a)) => ())
-            ---------------------------- ("is-owned")
-            (prove_is_owned(env, a) => ())
+            (if !prove_is_copy(env, p).is_proven())
+            ---------------------------- ("isnt copy")
+            (prove_isnt_known_to_be_copy(env, p) => ())
         )
     }
 }
@@ -199,21 +252,6 @@ judgment_fn! {
     }
 }
 
-judgment_fn! {
-    pub fn prove_is_boxed(
-        env: Env,
-        a: Parameter,
-    ) => () {
-        debug(a, env)
-
-        (
-            (prove_predicate(env, Predicate::boxed(a)) => ())
-            ---------------------------- ("prove")
-            (prove_is_boxed(env, a) => ())
-        )
-    }
-}
-
 pub fn prove_is_move_if_some(
     env: impl Upcast<Env>,
     a: impl Upcast<Option<(Place, Parameter)>>,
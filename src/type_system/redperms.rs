@@ -9,7 +9,7 @@ use crate::{
 };
 use formality_core::{cast_impl, judgment::ProofTree, judgment_fn, ProvenSet, Set, Upcast};
 
-use super::{env::Env, liveness::LivePlaces};
+use super::{env::Env, liveness::LivePlaces, memo::memoize};
 
 /// A reduced permission: the complete set of possible reduction chains for a
 /// permission expression. Because permissions like `given[x, y]` produce
@@ -262,32 +262,49 @@ judgment_fn! {
     }
 }
 
-judgment_fn! {
-    /// Convert `perm` to a non-empty set of reduced permissions.
-    /// Reduced permissions have a limited set of permissions:
-    ///
-    /// * `Perm::Shared`.
-    /// * `Perm::Ref[p]` where the type of `p` is not shared.
-    /// * `Perm::Mut[p]` where either
-    ///     * `p` is live
-    ///     * `p` is dead and the type of `p` is not lent.
-    /// * `Perm::Var(v)` is a variable `v`.
-    /// * An applied permission `P Q` where `Q` is not shared.
-    pub fn red_perm(
-        env: Env,
-        live_after: LivePlaces,
-        perm: Perm,
-    ) => RedPerm {
-        debug(env, live_after, perm)
-
-        (
-            (collect(some_expanded_red_chain(env, live_after, perm)) => chains)
-            --- ("collect")
-            (red_perm(env, live_after, perm) => RedPerm { chains: chains.clone() })
-        )
+/// `red_perm` is memoized: it is wrapped below by a function of the same name
+/// that consults the cache (see [`crate::type_system::memo`]).
+mod uncached {
+    use super::*;
+
+    judgment_fn! {
+        /// Convert `perm` to a non-empty set of reduced permissions.
+        /// Reduced permissions have a limited set of permissions:
+        ///
+        /// * `Perm::Shared`.
+        /// * `Perm::Ref[p]` where the type of `p` is not shared.
+        /// * `Perm::Mut[p]` where either
+        ///     * `p` is live
+        ///     * `p` is dead and the type of `p` is not lent.
+        /// * `Perm::Var(v)` is a variable `v`.
+        /// * An applied permission `P Q` where `Q` is not shared.
+        pub fn red_perm(
+            env: Env,
+            live_after: LivePlaces,
+            perm: Perm,
+        ) => RedPerm {
+            debug(env, live_after, perm)
+
+            (
+                (collect(some_expanded_red_chain(env, live_after, perm)) => chains)
+                --- ("collect")
+                (red_perm(env, live_after, perm) => RedPerm { chains: chains.clone() })
+            )
+        }
     }
 }
 
+pub fn red_perm(
+    env: impl Upcast<Env>,
+    live_after: impl Upcast<LivePlaces>,
+    perm: impl Upcast<Perm>,
+) -> ProvenSet<RedPerm> {
+    let key: (Env, LivePlaces, Perm) = (env.upcast(), live_after.upcast(), perm.upcast());
+    memoize("red_perm", key, |(env, live_after, perm)| {
+        uncached::red_perm(env, live_after, perm)
+    })
+}
+
 // Hack to leverage the search functionality of formality-core.
 // Collect all `P` that were provable and create a set `P`.
 // The judgments used to create `set` are not being used in the usual
@@ -23,7 +23,8 @@ judgment_fn! {
         debug(statements, env, live_after)
 
         (
-            (type_statements_with_final_ty(env, live_after, statements, Ty::unit()) => (env, ty))
+            (let live_afters = live_after.after_each(&statements))
+            (type_statements_with_final_ty(env, live_afters, statements, Ty::unit()) => (env, ty))
             ----------------------------------- ("type_statements")
             (type_statements(env, live_after, statements) => (env, ty))
         )
@@ -31,31 +32,31 @@ judgment_fn! {
 }
 
 judgment_fn! {
+    /// `live_afters` has one entry per statement: the places live after that statement.
     fn type_statements_with_final_ty(
         env: Env,
-        live_after: LivePlaces,
+        live_afters: Vec<LivePlaces>,
         statements: Vec<Statement>,
         ty: Ty,
     ) => (Env, Ty) {
-        debug(statements, ty, env, live_after)
+        debug(statements, ty, env, live_afters)
 
         (
             ----------------------------------- ("nil")
-            (type_statements_with_final_ty(env, _live_after, (), ty) => (env, ty))
+            (type_statements_with_final_ty(env, _live_afters, (), ty) => (env, ty))
         )
 
         (
-            (let live = live_after.before(statements))
             (type_statement(env, live, statement) => (env, ty))
-            (type_statements_with_final_ty(env, live_after, statements, ty) => (env, ty))
+            (type_statements_with_final_ty(env, live_afters, statements, ty) => (env, ty))
             ----------------------------------- ("cons")
-            (type_statements_with_final_ty(env, live_after, Cons(statement, statements), _ty) => (env, ty))
+            (type_statements_with_final_ty(env, Cons(live, live_afters), Cons(statement, statements), _ty) => (env, ty))
         )
     }
 }
 
 judgment_fn! {
-    fn type_statement(
+    pub fn type_statement(
         env: Env,
         live_after: LivePlaces,
         statement: Statement,
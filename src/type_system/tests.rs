@@ -3,11 +3,13 @@ use formality_core::test;
 mod array_ops;
 mod assignment;
 mod cancellation;
+mod check_all;
 mod drop_body;
 mod class_defn_wf;
 mod fn_calls;
 mod given_classes;
 mod mdbook;
+mod memo;
 mod move_check;
 mod move_tracking;
 mod new_with_self_references;
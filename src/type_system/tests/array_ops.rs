@@ -154,7 +154,7 @@ fn array_capacity_wrong_type_param() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
         src/type_system/subtypes.rs:38:1: no applicable rules for sub { a: given Int, b: given Data, live_after: LivePlaces { accessed: {}, traversed: {} }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
@@ -222,9 +222,9 @@ fn array_write_shared() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
 
         the rule "isnt copy" at (predicates.rs) failed because
           condition evaluated to false: `!prove_is_copy(env, p).is_proven()`"#]]);
@@ -240,7 +240,7 @@ fn array_write_ref() {
                 array_write[Int, ref[a]](a.ref, 0, 42);
             }
         }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [a], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [a], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// ref strips mutability — ref of mut should not satisfy prove_is_mut
@@ -254,7 +254,7 @@ fn array_write_ref_of_mut() {
                 array_write[Int, ref[array_mut]](array_mut.ref, 0, 42);
             }
         }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [array_mut], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], array_mut: mut [a] Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [array_mut], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], array_mut: mut [a] Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// array_write on a mut array should work
@@ -285,9 +285,9 @@ fn array_write_wrong_type_param() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [a], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [a], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Array[Int], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Array[Int], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
         src/type_system/subtypes.rs:38:1: no applicable rules for sub { a: Int, b: Data, live_after: LivePlaces { accessed: {}, traversed: {} }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
 }
@@ -319,7 +319,7 @@ fn array_write_not_an_array() {
                 array_write[Int, given](22, 0, 42);
             }
         }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass}, assumptions: {}, fresh: 0 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// array_write with non-Int index should fail
@@ -437,7 +437,7 @@ fn array_give_wrong_type_param() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
         src/type_system/subtypes.rs:38:1: no applicable rules for sub { a: given Int, b: given Data, live_after: LivePlaces { accessed: {}, traversed: {} }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
@@ -545,11 +545,11 @@ fn array_drop_shared() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], b: shared Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// array_drop on a ref array should work (A is ref is satisfied)
@@ -596,9 +596,9 @@ fn array_drop_wrong_type_param() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [a], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [a], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Array[Int], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Array[Int], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }
 
         src/type_system/subtypes.rs:38:1: no applicable rules for sub { a: Int, b: Data, live_after: LivePlaces { accessed: {}, traversed: {} }, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int]}, assumptions: {}, fresh: 0 } }"#]]);
 }
@@ -632,7 +632,7 @@ fn array_drop_bad_index_type() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], d: Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, a: Array[Int], d: Data}, assumptions: {}, fresh: 0 } }
 
         the rule "give" at (expressions.rs) failed because
           condition evaluated to false: `!live_after.is_live(place)`
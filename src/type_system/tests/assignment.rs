@@ -17,7 +17,7 @@ fn assign_leased_to_field_of_lease_that_is_typed_as_given() {
                 ();
             }
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, data: !perm_0 Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_0 is atomic}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, data: !perm_0 Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_0 is atomic}, fresh: 0 } }"#]]);
 }
 
 /// Pair is leased from P, but when you assign to its fields,
@@ -57,11 +57,11 @@ fn forall_shared_P_assign_to_field_of_P_pair() {
             }
         }
         }, expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is copy, !perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is copy, !perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is copy, !perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is copy, !perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is copy, !perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }"#]]);
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is copy, !perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }"#]]);
 }
 
 /// Test that field is not assignable when using a perm var that is not shared.
@@ -78,9 +78,9 @@ fn forall_P_assign_to_field_of_P_pair() {
             }
         }
         }, expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }"#]]);
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: given Main, @ fresh(0): Data, data: given Data, pair: !perm_0 Pair}, assumptions: {!perm_0 is relative, !perm_0 is atomic}, fresh: 1 } }"#]]);
 }
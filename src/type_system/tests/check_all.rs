@@ -0,0 +1,74 @@
+use crate::test_util::parse_program;
+use crate::type_system::check_program_all;
+
+/// Every failing class and method is reported, not just the first one.
+#[test]
+fn reports_every_failing_class_and_method() {
+    let program = parse_program(&["
+        class First {
+            fn ok(given self) -> Int { 0; }
+            fn bad_return(given self) -> Int {}
+        }
+
+        class Fine {
+            fn ok(given self) {}
+        }
+
+        class Second {
+            fn bad_let(given self) {
+                let x: Int = ();
+            }
+            fn also_bad(given self) -> Int {}
+        }
+    "]);
+
+    let report = check_program_all(&program);
+    let summary: Vec<(String, Vec<String>)> = report
+        .failures
+        .iter()
+        .map(|failure| {
+            (
+                format!("{:?}", failure.class),
+                failure
+                    .methods
+                    .iter()
+                    .map(|(name, _)| format!("{name:?}"))
+                    .collect(),
+            )
+        })
+        .collect();
+    expect_test::expect![[r#"
+        [
+            (
+                "First",
+                [
+                    "bad_return",
+                ],
+            ),
+            (
+                "Second",
+                [
+                    "bad_let",
+                    "also_bad",
+                ],
+            ),
+        ]
+    "#]]
+    .assert_debug_eq(&summary);
+
+    let message = report.into_result().unwrap_err().to_string();
+    assert!(
+        message.starts_with("2 class(es) failed to type check:"),
+        "{message}"
+    );
+}
+
+#[test]
+fn passing_program_has_no_failures() {
+    let program = parse_program(&["
+        class Main {
+            fn main(given self) -> Int { 22; }
+        }
+    "]);
+    assert!(check_program_all(&program).into_result().is_ok());
+}
@@ -18,7 +18,7 @@ fn create_PairSh_with_non_shared_type() {
                 ();
             }
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main}, assumptions: {}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -36,7 +36,7 @@ fn take_PairSh_with_non_shared_type() {
                 ();
             }
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, input: PairSh[Data]}, assumptions: {}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, input: PairSh[Data]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -65,7 +65,7 @@ fn forall_P_T_PT_requires_relative() {
         {
             field: P T;
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:832:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:870:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -112,7 +112,7 @@ fn forall_P_T_f1_T_f2_P_leased_f1_err() {
             f1: T;
             f2: P mut[self.f1] Data;
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:832:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:870:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -129,7 +129,7 @@ fn forall_P_T_f1_T_f2_P_given_f1_err() {
             f1: T;
             f2: P given[self.f1] Data;
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:832:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:870:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -159,7 +159,7 @@ fn forall_P_T_P_Vec_T_err() {
         {
             f1: P Vec[T];
         }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:832:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:870:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !ty_1], local_variables: {self: Ref[!perm_0, !ty_1]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -175,7 +175,7 @@ fn Ref1_requires_rel_Ref2_does_not_err() {
         class Ref2[ty T] {
             f1: Ref1[shared, T];
         }
-      }, expect_test::expect![[r#"src/type_system/predicates.rs:832:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Ref2[!ty_0]}, assumptions: {}, fresh: 0 } }"#]]);
+      }, expect_test::expect![[r#"src/type_system/predicates.rs:870:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Ref2[!ty_0]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -188,7 +188,7 @@ fn sh_from_arena() {
             arena: Arena;
             f1: ref[self.arena] T;
         }
-      }, expect_test::expect![[r#"src/type_system/predicates.rs:832:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Ref[!ty_0]}, assumptions: {}, fresh: 0 } }"#]]);
+      }, expect_test::expect![[r#"src/type_system/predicates.rs:870:1: no applicable rules for variance_predicate { kind: relative, parameter: !ty_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Ref[!ty_0]}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 #[test]
@@ -203,7 +203,7 @@ fn atomic_field_req_atomic_err() {
           the rule "check_field" at (classes.rs) failed because
             judgment `prove_predicate { predicate: !ty_0 is atomic, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Atomic[!ty_0]}, assumptions: {!ty_0 is share}, fresh: 0 } }` failed at the following rule(s):
               the rule "variance" at (predicates.rs) failed because
-                src/type_system/predicates.rs:832:1: judgment had no applicable rules: `variance_predicate { kind: atomic, parameter: !ty_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Atomic[!ty_0]}, assumptions: {!ty_0 is share}, fresh: 0 } }`"#]]);
+                src/type_system/predicates.rs:870:1: judgment had no applicable rules: `variance_predicate { kind: atomic, parameter: !ty_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!ty_0], local_variables: {self: Atomic[!ty_0]}, assumptions: {!ty_0 is share}, fresh: 0 } }`"#]]);
 }
 
 #[test]
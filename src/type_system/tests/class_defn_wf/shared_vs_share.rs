@@ -18,7 +18,7 @@ fn our_class_cannot_hold_a_share_class_directly() {
                       the rule "is" at (predicates.rs) failed because
                         judgment `prove_predicate { predicate: RegularClass is copy, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: OurClass}, assumptions: {}, fresh: 0 } }` failed at the following rule(s):
                           the rule "copy" at (predicates.rs) failed because
-                            src/type_system/predicates.rs:324:1: judgment had no applicable rules: `prove_copy_predicate { p: RegularClass, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: OurClass}, assumptions: {}, fresh: 0 } }`"#]]);
+                            src/type_system/predicates.rs:362:1: judgment had no applicable rules: `prove_copy_predicate { p: RegularClass, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: OurClass}, assumptions: {}, fresh: 0 } }`"#]]);
 }
 
 #[test]
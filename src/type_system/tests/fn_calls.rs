@@ -56,7 +56,7 @@ fn send_same_message_twice() {
                 }
             }
         }, expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Bar, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, @ fresh(0): mut [channel] Channel[Bar], bar: Bar, channel: Channel[Bar]}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Bar, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, @ fresh(0): mut [channel] Channel[Bar], bar: Bar, channel: Channel[Bar]}, assumptions: {}, fresh: 1 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
@@ -87,7 +87,7 @@ fn needs_leased_got_shared_self() {
                     ();
                 }
             }
-        }, expect_test::expect![[r#"src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [channel], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, @ fresh(0): ref [channel] Channel[Bar], @ fresh(1): Bar, bar: Bar, channel: Channel[Bar]}, assumptions: {}, fresh: 2 } }"#]])
+        }, expect_test::expect![[r#"src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [channel], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given TheClass, @ fresh(0): ref [channel] Channel[Bar], @ fresh(1): Bar, bar: Bar, channel: Channel[Bar]}, assumptions: {}, fresh: 2 } }"#]])
 }
 
 /// Test where function expects a `Pair` and data borrowed from `pair`.
@@ -303,5 +303,5 @@ fn pair_method__expect_leased_self_a__got_leased_self_b() {
                 place_b = @ fresh(0) . a
                 place_a = @ fresh(0) . b
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [@ fresh(0) . b], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): Pair, @ fresh(1): mut [@ fresh(0) . b] Data, data: mut [@ fresh(0) . b] Data, pair: Pair}, assumptions: {}, fresh: 2 } }"#]])
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [@ fresh(0) . b], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): Pair, @ fresh(1): mut [@ fresh(0) . b] Data, data: mut [@ fresh(0) . b] Data, pair: Pair}, assumptions: {}, fresh: 2 } }"#]])
 }
@@ -72,13 +72,13 @@ fn regular_class_cannot_hold_P_guard_class() {
                       the rule "is" at (predicates.rs) failed because
                         judgment `prove_predicate { predicate: !perm_0 is copy, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }` failed at the following rule(s):
                           the rule "copy" at (predicates.rs) failed because
-                            src/type_system/predicates.rs:324:1: judgment had no applicable rules: `prove_copy_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }`
+                            src/type_system/predicates.rs:362:1: judgment had no applicable rules: `prove_copy_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }`
                   the rule "share mut T" at (predicates.rs) failed because
                     judgment `prove_is_mut { a: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }` failed at the following rule(s):
                       the rule "is-mut" at (predicates.rs) failed because
                         judgment `prove_predicate { predicate: !perm_0 is mut, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }` failed at the following rule(s):
                           the rule "mut" at (predicates.rs) failed because
-                            src/type_system/predicates.rs:623:1: judgment had no applicable rules: `prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }`"#]]);
+                            src/type_system/predicates.rs:661:1: judgment had no applicable rules: `prove_mut_predicate { p: !perm_0, env: Env { program: "...", universe: universe(1), in_scope_vars: [!perm_0], local_variables: {self: RegularClass[!perm_0]}, assumptions: {}, fresh: 0 } }`"#]]);
 }
 
 // FIXME: We use `P is mut` here but would be better served with a predicate
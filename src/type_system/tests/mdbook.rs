@@ -90,7 +90,7 @@ fn giving_a_value_twice_is_error() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
@@ -145,7 +145,7 @@ fn giving_field_then_whole_is_error() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, p: Pair}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, p: Pair}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
@@ -176,7 +176,7 @@ fn giving_whole_then_field_is_error() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Pair, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, p: Pair}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Pair, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, p: Pair}, assumptions: {}, fresh: 0 } }
 
             the rule "give" at (expressions.rs) failed because
               condition evaluated to false: `!live_after.is_live(place)`
@@ -661,16 +661,16 @@ fn subtyping_narrowing_ref_fails() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data}, assumptions: {}, fresh: 0 } }
 
             the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
               condition evaluated to false: `place_b.is_prefix_of(place_a)`
                 place_b = d1
                 place_a = d2
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: subtyping_narrowing_ref_fails
 }
@@ -748,17 +748,17 @@ fn subtyping_non_copy_params_block_erasure() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: subtyping_non_copy_params_block_erasure
 }
@@ -800,16 +800,16 @@ fn subtyping_place_refinement_reverse_fails() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
             the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
               condition evaluated to false: `place_b.is_prefix_of(place_a)`
                 place_b = d . left
                 place_a = d
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: subtyping_place_refinement_reverse_fails
 }
@@ -853,11 +853,11 @@ fn copy_perm_ref_not_subtype_shared() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, r: ref [d] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, r: ref [d] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, r: ref [d] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, r: ref [d] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, r: ref [d] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, r: ref [d] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: copy_perm_ref_not_subtype_shared
 }
@@ -916,7 +916,7 @@ fn copy_perm_shared_mut_not_subtype_ref() {
                 }
             }
         },
-        expect_test::expect![[r#"src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, p: mut [d] Data, sm: shared mut [d] Data}, assumptions: {}, fresh: 0 } }"#]]
+        expect_test::expect![[r#"src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, p: mut [d] Data, sm: shared mut [d] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: copy_perm_shared_mut_not_subtype_ref
 }
@@ -977,9 +977,9 @@ fn copy_perm_mut_not_subtype_ref() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, p: mut [d] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, p: mut [d] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, p: mut [d] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data, p: mut [d] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: copy_perm_mut_not_subtype_ref
 }
@@ -998,7 +998,7 @@ fn copy_perm_given_not_subtype_shared() {
                 }
             }
         },
-        expect_test::expect![[r#"src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
+        expect_test::expect![[r#"src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: copy_perm_given_not_subtype_shared
 }
@@ -1067,16 +1067,16 @@ fn place_ordering_reverse_fails() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
             the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
               condition evaluated to false: `place_b.is_prefix_of(place_a)`
                 place_b = d . left
                 place_a = d
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: given Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: place_ordering_reverse_fails
 }
@@ -1115,16 +1115,16 @@ fn place_ordering_dropping_source_fails() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data, r: ref [d1, d2] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data, r: ref [d1, d2] Data}, assumptions: {}, fresh: 0 } }
 
             the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
               condition evaluated to false: `place_b.is_prefix_of(place_a)`
                 place_b = d1
                 place_a = d2
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data, r: ref [d1, d2] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data, r: ref [d1, d2] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data, r: ref [d1, d2] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d1: given Data, d2: given Data, r: ref [d1, d2] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: place_ordering_dropping_source_fails
 }
@@ -1223,7 +1223,7 @@ fn liveness_live_mut_no_cancel() {
                 place_b = d
                 place_a = p
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [p], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: mut [p] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [p], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: mut [p] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: liveness_live_mut_no_cancel
 }
@@ -1274,9 +1274,9 @@ fn liveness_live_ref_no_promote() {
                 place_b = d
                 place_a = p
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: liveness_live_ref_no_promote
 }
@@ -1317,11 +1317,11 @@ fn liveness_ref_shared_no_cancel() {
             }
         },
         expect_test::expect![[r#"
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] mut [d] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] mut [d] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] mut [d] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [d], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] mut [d] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] mut [d] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: mut [d] Data, q: ref [p] mut [d] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: liveness_ref_shared_no_cancel
 }
@@ -1350,9 +1350,9 @@ fn liveness_all_places_must_be_dead() {
                 &link = Rfd(d)
                 &popped_vars = [d, p, q, r, s]
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: ref [d] Data, q: ref [d] Data, r: ref [@ fresh(0), p] ref [d] Data, s: ref [d] Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: ref [d] Data, q: ref [d] Data, r: ref [@ fresh(0), p] ref [d] Data, s: ref [d] Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: ref [d] Data, q: ref [d] Data, r: ref [@ fresh(0), p] ref [d] Data, s: ref [d] Data}, assumptions: {}, fresh: 0 } }"#]]
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: Data, p: ref [d] Data, q: ref [d] Data, r: ref [@ fresh(0), p] ref [d] Data, s: ref [d] Data}, assumptions: {}, fresh: 0 } }"#]]
     );
     // ANCHOR_END: liveness_all_places_must_be_dead
 }
@@ -0,0 +1,47 @@
+use crate::test_util::parse_program;
+use crate::type_system::{check_program, memo::with_memo};
+
+const POINTS: &str = "
+    class Point {
+        x: Int;
+        y: Int;
+        z: Int;
+    }
+
+    class Main {
+        fn test(given self) -> Int {
+            let p = new Point(1, 2, 3);
+            let q = new Point(p.x.give, p.y.give, p.z.give);
+            q.x.give;
+        }
+    }
+";
+
+/// Repeated judgments are answered from the cache, and the result is the
+/// same as checking without memoization.
+#[test]
+fn memoized_check_hits_cache() {
+    let program = parse_program(&[POINTS]);
+
+    let uncached = check_program(&program);
+    let (cached, report) = with_memo(|| check_program(&program));
+    assert_eq!(format!("{uncached:?}"), format!("{cached:?}"));
+
+    let total = report.total();
+    assert!(total.misses > 0, "{report}");
+    assert!(total.hits > 0, "{report}");
+    assert!(report.judgments.contains_key("prove_is_copy"), "{report}");
+}
+
+/// The cache does not outlive `with_memo`.
+#[test]
+fn memo_is_scoped() {
+    let program = parse_program(&[POINTS]);
+
+    let (_, first) = with_memo(|| check_program(&program));
+    let (_, second) = with_memo(|| check_program(&program));
+    assert_eq!(first.total(), second.total());
+
+    let (_, nested) = with_memo(|| with_memo(|| check_program(&program)).1);
+    assert_eq!(nested.total(), first.total());
+}
@@ -200,7 +200,7 @@ fn predicate_or_not_copy_when_mut() {
                 ();
             }
         }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [x], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): given Main, x: given Data, y: given Data}, assumptions: {}, fresh: 1 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [x], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): given Main, x: given Data, y: given Data}, assumptions: {}, fresh: 1 } }"#]]);
 }
 
 /// or(given, given) is given ✅
@@ -245,7 +245,7 @@ fn predicate_or_not_copy_when_given() {
                 ();
             }
         }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): given Main}, assumptions: {}, fresh: 1 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): given Main}, assumptions: {}, fresh: 1 } }"#]]);
 }
 
 /// or(given, given) is move ✅ — given implies move
@@ -275,7 +275,7 @@ fn predicate_or_not_copy_mixed_ref_mut() {
                 ();
             }
         }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [y], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): given Main, x: given Data, y: given Data}, assumptions: {}, fresh: 1 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [y], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): given Main, x: given Data, y: given Data}, assumptions: {}, fresh: 1 } }"#]]);
 }
 
 // ---------------------------------------------------------------------------
@@ -325,16 +325,16 @@ fn subtype_or_ref_not_subtype_single_ref() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = x
             place_a = y
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data}, assumptions: {}, fresh: 0 } }"#]]);
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// or(ref[x], ref[y]) <: or(ref[x], ref[y], ref[z]) ✅
@@ -367,27 +367,27 @@ fn subtype_or_superset_fails() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = x
             place_a = z
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = y
             place_a = z
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }"#]]);
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y], ref [z]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// ref[x] <: or(ref[x], ref[y]) ✅
@@ -419,27 +419,27 @@ fn subtype_or_partial_overlap_fails() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = y
             place_a = x
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = z
             place_a = x
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }"#]]);
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: or(ref [x], ref [y]) Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 /// or(ref[x], ref[y]) <: or(ref[x], ref[y]) ✅
@@ -503,27 +503,27 @@ fn subtype_wider_multi_ref_to_or_fails() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = x
             place_a = z
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
         the rule "(ref::P) vs (ref::P)" at (redperms.rs) failed because
           condition evaluated to false: `place_b.is_prefix_of(place_a)`
             place_b = y
             place_a = z
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }"#]]);
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, d: ref [x, y, z] Data, x: given Data, y: given Data, z: given Data}, assumptions: {}, fresh: 0 } }"#]]);
 }
 
 // ---------------------------------------------------------------------------
@@ -99,15 +99,15 @@ fn ref_then_mut_errors() {
                 }
             }
         }, expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Foo, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Foo, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Foo, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }"#]])
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Foo, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, bar: ref [foo] Foo, foo: Foo}, assumptions: {}, fresh: 0 } }"#]])
 }
 
 /// Check giving a field from a shared value is not ok.
@@ -282,19 +282,19 @@ fn mutate_field_of_shared_pair() {
                 }
             }
         }, expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [self], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [self], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Pair, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Pair, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [self], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [self], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [self], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [self], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Pair, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }"#]])
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Pair, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, me: ref [self] Pair}, assumptions: {}, fresh: 1 } }"#]])
 }
 
 /// Test that we cannot mutate fields of a shared class.
@@ -314,11 +314,11 @@ fn mutate_field_of_our_pair() {
                 }
             }
         }, expect_test::expect![[r#"
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, pair: shared Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, pair: shared Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, pair: shared Pair}, assumptions: {}, fresh: 1 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, pair: shared Pair}, assumptions: {}, fresh: 1 } }
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, pair: shared Pair}, assumptions: {}, fresh: 1 } }"#]])
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: shared, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, @ fresh(0): Data, data: given Data, pair: shared Pair}, assumptions: {}, fresh: 1 } }"#]])
 }
 
 /// Test that we can mutate fields of a leased class.
@@ -406,9 +406,9 @@ fn take_given_and_shared_move_given_then_return_shared() {
                 &link = Rfd(owner1)
                 &popped_vars = [d, owner1]
 
-            src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, d: ref [owner1] Data, data: ref [owner1] Data, owner: given Data, owner1: given Data}, assumptions: {}, fresh: 0 } }
+            src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: given, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, d: ref [owner1] Data, data: ref [owner1] Data, owner: given Data, owner1: given Data}, assumptions: {}, fresh: 0 } }
 
-            src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, d: ref [owner1] Data, data: ref [owner1] Data, owner: given Data, owner1: given Data}, assumptions: {}, fresh: 0 } }"#]])
+            src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Data, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Pair, d: ref [owner1] Data, data: ref [owner1] Data, owner: given Data, owner1: given Data}, assumptions: {}, fresh: 0 } }"#]])
 }
 
 /// Interesting example from [conversation with Isaac][r]. In this example,
@@ -482,7 +482,7 @@ fn escapes_err_use_again() {
               y.give;
             }
           }
-    }, expect_test::expect![[r#"src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [y], env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], @ fresh(2): mut [y] R[Int], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_1 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 3 } }"#]]);
+    }, expect_test::expect![[r#"src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [y], env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], @ fresh(2): mut [y] R[Int], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_1 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 3 } }"#]]);
 }
 
 /// See `escapes_ok`, but here we don't know that `B` is leased (and hence get an error).
@@ -520,11 +520,11 @@ fn escapes_err_not_leased() {
             }
           }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 2 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 2 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 2 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 2 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: !perm_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 2 } }"#]]);
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: !perm_1, env: Env { program: "...", universe: universe(2), in_scope_vars: [!perm_0, !perm_1], local_variables: {self: given Main, @ fresh(0): given Main, @ fresh(1): !perm_0 R[!perm_1 R[Int]], x: !perm_0 R[!perm_1 R[Int]], y: !perm_1 R[Int]}, assumptions: {!perm_0 is mut, !perm_0 is relative, !perm_1 is relative, !perm_0 is atomic, !perm_1 is atomic}, fresh: 2 } }"#]]);
 }
 
 /// Check that a `ref[d1, d2]` in parameters prohibits writes to `d1`.
@@ -634,11 +634,11 @@ fn ref_of_mut_is_not_mut() {
             }
         }
     }, expect_test::expect![[r#"
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [m], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [m], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }
 
-        src/type_system/predicates.rs:623:1: no applicable rules for prove_mut_predicate { p: ref [m], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }
+        src/type_system/predicates.rs:661:1: no applicable rules for prove_mut_predicate { p: ref [m], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: mut [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: mut [foo], env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }
 
-        src/type_system/predicates.rs:324:1: no applicable rules for prove_copy_predicate { p: Foo, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }"#]]);
+        src/type_system/predicates.rs:362:1: no applicable rules for prove_copy_predicate { p: Foo, env: Env { program: "...", universe: universe(0), in_scope_vars: [], local_variables: {self: given Main, @ fresh(0): ref [m] Foo, foo: Foo, m: mut [foo] Foo}, assumptions: {}, fresh: 1 } }"#]]);
 }
@@ -0,0 +1,263 @@
+//! Runs every `.dada` file under `tests/ui` through the type checker and,
+//! if asked to, the interpreter.
+//!
+//! A test is annotated with comments, which are stripped before parsing:
+//!
+//! * `//~ ERROR <text>`: a type error must be reported on this line, and its
+//!   leaf failures must contain `<text>` (e.g. a judgment name such as
+//!   `prove_is_copy`). The type checker does not report source locations, so a
+//!   failing method is reported on its first top-level statement that does not
+//!   type check, or on its name if every statement does; a class that fails
+//!   outside of its methods is reported on its name (see
+//!   `test_util::located_type_errors`). Every type error must be annotated, so a
+//!   program without any `ERROR` annotation must type check.
+//! * `//@ run`: run the program in the interpreter, which must not fault.
+//! * `//@ run-output: <line>`: run the program; the lines it prints must be
+//!   exactly those given by the `run-output` annotations, in order.
+//! * `//@ run-fault: <text>`: run the program; it must fault with a message
+//!   containing `<text>`.
+//!
+//! The type errors, with their lines and leaf failures, are compared against
+//! `foo.stderr` next to `foo.dada` (with the `line:column` of each rule replaced
+//! by `LL:CC`), and the interpreter's trace and result
+//! against `foo.stdout`. Run with `UPDATE_EXPECT=1` to create or update them.
+
+use std::path::{Path, PathBuf};
+
+use dada_model::test_util::{located_type_errors, run_interpreter, try_parse_program, TypeError};
+
+#[derive(Debug, Default)]
+struct Annotations {
+    /// The line (counting from 1) and text of each `ERROR` annotation.
+    errors: Vec<(usize, String)>,
+    run: bool,
+    run_output: Vec<String>,
+    run_fault: Option<String>,
+}
+
+impl Annotations {
+    fn parse(source: &str) -> Result<Self, String> {
+        let mut annotations = Annotations::default();
+        for (index, line) in source.lines().enumerate() {
+            let Some((_, comment)) = line.split_once("//") else {
+                continue;
+            };
+            if let Some(text) = comment.strip_prefix("~ ERROR") {
+                annotations
+                    .errors
+                    .push((index + 1, text.trim().to_string()));
+            } else if let Some(directive) = comment.strip_prefix('@') {
+                let directive = directive.trim();
+                if directive == "run" {
+                    annotations.run = true;
+                } else if let Some(text) = directive.strip_prefix("run-output:") {
+                    annotations.run = true;
+                    annotations.run_output.push(text.trim().to_string());
+                } else if let Some(text) = directive.strip_prefix("run-fault:") {
+                    annotations.run = true;
+                    annotations.run_fault = Some(text.trim().to_string());
+                } else {
+                    return Err(format!(
+                        "line {}: unknown directive `{directive}`",
+                        index + 1
+                    ));
+                }
+            }
+        }
+        Ok(annotations)
+    }
+}
+
+/// Replace comments with nothing, keeping line numbers intact.
+fn strip_comments(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| match line.split_once("//") {
+            Some((code, _)) => code,
+            None => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn collect_tests(dir: &Path, tests: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("cannot read `{}`: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect_tests(&path, tests);
+        } else if path.extension().is_some_and(|ext| ext == "dada") {
+            tests.push(path);
+        }
+    }
+}
+
+/// Compare `actual` against the snapshot file `path` (absent if `actual` is `None`),
+/// or update the file when blessing.
+fn check_snapshot(path: &Path, actual: Option<&str>, bless: bool) -> Result<(), String> {
+    let expected = std::fs::read_to_string(path).ok();
+    let actual = actual.map(|text| format!("{}\n", text.trim_end()));
+    if expected == actual {
+        return Ok(());
+    }
+    if bless {
+        match &actual {
+            Some(text) => std::fs::write(path, text).map_err(|e| e.to_string())?,
+            None => std::fs::remove_file(path).map_err(|e| e.to_string())?,
+        }
+        return Ok(());
+    }
+    match (expected, actual) {
+        (_, None) => Err(format!(
+            "`{}` should not exist (run with UPDATE_EXPECT=1 to delete it)",
+            path.display()
+        )),
+        (None, Some(actual)) => Err(format!(
+            "`{}` does not exist (run with UPDATE_EXPECT=1 to create it); actual:\n{actual}",
+            path.display()
+        )),
+        (Some(expected), Some(actual)) => Err(format!(
+            "`{}` does not match (run with UPDATE_EXPECT=1 to update it)\nexpected:\n{expected}\nactual:\n{actual}",
+            path.display()
+        )),
+    }
+}
+
+/// `line 3` or `lines 3-5`.
+fn describe_lines(error: &TypeError) -> String {
+    let (first, last) = (error.lines.start(), error.lines.end());
+    if first == last {
+        format!("line {first}")
+    } else {
+        format!("lines {first}-{last}")
+    }
+}
+
+fn run_test(path: &Path, bless: bool) -> Result<(), String> {
+    let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let annotations = Annotations::parse(&source)?;
+    let program =
+        try_parse_program(&strip_comments(&source)).map_err(|e| format!("parse error: {e:?}"))?;
+
+    let mut failures = vec![];
+
+    let type_errors = located_type_errors(&strip_comments(&source), &program);
+    for (line, expected) in &annotations.errors {
+        let found = type_errors
+            .iter()
+            .any(|error| error.lines.contains(line) && error.leaves.contains(expected.as_str()));
+        if !found {
+            let elsewhere: Vec<String> = type_errors
+                .iter()
+                .filter(|error| error.leaves.contains(expected.as_str()))
+                .map(|error| describe_lines(error))
+                .collect();
+            failures.push(if elsewhere.is_empty() {
+                format!("line {line}: expected a type error mentioning `{expected}`")
+            } else {
+                format!(
+                    "line {line}: expected a type error mentioning `{expected}`, but it was reported on {}",
+                    elsewhere.join(", ")
+                )
+            });
+        }
+    }
+    for error in &type_errors {
+        let annotated = annotations
+            .errors
+            .iter()
+            .any(|(line, _)| error.lines.contains(line));
+        if !annotated {
+            failures.push(format!(
+                "unexpected type error on {}:\n{}",
+                describe_lines(error),
+                error.leaves
+            ));
+        }
+    }
+    let stderr = (!type_errors.is_empty()).then(|| {
+        type_errors
+            .iter()
+            .map(|error| format!("error on {}:\n{}", describe_lines(error), error.leaves))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    });
+    if let Err(e) = check_snapshot(&path.with_extension("stderr"), stderr.as_deref(), bless) {
+        failures.push(e);
+    }
+
+    let run = annotations.run.then(|| run_interpreter(&program));
+    if let Some(result) = &run {
+        match &annotations.run_fault {
+            Some(expected) => {
+                if !result.result.starts_with("Fault:")
+                    || !result.result.contains(expected.as_str())
+                {
+                    failures.push(format!(
+                        "expected a fault containing `{expected}`, got `{}`",
+                        result.result
+                    ));
+                }
+            }
+            None => {
+                if !result.result.starts_with("Ok:") {
+                    failures.push(format!("unexpected fault: `{}`", result.result));
+                }
+            }
+        }
+
+        let printed: Vec<&str> = result
+            .output_lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("----->"))
+            .map(|line| line.trim())
+            .collect();
+        if !annotations.run_output.is_empty() && printed != annotations.run_output {
+            failures.push(format!(
+                "expected output {:?}, got {printed:?}",
+                annotations.run_output
+            ));
+        }
+    }
+    let stdout = run.as_ref().map(|result| result.to_snapshot());
+    if let Err(e) = check_snapshot(&path.with_extension("stdout"), stdout.as_deref(), bless) {
+        failures.push(e);
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
+
+#[test]
+fn ui() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ui");
+    let bless = std::env::var_os("UPDATE_EXPECT").is_some();
+
+    let mut tests = vec![];
+    collect_tests(&root, &mut tests);
+    assert!(!tests.is_empty(), "no tests found in `{}`", root.display());
+
+    let failures: Vec<String> = tests
+        .iter()
+        .filter_map(|path| {
+            let name = path.strip_prefix(&root).unwrap_or(path).display();
+            run_test(path, bless)
+                .err()
+                .map(|error| format!("{name}:\n{error}"))
+        })
+        .collect();
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} ui test(s) failed:\n\n{}",
+            failures.len(),
+            tests.len(),
+            failures.join("\n\n")
+        );
+    }
+}